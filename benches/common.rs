@@ -4,27 +4,27 @@
 mod bench_utils;
 
 use ark_std::UniformRand;
+use ark_vrf::suites::SuiteInfo;
 use ark_vrf::{AffinePoint, Input, Output, Secret, VrfIo};
-use bench_utils::BenchInfo;
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
 
-fn bench_vrf_output<S: BenchInfo>(c: &mut Criterion) {
+fn bench_vrf_output<S: SuiteInfo>(c: &mut Criterion) {
     let secret = Secret::<S>::from_seed([0; 32]);
     let input = Input::<S>::new(b"bench input data").unwrap();
-    let name = format!("{}/vrf_output", S::SUITE_NAME);
+    let name = format!("{}/vrf_output", S::NAME);
     c.bench_function(&name, |b| {
         b.iter(|| secret.output(black_box(input)));
     });
 }
 
-fn bench_data_to_point_tai<S: BenchInfo>(c: &mut Criterion) {
-    let name = format!("{}/data_to_point_tai", S::SUITE_NAME);
+fn bench_data_to_point_tai<S: SuiteInfo>(c: &mut Criterion) {
+    let name = format!("{}/data_to_point_tai", S::NAME);
     c.bench_function(&name, |b| {
         b.iter(|| ark_vrf::utils::hash_to_curve_tai::<S>(black_box(b"bench input data")).unwrap());
     });
 }
 
-fn bench_data_to_point_ell2<S: BenchInfo>(c: &mut Criterion)
+fn bench_data_to_point_ell2<S: SuiteInfo>(c: &mut Criterion)
 where
     ark_vrf::CurveConfig<S>: ark_ec::twisted_edwards::TECurveConfig,
     ark_vrf::CurveConfig<S>: ark_ec::hashing::curve_maps::elligator2::Elligator2Config,
@@ -34,7 +34,7 @@ where
             >,
 {
     let h2c_suite_id = b"Bandersnatch_XMD:SHA-512_ELL2_RO_";
-    let name = format!("{}/data_to_point_ell2", S::SUITE_NAME);
+    let name = format!("{}/data_to_point_ell2", S::NAME);
     c.bench_function(&name, |b| {
         b.iter(|| {
             ark_vrf::utils::hash_to_curve_ell2_xmd::<S, sha2::Sha512>(
@@ -46,13 +46,13 @@ where
     });
 }
 
-fn bench_challenge<S: BenchInfo>(c: &mut Criterion) {
+fn bench_challenge<S: SuiteInfo>(c: &mut Criterion) {
     let secret = Secret::<S>::from_seed([0; 32]);
     let input = Input::<S>::new(b"bench input data").unwrap();
     let output = secret.output(input);
     let generator = S::generator();
 
-    let name = format!("{}/challenge", S::SUITE_NAME);
+    let name = format!("{}/challenge", S::NAME);
     c.bench_function(&name, |b| {
         b.iter(|| {
             S::challenge(
@@ -69,29 +69,29 @@ fn bench_challenge<S: BenchInfo>(c: &mut Criterion) {
     });
 }
 
-fn bench_point_to_hash<S: BenchInfo>(c: &mut Criterion) {
+fn bench_point_to_hash<S: SuiteInfo>(c: &mut Criterion) {
     let mut rng = ark_std::test_rng();
     let point = AffinePoint::<S>::rand(&mut rng);
 
-    let name = format!("{}/point_to_hash", S::SUITE_NAME);
+    let name = format!("{}/point_to_hash", S::NAME);
     c.bench_function(&name, |b| {
         b.iter(|| S::point_to_hash::<32>(black_box(&point)));
     });
 }
 
-fn bench_nonce<S: BenchInfo>(c: &mut Criterion) {
+fn bench_nonce<S: SuiteInfo>(c: &mut Criterion) {
     let secret = Secret::<S>::from_seed([0; 32]);
     let input = Input::<S>::new(b"bench input data").unwrap();
 
-    let name = format!("{}/nonce[{}]", S::SUITE_NAME, S::NONCE_TAG);
+    let name = format!("{}/nonce[{}]", S::NAME, S::NONCE);
     c.bench_function(&name, |b| {
         b.iter(|| S::nonce(black_box(secret.scalar()), None));
     });
 }
 
 // All common benchmarks for a single suite.
-fn bench_common_suite<S: BenchInfo>(c: &mut Criterion) {
-    S::print_info();
+fn bench_common_suite<S: SuiteInfo>(c: &mut Criterion) {
+    bench_utils::print_info::<S>();
     bench_vrf_output::<S>(c);
     bench_data_to_point_tai::<S>(c);
     bench_point_to_hash::<S>(c);
@@ -1,24 +1,24 @@
 #[macro_use]
 mod bench_utils;
 
+use ark_vrf::suites::SuiteInfo;
 use ark_vrf::{Input, Secret};
-use bench_utils::BenchInfo;
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 
-fn bench_tiny_prove<S: BenchInfo>(c: &mut Criterion) {
+fn bench_tiny_prove<S: SuiteInfo>(c: &mut Criterion) {
     use ark_vrf::tiny::Prover;
 
     let secret = Secret::<S>::from_seed([0; 32]);
     let input = Input::<S>::new(b"bench input data").unwrap();
     let io = secret.vrf_io(input);
 
-    let name = format!("{}/tiny_prove", S::SUITE_NAME);
+    let name = format!("{}/tiny_prove", S::NAME);
     c.bench_function(&name, |b| {
         b.iter(|| secret.prove(black_box(io), b"ad"));
     });
 }
 
-fn bench_tiny_verify<S: BenchInfo>(c: &mut Criterion) {
+fn bench_tiny_verify<S: SuiteInfo>(c: &mut Criterion) {
     use ark_vrf::tiny::{Prover, Verifier};
 
     let secret = Secret::<S>::from_seed([0; 32]);
@@ -27,7 +27,7 @@ fn bench_tiny_verify<S: BenchInfo>(c: &mut Criterion) {
     let io = secret.vrf_io(input);
     let proof = secret.prove(io, b"ad");
 
-    let name = format!("{}/tiny_verify", S::SUITE_NAME);
+    let name = format!("{}/tiny_verify", S::NAME);
     c.bench_function(&name, |b| {
         b.iter(|| {
             public
@@ -37,10 +37,27 @@ fn bench_tiny_verify<S: BenchInfo>(c: &mut Criterion) {
     });
 }
 
-fn bench_tiny_suite<S: BenchInfo>(c: &mut Criterion) {
-    S::print_info();
+fn bench_tiny_verify_context<S: SuiteInfo>(c: &mut Criterion) {
+    use ark_vrf::tiny::{Prover, Verifier};
+
+    let secret = Secret::<S>::from_seed([0; 32]);
+    let precomp = ark_vrf::utils::SuitePrecomp::<S>::new();
+    let ctx = ark_vrf::utils::VerifierContext::new(secret.public(), &precomp);
+    let input = Input::<S>::new(b"bench input data").unwrap();
+    let io = secret.vrf_io(input);
+    let proof = secret.prove(io, b"ad");
+
+    let name = format!("{}/tiny_verify_context", S::NAME);
+    c.bench_function(&name, |b| {
+        b.iter(|| ctx.verify(black_box(io), b"ad", black_box(&proof)).unwrap());
+    });
+}
+
+fn bench_tiny_suite<S: SuiteInfo>(c: &mut Criterion) {
+    bench_utils::print_info::<S>();
     bench_tiny_prove::<S>(c);
     bench_tiny_verify::<S>(c);
+    bench_tiny_verify_context::<S>(c);
 }
 
 fn bench_tiny(c: &mut Criterion) {
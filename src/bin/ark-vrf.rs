@@ -0,0 +1,403 @@
+//! # ark-vrf CLI
+//!
+//! Key and proof operations over the Bandersnatch suite
+//! ([`BandersnatchSha512Ell2`]), for ops debugging and fixtures generation.
+//!
+//! Keys, points, scalars and proofs are hex encoded in the suite's compressed
+//! encoding and decoded with the full validity checks. VRF inputs and
+//! additional data are hex encoded raw data; inputs are hashed to the curve.
+//! Results are printed as `name: value` lines.
+//!
+//! ```text
+//! $ ark-vrf keygen --seed 00
+//! $ ark-vrf prove --scheme tiny --secret <SECRET> --input 0a --ad 0b
+//! $ ark-vrf verify --scheme tiny --public <PUBLIC> --input 0a --output <OUTPUT> --ad 0b --proof <PROOF>
+//! ```
+//!
+//! With the `ring` feature, ring commitments, Ring VRF proofs and SRS files
+//! (uncompressed PCS parameters, as in `data/srs`) are supported as well.
+
+use ark_vrf::reexports::ark_serialize::CanonicalSerialize;
+use ark_vrf::suites::bandersnatch::*;
+use ark_vrf::utils::decode_canonical;
+use ark_vrf::{Error, pedersen, thin, tiny};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::process::ExitCode;
+
+type CliResult<T = ()> = Result<T, Box<dyn std::error::Error>>;
+
+/// Hex encoded bytes argument.
+#[derive(Debug, Clone)]
+struct Hex(Vec<u8>);
+
+impl std::str::FromStr for Hex {
+    type Err = hex::FromHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        hex::decode(s.trim_start_matches("0x")).map(Hex)
+    }
+}
+
+/// Proof scheme.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Scheme {
+    Tiny,
+    Thin,
+    Pedersen,
+}
+
+#[derive(Parser)]
+#[command(
+    name = "ark-vrf",
+    version,
+    about = "Bandersnatch VRF key and proof operations"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a key pair from a seed (random if not given).
+    Keygen {
+        /// Secret seed, zero padded to 32 bytes.
+        #[arg(long)]
+        seed: Option<Hex>,
+    },
+    /// Public key of a secret key.
+    Public {
+        #[arg(long)]
+        secret: Hex,
+    },
+    /// VRF output point and hash for an input.
+    Output {
+        #[arg(long)]
+        secret: Hex,
+        #[arg(long)]
+        input: Hex,
+    },
+    /// Prove a VRF evaluation.
+    Prove {
+        #[arg(long, value_enum)]
+        scheme: Scheme,
+        #[arg(long)]
+        secret: Hex,
+        #[arg(long)]
+        input: Hex,
+        #[arg(long, default_value = "")]
+        ad: Hex,
+    },
+    /// Verify a VRF proof.
+    Verify {
+        #[arg(long, value_enum)]
+        scheme: Scheme,
+        /// Public key (not used by Pedersen VRF).
+        #[arg(long)]
+        public: Option<Hex>,
+        #[arg(long)]
+        input: Hex,
+        #[arg(long)]
+        output: Hex,
+        #[arg(long, default_value = "")]
+        ad: Hex,
+        #[arg(long)]
+        proof: Hex,
+    },
+    /// Ring commitment of a list of public keys.
+    #[cfg(feature = "ring")]
+    RingCommitment {
+        /// SRS file.
+        #[arg(long)]
+        srs: std::path::PathBuf,
+        /// Ring public keys, comma separated.
+        #[arg(long, value_delimiter = ',', required = true)]
+        keys: Vec<Hex>,
+    },
+    /// Prove a Ring VRF evaluation, for a secret whose public key is in the ring.
+    #[cfg(feature = "ring")]
+    RingProve {
+        #[arg(long)]
+        srs: std::path::PathBuf,
+        #[arg(long, value_delimiter = ',', required = true)]
+        keys: Vec<Hex>,
+        #[arg(long)]
+        secret: Hex,
+        #[arg(long)]
+        input: Hex,
+        #[arg(long, default_value = "")]
+        ad: Hex,
+    },
+    /// Verify a Ring VRF proof against a ring commitment.
+    #[cfg(feature = "ring")]
+    RingVerify {
+        #[arg(long)]
+        srs: std::path::PathBuf,
+        /// Ring size the commitment was built for.
+        #[arg(long)]
+        ring_size: usize,
+        #[arg(long)]
+        commitment: Hex,
+        #[arg(long)]
+        input: Hex,
+        #[arg(long)]
+        output: Hex,
+        #[arg(long, default_value = "")]
+        ad: Hex,
+        #[arg(long)]
+        proof: Hex,
+    },
+    /// Sizes of an SRS file and the max ring size it supports.
+    #[cfg(feature = "ring")]
+    SrsInfo {
+        #[arg(long)]
+        srs: std::path::PathBuf,
+    },
+    /// Write the SRS powers needed for a ring size to a new file.
+    #[cfg(feature = "ring")]
+    SrsDump {
+        #[arg(long)]
+        srs: std::path::PathBuf,
+        #[arg(long)]
+        ring_size: usize,
+        /// Output file.
+        #[arg(long)]
+        out: std::path::PathBuf,
+    },
+}
+
+fn encode(value: &impl CanonicalSerialize) -> String {
+    let mut buf = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut buf)
+        .expect("serialization into a vector can't fail");
+    hex::encode(buf)
+}
+
+fn input(data: &Hex) -> Result<Input, Error> {
+    Input::new(&data.0).ok_or(Error::HashToCurveFailure)
+}
+
+fn print_keys(secret: &Secret) {
+    println!("secret: {}", encode(secret));
+    println!("public: {}", encode(&secret.public()));
+}
+
+fn print_output(output: &Output) {
+    println!("output: {}", encode(output));
+    println!("hash: {}", hex::encode(output.hash::<32>()));
+}
+
+fn run(command: Command) -> CliResult {
+    match command {
+        Command::Keygen { seed } => {
+            let mut buf = [0; 32];
+            match seed {
+                Some(Hex(seed)) if seed.len() > 32 => {
+                    return Err("seed longer than 32 bytes".into());
+                }
+                Some(Hex(seed)) => buf[..seed.len()].copy_from_slice(&seed),
+                None => ark_vrf::reexports::ark_std::rand::RngCore::fill_bytes(
+                    &mut ark_vrf::reexports::ark_std::rand::rngs::OsRng,
+                    &mut buf,
+                ),
+            }
+            print_keys(&Secret::from_seed(buf));
+        }
+        Command::Public { secret } => {
+            print_keys(&decode_canonical::<Secret>(&secret.0)?);
+        }
+        Command::Output {
+            secret,
+            input: data,
+        } => {
+            let secret: Secret = decode_canonical(&secret.0)?;
+            print_output(&secret.output(input(&data)?));
+        }
+        Command::Prove {
+            scheme,
+            secret,
+            input: data,
+            ad,
+        } => {
+            let secret: Secret = decode_canonical(&secret.0)?;
+            let io = secret.vrf_io(input(&data)?);
+            print_output(&io.output);
+            match scheme {
+                Scheme::Tiny => println!(
+                    "proof: {}",
+                    encode(&tiny::Prover::prove(&secret, io, &ad.0))
+                ),
+                Scheme::Thin => println!(
+                    "proof: {}",
+                    encode(&thin::Prover::prove(&secret, io, &ad.0))
+                ),
+                Scheme::Pedersen => {
+                    let (proof, blinding) = pedersen::Prover::prove(&secret, io, &ad.0);
+                    println!("proof: {}", encode(&proof));
+                    println!("blinding: {}", encode(&blinding));
+                }
+            }
+        }
+        Command::Verify {
+            scheme,
+            public,
+            input: data,
+            output,
+            ad,
+            proof,
+        } => {
+            let io = VrfIo {
+                input: input(&data)?,
+                output: decode_canonical(&output.0)?,
+            };
+            let public = || -> CliResult<Public> {
+                let public = public.as_ref().ok_or("missing public key")?;
+                Ok(decode_canonical(&public.0)?)
+            };
+            match scheme {
+                Scheme::Tiny => {
+                    let proof: TinyProof = decode_canonical(&proof.0)?;
+                    tiny::Verifier::verify(&public()?, io, &ad.0, &proof)?;
+                }
+                Scheme::Thin => {
+                    let proof: ThinProof = decode_canonical(&proof.0)?;
+                    thin::Verifier::verify(&public()?, io, &ad.0, &proof)?;
+                }
+                Scheme::Pedersen => {
+                    let proof: PedersenProof = decode_canonical(&proof.0)?;
+                    <Public as pedersen::Verifier<_>>::verify(io, &ad.0, &proof)?;
+                }
+            }
+            println!("valid");
+        }
+        #[cfg(feature = "ring")]
+        Command::RingCommitment { srs, keys } => {
+            let setup = ring::load_setup(&srs, keys.len())?;
+            let pks = ring::public_keys(&keys)?;
+            println!(
+                "commitment: {}",
+                encode(&setup.verifier_key(&pks)?.commitment())
+            );
+        }
+        #[cfg(feature = "ring")]
+        Command::RingProve {
+            srs,
+            keys,
+            secret,
+            input: data,
+            ad,
+        } => {
+            let setup = ring::load_setup(&srs, keys.len())?;
+            let pks = ring::public_keys(&keys)?;
+            let secret: Secret = decode_canonical(&secret.0)?;
+            let index = pks
+                .iter()
+                .position(|pk| *pk == secret.public().0)
+                .ok_or("secret public key not in the ring")?;
+            let prover = setup
+                .ring_context()
+                .ring_prover(setup.prover_key(&pks)?, index);
+            let io = secret.vrf_io(input(&data)?);
+            let proof = ark_vrf::ring::Prover::prove(&secret, io, &ad.0, &prover)?;
+            print_output(&io.output);
+            println!(
+                "commitment: {}",
+                encode(&setup.verifier_key(&pks)?.commitment())
+            );
+            println!("proof: {}", encode(&proof));
+        }
+        #[cfg(feature = "ring")]
+        Command::RingVerify {
+            srs,
+            ring_size,
+            commitment,
+            input: data,
+            output,
+            ad,
+            proof,
+        } => {
+            let setup = ring::load_setup(&srs, ring_size)?;
+            let commitment: RingCommitment = decode_canonical(&commitment.0)?;
+            let verifier = setup
+                .ring_context()
+                .ring_verifier(setup.verifier_key_from_commitment(commitment));
+            let io = VrfIo {
+                input: input(&data)?,
+                output: decode_canonical(&output.0)?,
+            };
+            let proof: RingProof = decode_canonical(&proof.0)?;
+            <Public as ark_vrf::ring::Verifier<_>>::verify(io, &ad.0, &proof, &verifier)?;
+            println!("valid");
+        }
+        #[cfg(feature = "ring")]
+        Command::SrsInfo { srs } => {
+            let params = ring::load_params(&srs)?;
+            let g1_len = params.powers_in_g1.len();
+            println!("g1_powers: {g1_len}");
+            println!("g2_powers: {}", params.powers_in_g2.len());
+            println!(
+                "max_ring_size: {}",
+                ark_vrf::ring::max_ring_size_from_pcs_domain_size::<BandersnatchSha512Ell2>(g1_len)
+            );
+        }
+        #[cfg(feature = "ring")]
+        Command::SrsDump {
+            srs,
+            ring_size,
+            out,
+        } => {
+            let setup = ring::load_setup(&srs, ring_size)?;
+            let mut buf = Vec::new();
+            setup
+                .pcs_params
+                .serialize_uncompressed(&mut buf)
+                .expect("serialization into a vector can't fail");
+            std::fs::write(&out, buf)?;
+            println!("g1_powers: {}", setup.pcs_params.powers_in_g1.len());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "ring")]
+mod ring {
+    use super::*;
+    use ark_vrf::reexports::ark_serialize::CanonicalDeserialize;
+    use std::path::Path;
+
+    pub fn load_params(path: &Path) -> CliResult<PcsParams> {
+        let buf = std::fs::read(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(PcsParams::deserialize_uncompressed_unchecked(&buf[..])?)
+    }
+
+    pub fn load_setup(path: &Path, ring_size: usize) -> CliResult<RingSetup> {
+        Ok(RingSetup::from_pcs_params(ring_size, load_params(path)?)?)
+    }
+
+    pub fn public_keys(keys: &[Hex]) -> CliResult<Vec<AffinePoint>> {
+        keys.iter()
+            .map(|key| Ok(decode_canonical::<Public>(&key.0)?.0))
+            .collect()
+    }
+}
+
+/// Secret split masks source with `secret-split-custom-rng` (OS randomness).
+#[cfg(feature = "secret-split-custom-rng")]
+fn os_random(buf: &mut [u8]) {
+    use ark_vrf::reexports::ark_std::rand::RngCore;
+    ark_vrf::reexports::ark_std::rand::rngs::OsRng.fill_bytes(buf);
+}
+
+#[cfg(feature = "secret-split-custom-rng")]
+ark_vrf::register_secret_split_rng!(os_random);
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
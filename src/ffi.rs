@@ -0,0 +1,536 @@
+//! # C FFI
+//!
+//! `extern "C"` bindings for the Bandersnatch SHA-512 Elligator2 suite
+//! ([`BandersnatchSha512Ell2`]), exchanging plain byte buffers in the suite's
+//! compressed encoding.
+//!
+//! Every function returns one of the `ARK_VRF_*` status codes. Buffers are
+//! passed as pointers to caller-owned memory of the documented fixed length
+//! (see the `ARK_VRF_*_LEN` constants); variable-length inputs take an
+//! explicit length. Output buffers are written only on success.
+//!
+//! The crate is built as an `rlib`; link the bindings into C/C++/Go software
+//! through a thin `cdylib`/`staticlib` crate depending on `ark-vrf` with the
+//! `ffi` feature, or via `cargo rustc --features ffi --crate-type cdylib`.
+
+#![allow(unsafe_code)]
+
+use crate::suites::bandersnatch::BandersnatchSha512Ell2;
+use crate::*;
+
+type S = BandersnatchSha512Ell2;
+
+/// Success.
+pub const ARK_VRF_OK: i32 = 0;
+/// Proof verification failed.
+pub const ARK_VRF_ERR_VERIFICATION: i32 = 1;
+/// Malformed encoding or invalid parameters.
+pub const ARK_VRF_ERR_INVALID_DATA: i32 = 2;
+/// A required pointer is null.
+pub const ARK_VRF_ERR_NULL_POINTER: i32 = 3;
+/// Output buffer is too small.
+pub const ARK_VRF_ERR_BUFFER_TOO_SMALL: i32 = 4;
+/// Hash-to-curve failed for the input data.
+pub const ARK_VRF_ERR_HASH_TO_CURVE: i32 = 5;
+
+/// Encoded secret key length.
+pub const ARK_VRF_SECRET_LEN: usize = 32;
+/// Encoded public key length.
+pub const ARK_VRF_PUBLIC_LEN: usize = 32;
+/// Encoded VRF output point length.
+pub const ARK_VRF_OUTPUT_LEN: usize = 32;
+/// VRF output hash length.
+pub const ARK_VRF_HASH_LEN: usize = 32;
+/// Encoded Tiny VRF proof length.
+pub const ARK_VRF_TINY_PROOF_LEN: usize = 48;
+/// Encoded Pedersen VRF proof length.
+pub const ARK_VRF_PEDERSEN_PROOF_LEN: usize = 160;
+
+fn error_code(err: Error) -> i32 {
+    match err {
+        Error::VerificationFailure => ARK_VRF_ERR_VERIFICATION,
+        Error::HashToCurveFailure => ARK_VRF_ERR_HASH_TO_CURVE,
+        Error::InvalidProofLength { .. } => ARK_VRF_ERR_BUFFER_TOO_SMALL,
+        _ => ARK_VRF_ERR_INVALID_DATA,
+    }
+}
+
+fn status(res: Result<(), i32>) -> i32 {
+    res.err().unwrap_or(ARK_VRF_OK)
+}
+
+/// # Safety
+///
+/// Unless `len` is 0, `ptr` must be valid for reads of `len` bytes.
+unsafe fn in_buf<'a>(ptr: *const u8, len: usize) -> Result<&'a [u8], i32> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(ARK_VRF_ERR_NULL_POINTER),
+        // SAFETY: guaranteed by the caller.
+        (false, _) => Ok(unsafe { core::slice::from_raw_parts(ptr, len) }),
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for writes of `len` bytes.
+unsafe fn out_buf<'a>(ptr: *mut u8, len: usize) -> Result<&'a mut [u8], i32> {
+    match ptr.is_null() {
+        true => Err(ARK_VRF_ERR_NULL_POINTER),
+        // SAFETY: guaranteed by the caller.
+        false => Ok(unsafe { core::slice::from_raw_parts_mut(ptr, len) }),
+    }
+}
+
+fn decode<T: CanonicalDeserialize>(buf: &[u8]) -> Result<T, i32> {
+    T::deserialize_compressed(buf).map_err(|_| ARK_VRF_ERR_INVALID_DATA)
+}
+
+fn encode<T: CanonicalSerialize>(value: &T, out: &mut [u8]) -> Result<(), i32> {
+    if value.compressed_size() > out.len() {
+        return Err(ARK_VRF_ERR_BUFFER_TOO_SMALL);
+    }
+    value
+        .serialize_compressed(out)
+        .map_err(|_| ARK_VRF_ERR_INVALID_DATA)
+}
+
+fn input(data: &[u8]) -> Result<Input<S>, i32> {
+    Input::new(data).ok_or(ARK_VRF_ERR_HASH_TO_CURVE)
+}
+
+/// Derive a key pair from a 32-byte seed.
+///
+/// # Safety
+///
+/// `seed` must be valid for reads of 32 bytes. `secret_out` and `public_out`
+/// must be valid for writes of [`ARK_VRF_SECRET_LEN`] and
+/// [`ARK_VRF_PUBLIC_LEN`] bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_secret_from_seed(
+    seed: *const u8,
+    secret_out: *mut u8,
+    public_out: *mut u8,
+) -> i32 {
+    status((|| {
+        let seed = unsafe { in_buf(seed, 32)? };
+        let secret = Secret::<S>::from_seed(seed.try_into().expect("length is 32"));
+        encode(&secret, unsafe { out_buf(secret_out, ARK_VRF_SECRET_LEN)? })?;
+        encode(&secret.public(), unsafe {
+            out_buf(public_out, ARK_VRF_PUBLIC_LEN)?
+        })
+    })())
+}
+
+/// Compute the public key of a secret key.
+///
+/// # Safety
+///
+/// `secret` must be valid for reads of [`ARK_VRF_SECRET_LEN`] bytes and
+/// `public_out` for writes of [`ARK_VRF_PUBLIC_LEN`] bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_public_from_secret(secret: *const u8, public_out: *mut u8) -> i32 {
+    status((|| {
+        let secret: Secret<S> = decode(unsafe { in_buf(secret, ARK_VRF_SECRET_LEN)? })?;
+        encode(&secret.public(), unsafe {
+            out_buf(public_out, ARK_VRF_PUBLIC_LEN)?
+        })
+    })())
+}
+
+/// Compute the VRF output point for the input derived from `data`.
+///
+/// # Safety
+///
+/// `secret` must be valid for reads of [`ARK_VRF_SECRET_LEN`] bytes, `data`
+/// for reads of `data_len` bytes and `output_out` for writes of
+/// [`ARK_VRF_OUTPUT_LEN`] bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_output(
+    secret: *const u8,
+    data: *const u8,
+    data_len: usize,
+    output_out: *mut u8,
+) -> i32 {
+    status((|| {
+        let secret: Secret<S> = decode(unsafe { in_buf(secret, ARK_VRF_SECRET_LEN)? })?;
+        let input = input(unsafe { in_buf(data, data_len)? })?;
+        encode(&secret.output(input), unsafe {
+            out_buf(output_out, ARK_VRF_OUTPUT_LEN)?
+        })
+    })())
+}
+
+/// Hash a VRF output point to [`ARK_VRF_HASH_LEN`] bytes.
+///
+/// # Safety
+///
+/// `output` must be valid for reads of [`ARK_VRF_OUTPUT_LEN`] bytes and
+/// `hash_out` for writes of [`ARK_VRF_HASH_LEN`] bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_output_hash(output: *const u8, hash_out: *mut u8) -> i32 {
+    status((|| {
+        let output: Output<S> = decode(unsafe { in_buf(output, ARK_VRF_OUTPUT_LEN)? })?;
+        let hash_out = unsafe { out_buf(hash_out, ARK_VRF_HASH_LEN)? };
+        hash_out.copy_from_slice(&output.hash::<ARK_VRF_HASH_LEN>());
+        Ok(())
+    })())
+}
+
+/// Tiny VRF proof for the input derived from `data`.
+///
+/// Writes the VRF output point and the proof.
+///
+/// # Safety
+///
+/// `secret` must be valid for reads of [`ARK_VRF_SECRET_LEN`] bytes, `data`
+/// and `ad` for reads of `data_len` and `ad_len` bytes. `output_out` and
+/// `proof_out` must be valid for writes of [`ARK_VRF_OUTPUT_LEN`] and
+/// [`ARK_VRF_TINY_PROOF_LEN`] bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_tiny_prove(
+    secret: *const u8,
+    data: *const u8,
+    data_len: usize,
+    ad: *const u8,
+    ad_len: usize,
+    output_out: *mut u8,
+    proof_out: *mut u8,
+) -> i32 {
+    use tiny::Prover;
+    status((|| {
+        let secret: Secret<S> = decode(unsafe { in_buf(secret, ARK_VRF_SECRET_LEN)? })?;
+        let io = secret.vrf_io(input(unsafe { in_buf(data, data_len)? })?);
+        let ad = unsafe { in_buf(ad, ad_len)? };
+        let proof_out = unsafe { out_buf(proof_out, ARK_VRF_TINY_PROOF_LEN)? };
+        let output_out = unsafe { out_buf(output_out, ARK_VRF_OUTPUT_LEN)? };
+        encode(&secret.prove(io, ad), proof_out)?;
+        encode(&io.output, output_out)
+    })())
+}
+
+/// Verify a Tiny VRF proof.
+///
+/// Returns [`ARK_VRF_OK`] if the proof is valid.
+///
+/// # Safety
+///
+/// `public`, `output` and `proof` must be valid for reads of
+/// [`ARK_VRF_PUBLIC_LEN`], [`ARK_VRF_OUTPUT_LEN`] and [`ARK_VRF_TINY_PROOF_LEN`]
+/// bytes. `data` and `ad` must be valid for reads of `data_len` and `ad_len`
+/// bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_tiny_verify(
+    public: *const u8,
+    data: *const u8,
+    data_len: usize,
+    output: *const u8,
+    ad: *const u8,
+    ad_len: usize,
+    proof: *const u8,
+) -> i32 {
+    use tiny::Verifier;
+    status((|| {
+        let public: Public<S> = decode(unsafe { in_buf(public, ARK_VRF_PUBLIC_LEN)? })?;
+        let io = VrfIo {
+            input: input(unsafe { in_buf(data, data_len)? })?,
+            output: decode(unsafe { in_buf(output, ARK_VRF_OUTPUT_LEN)? })?,
+        };
+        let ad = unsafe { in_buf(ad, ad_len)? };
+        let proof = unsafe { in_buf(proof, ARK_VRF_TINY_PROOF_LEN)? };
+        let proof: tiny::Proof<S> = decode(proof)?;
+        public.verify(io, ad, &proof).map_err(error_code)
+    })())
+}
+
+/// Pedersen VRF proof for the input derived from `data`.
+///
+/// Writes the VRF output point and the proof. The blinding factor is not
+/// returned.
+///
+/// # Safety
+///
+/// `secret` must be valid for reads of [`ARK_VRF_SECRET_LEN`] bytes, `data`
+/// and `ad` for reads of `data_len` and `ad_len` bytes. `output_out` and
+/// `proof_out` must be valid for writes of [`ARK_VRF_OUTPUT_LEN`] and
+/// [`ARK_VRF_PEDERSEN_PROOF_LEN`] bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_pedersen_prove(
+    secret: *const u8,
+    data: *const u8,
+    data_len: usize,
+    ad: *const u8,
+    ad_len: usize,
+    output_out: *mut u8,
+    proof_out: *mut u8,
+) -> i32 {
+    use pedersen::Prover;
+    status((|| {
+        let secret: Secret<S> = decode(unsafe { in_buf(secret, ARK_VRF_SECRET_LEN)? })?;
+        let io = secret.vrf_io(input(unsafe { in_buf(data, data_len)? })?);
+        let ad = unsafe { in_buf(ad, ad_len)? };
+        let (proof, _) = secret.prove(io, ad);
+        encode(&proof, unsafe {
+            out_buf(proof_out, ARK_VRF_PEDERSEN_PROOF_LEN)?
+        })?;
+        encode(&io.output, unsafe {
+            out_buf(output_out, ARK_VRF_OUTPUT_LEN)?
+        })
+    })())
+}
+
+/// Verify a Pedersen VRF proof.
+///
+/// Returns [`ARK_VRF_OK`] if the proof is valid.
+///
+/// # Safety
+///
+/// `output` and `proof` must be valid for reads of [`ARK_VRF_OUTPUT_LEN`] and
+/// [`ARK_VRF_PEDERSEN_PROOF_LEN`] bytes. `data` and `ad` must be valid for
+/// reads of `data_len` and `ad_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_pedersen_verify(
+    data: *const u8,
+    data_len: usize,
+    output: *const u8,
+    ad: *const u8,
+    ad_len: usize,
+    proof: *const u8,
+) -> i32 {
+    use pedersen::Verifier;
+    status((|| {
+        let io = VrfIo {
+            input: input(unsafe { in_buf(data, data_len)? })?,
+            output: decode(unsafe { in_buf(output, ARK_VRF_OUTPUT_LEN)? })?,
+        };
+        let ad = unsafe { in_buf(ad, ad_len)? };
+        let proof: pedersen::Proof<S> =
+            decode(unsafe { in_buf(proof, ARK_VRF_PEDERSEN_PROOF_LEN)? })?;
+        Public::<S>::verify(io, ad, &proof).map_err(error_code)
+    })())
+}
+
+#[cfg(feature = "ring")]
+fn ring_keys(ring: &[u8]) -> Result<Vec<AffinePoint<S>>, i32> {
+    if ring.len() % ARK_VRF_PUBLIC_LEN != 0 {
+        return Err(ARK_VRF_ERR_INVALID_DATA);
+    }
+    ring.chunks(ARK_VRF_PUBLIC_LEN)
+        .map(|pk| decode::<Public<S>>(pk).map(|pk| pk.0))
+        .collect()
+}
+
+/// Ring VRF proof for the input derived from `data`.
+///
+/// `setup` is a serialized [`RingSetup`](ring::RingSetup) and `ring` the
+/// concatenation of `ring_len` public keys, with the prover's key at
+/// `key_index`. On entry `proof_len` holds the capacity of `proof_out`; on
+/// success it is set to the number of bytes written.
+///
+/// The prover key is rebuilt on every call; long-lived provers should use the
+/// Rust API.
+///
+/// # Safety
+///
+/// `setup`, `ring`, `data` and `ad` must be valid for reads of `setup_len`,
+/// `ring_len * ARK_VRF_PUBLIC_LEN`, `data_len` and `ad_len` bytes. `secret`
+/// must be valid for reads of [`ARK_VRF_SECRET_LEN`] bytes. `output_out` must
+/// be valid for writes of [`ARK_VRF_OUTPUT_LEN`] bytes and `proof_out` for
+/// writes of `*proof_len` bytes.
+#[cfg(feature = "ring")]
+#[allow(clippy::too_many_arguments)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_ring_prove(
+    setup: *const u8,
+    setup_len: usize,
+    ring: *const u8,
+    ring_len: usize,
+    key_index: usize,
+    secret: *const u8,
+    data: *const u8,
+    data_len: usize,
+    ad: *const u8,
+    ad_len: usize,
+    output_out: *mut u8,
+    proof_out: *mut u8,
+    proof_len: *mut usize,
+) -> i32 {
+    use ring::Prover;
+    status((|| {
+        if proof_len.is_null() {
+            return Err(ARK_VRF_ERR_NULL_POINTER);
+        }
+        let setup: ring::RingSetup<S> = decode(unsafe { in_buf(setup, setup_len)? })?;
+        let ring_bytes = ring_len
+            .checked_mul(ARK_VRF_PUBLIC_LEN)
+            .ok_or(ARK_VRF_ERR_INVALID_DATA)?;
+        let pks = ring_keys(unsafe { in_buf(ring, ring_bytes)? })?;
+        let secret: Secret<S> = decode(unsafe { in_buf(secret, ARK_VRF_SECRET_LEN)? })?;
+        let io = secret.vrf_io(input(unsafe { in_buf(data, data_len)? })?);
+        let ad = unsafe { in_buf(ad, ad_len)? };
+
+        let prover_key = setup.prover_key(&pks).map_err(error_code)?;
+        let prover = setup.ring_context().ring_prover(prover_key, key_index);
+        let proof = secret.prove(io, ad, &prover).map_err(error_code)?;
+
+        // SAFETY: checked non-null above, validity guaranteed by the caller.
+        let capacity = unsafe { *proof_len };
+        let len = proof.compressed_size();
+        encode(&proof, unsafe { out_buf(proof_out, capacity)? })?;
+        encode(&io.output, unsafe {
+            out_buf(output_out, ARK_VRF_OUTPUT_LEN)?
+        })?;
+        unsafe { *proof_len = len };
+        Ok(())
+    })())
+}
+
+/// Verify a Ring VRF proof.
+///
+/// `setup` is a serialized [`RingSetup`](ring::RingSetup) and `ring` the
+/// concatenation of `ring_len` public keys. Returns [`ARK_VRF_OK`] if the
+/// proof is valid.
+///
+/// The verifier key is rebuilt on every call; long-lived verifiers should use
+/// the Rust API.
+///
+/// # Safety
+///
+/// `setup`, `ring`, `data`, `ad` and `proof` must be valid for reads of
+/// `setup_len`, `ring_len * ARK_VRF_PUBLIC_LEN`, `data_len`, `ad_len` and
+/// `proof_len` bytes. `output` must be valid for reads of
+/// [`ARK_VRF_OUTPUT_LEN`] bytes.
+#[cfg(feature = "ring")]
+#[allow(clippy::too_many_arguments)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ark_vrf_ring_verify(
+    setup: *const u8,
+    setup_len: usize,
+    ring: *const u8,
+    ring_len: usize,
+    data: *const u8,
+    data_len: usize,
+    output: *const u8,
+    ad: *const u8,
+    ad_len: usize,
+    proof: *const u8,
+    proof_len: usize,
+) -> i32 {
+    use ring::Verifier;
+    status((|| {
+        let setup: ring::RingSetup<S> = decode(unsafe { in_buf(setup, setup_len)? })?;
+        let ring_bytes = ring_len
+            .checked_mul(ARK_VRF_PUBLIC_LEN)
+            .ok_or(ARK_VRF_ERR_INVALID_DATA)?;
+        let pks = ring_keys(unsafe { in_buf(ring, ring_bytes)? })?;
+        let io = VrfIo {
+            input: input(unsafe { in_buf(data, data_len)? })?,
+            output: decode(unsafe { in_buf(output, ARK_VRF_OUTPUT_LEN)? })?,
+        };
+        let ad = unsafe { in_buf(ad, ad_len)? };
+        let proof: ring::Proof<S> = decode(unsafe { in_buf(proof, proof_len)? })?;
+
+        let verifier_key = setup.verifier_key(&pks).map_err(error_code)?;
+        let verifier = setup.ring_context().ring_verifier(verifier_key);
+        Public::<S>::verify(io, ad, &proof, &verifier).map_err(error_code)
+    })())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr::{null, null_mut};
+
+    #[test]
+    fn encoded_lengths() {
+        let secret = Secret::<S>::from_seed([0; 32]);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        assert_eq!(secret.compressed_size(), ARK_VRF_SECRET_LEN);
+        assert_eq!(secret.public().compressed_size(), ARK_VRF_PUBLIC_LEN);
+        assert_eq!(io.output.compressed_size(), ARK_VRF_OUTPUT_LEN);
+        assert_eq!(tiny::Proof::<S>::ENCODED_LEN, ARK_VRF_TINY_PROOF_LEN);
+        let (proof, _) = pedersen::Prover::prove(&secret, io, b"bar");
+        assert_eq!(proof.compressed_size(), ARK_VRF_PEDERSEN_PROOF_LEN);
+    }
+
+    #[test]
+    fn prove_verify() {
+        let mut secret = [0; ARK_VRF_SECRET_LEN];
+        let mut public = [0; ARK_VRF_PUBLIC_LEN];
+        let mut output = [0; ARK_VRF_OUTPUT_LEN];
+        let mut hash = [0; ARK_VRF_HASH_LEN];
+        let (data, ad) = (b"foo", b"bar");
+        unsafe {
+            let res = ark_vrf_secret_from_seed(
+                [0; 32].as_ptr(),
+                secret.as_mut_ptr(),
+                public.as_mut_ptr(),
+            );
+            assert_eq!(res, ARK_VRF_OK);
+            assert_eq!(
+                ark_vrf_output(
+                    secret.as_ptr(),
+                    data.as_ptr(),
+                    data.len(),
+                    output.as_mut_ptr()
+                ),
+                ARK_VRF_OK
+            );
+            assert_eq!(
+                ark_vrf_output_hash(output.as_ptr(), hash.as_mut_ptr()),
+                ARK_VRF_OK
+            );
+
+            let mut proof = [0; ARK_VRF_TINY_PROOF_LEN];
+            let mut proof_output = [0; ARK_VRF_OUTPUT_LEN];
+            let res = ark_vrf_tiny_prove(
+                secret.as_ptr(),
+                data.as_ptr(),
+                data.len(),
+                ad.as_ptr(),
+                ad.len(),
+                proof_output.as_mut_ptr(),
+                proof.as_mut_ptr(),
+            );
+            assert_eq!(res, ARK_VRF_OK);
+            assert_eq!(proof_output, output);
+            let verify = |ad: &[u8]| {
+                ark_vrf_tiny_verify(
+                    public.as_ptr(),
+                    data.as_ptr(),
+                    data.len(),
+                    output.as_ptr(),
+                    ad.as_ptr(),
+                    ad.len(),
+                    proof.as_ptr(),
+                )
+            };
+            assert_eq!(verify(ad), ARK_VRF_OK);
+            assert_eq!(verify(b"baz"), ARK_VRF_ERR_VERIFICATION);
+
+            let mut proof = [0; ARK_VRF_PEDERSEN_PROOF_LEN];
+            let res = ark_vrf_pedersen_prove(
+                secret.as_ptr(),
+                data.as_ptr(),
+                data.len(),
+                null(),
+                0,
+                proof_output.as_mut_ptr(),
+                proof.as_mut_ptr(),
+            );
+            assert_eq!(res, ARK_VRF_OK);
+            let res = ark_vrf_pedersen_verify(
+                data.as_ptr(),
+                data.len(),
+                output.as_ptr(),
+                null(),
+                0,
+                proof.as_ptr(),
+            );
+            assert_eq!(res, ARK_VRF_OK);
+
+            let res = ark_vrf_public_from_secret(secret.as_ptr(), null_mut());
+            assert_eq!(res, ARK_VRF_ERR_NULL_POINTER);
+        }
+    }
+}
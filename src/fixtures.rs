@@ -0,0 +1,166 @@
+//! # Test fixtures
+//!
+//! Deterministic keys, VRF inputs and outputs, proofs and small ring setups,
+//! for the unit tests of downstream crates.
+//!
+//! All the values are derived from a fixture index, so that tests are
+//! reproducible and different indices give unrelated values. The secret keys
+//! are public knowledge and the ring setup trapdoor is known to anyone: never
+//! use the fixtures outside of tests.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::{fixtures::Fixture, suites::bandersnatch::*};
+//!
+//! let fixture = Fixture::<BandersnatchSha512Ell2>::new(0);
+//! let proof = fixture.tiny_proof();
+//! assert!(fixture.public.verify(fixture.io, &fixture.ad, &proof).is_ok());
+//! ```
+
+use crate::*;
+
+/// Seed of the fixture values.
+pub const FIXTURE_SEED: [u8; 32] = *b"ark-vrf fixtures insecure seed!!";
+
+/// Secret key of the fixture `index`.
+pub fn secret<S: Suite>(index: u32) -> Secret<S> {
+    let mut seed = FIXTURE_SEED;
+    seed[..4].copy_from_slice(&index.to_le_bytes());
+    Secret::from_seed(seed)
+}
+
+/// Public keys of the fixtures `0..n`.
+pub fn public_keys<S: Suite>(n: u32) -> Vec<Public<S>> {
+    (0..n).map(|i| secret::<S>(i).public()).collect()
+}
+
+/// VRF input of the fixture `index`.
+pub fn input<S: Suite>(index: u32) -> Input<S> {
+    let data = [&b"ark-vrf fixture input"[..], &index.to_le_bytes()].concat();
+    Input::new(&data).expect("Fixture inputs are hashed to curve")
+}
+
+/// Deterministic VRF evaluation and proofs material.
+pub struct Fixture<S: Suite> {
+    /// Secret key.
+    pub secret: Secret<S>,
+    /// Public key.
+    pub public: Public<S>,
+    /// VRF I/O pair, for the fixture input.
+    pub io: VrfIo<S>,
+    /// Additional data.
+    pub ad: Vec<u8>,
+}
+
+impl<S: Suite> Fixture<S> {
+    /// Fixture `index`: its secret key evaluated at its input.
+    pub fn new(index: u32) -> Self {
+        let secret = secret::<S>(index);
+        let io = secret.vrf_io(input::<S>(index));
+        Self {
+            public: secret.public(),
+            secret,
+            io,
+            ad: [&b"ark-vrf fixture ad"[..], &index.to_le_bytes()].concat(),
+        }
+    }
+
+    /// Tiny VRF proof.
+    pub fn tiny_proof(&self) -> tiny::Proof<S> {
+        tiny::Prover::prove(&self.secret, self.io, &self.ad)
+    }
+
+    /// Thin VRF proof.
+    pub fn thin_proof(&self) -> thin::Proof<S> {
+        thin::Prover::prove(&self.secret, self.io, &self.ad)
+    }
+
+    /// Pedersen VRF proof and blinding factor.
+    pub fn pedersen_proof(&self) -> (pedersen::Proof<S>, ScalarField<S>)
+    where
+        S: pedersen::PedersenSuite,
+    {
+        pedersen::Prover::prove(&self.secret, self.io, &self.ad)
+    }
+}
+
+/// Ring of fixture keys with a small insecure setup.
+///
+/// The ring holds the public keys of the fixtures `0..ring_size`, so that
+/// the fixture `index` is at the ring index `index`.
+#[cfg(feature = "ring")]
+pub struct RingFixture<S: ring::RingSuite> {
+    /// Ring setup, from [`FIXTURE_SEED`].
+    pub setup: ring::RingSetup<S>,
+    /// Ring keys.
+    pub keys: Vec<AffinePoint<S>>,
+}
+
+#[cfg(feature = "ring")]
+impl<S: ring::RingSuite> RingFixture<S> {
+    /// Ring of `ring_size` fixture keys.
+    ///
+    /// The setup supports `ring_size` keys: keep it small (e.g. 8), as the
+    /// setup is generated on each call.
+    pub fn new(ring_size: u32) -> Self {
+        Self {
+            setup: ring::RingSetup::from_seed(ring_size as usize, FIXTURE_SEED),
+            keys: public_keys::<S>(ring_size).iter().map(|pk| pk.0).collect(),
+        }
+    }
+
+    /// Ring prover of the fixture `index`.
+    pub fn prover(&self, index: u32) -> ring::RingProver<S> {
+        let prover_key = self
+            .setup
+            .prover_key(&self.keys)
+            .expect("Ring fits the setup");
+        self.setup
+            .ring_context()
+            .ring_prover(prover_key, index as usize)
+    }
+
+    /// Ring verifier.
+    pub fn verifier(&self) -> ring::RingVerifier<S> {
+        let verifier_key = self
+            .setup
+            .verifier_key(&self.keys)
+            .expect("Ring fits the setup");
+        self.setup.ring_context().ring_verifier(verifier_key)
+    }
+
+    /// Ring VRF proof of the fixture `index`, with its material.
+    ///
+    /// Returns `Error::KeyIndexOutOfRange` if `index` is not in the ring.
+    pub fn proof(&self, index: u32) -> Result<(Fixture<S>, ring::Proof<S>), Error> {
+        let fixture = Fixture::<S>::new(index);
+        let proof = ring::Prover::prove(
+            &fixture.secret,
+            fixture.io,
+            &fixture.ad,
+            &self.prover(index),
+        )?;
+        Ok((fixture, proof))
+    }
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+
+    #[test]
+    fn fixtures() {
+        let fixture = Fixture::<S>::new(1);
+        assert_eq!(fixture.public, public_keys::<S>(2)[1]);
+        assert_ne!(fixture.public, Fixture::<S>::new(2).public);
+        assert_eq!(fixture.io, Fixture::<S>::new(1).io);
+
+        let (public, io, ad) = (&fixture.public, fixture.io, &fixture.ad);
+        assert!(tiny::Verifier::verify(public, io, ad, &fixture.tiny_proof()).is_ok());
+        assert!(thin::Verifier::verify(public, io, ad, &fixture.thin_proof()).is_ok());
+        let (proof, _) = fixture.pedersen_proof();
+        assert!(<Public<S> as pedersen::Verifier<S>>::verify(io, ad, &proof).is_ok());
+    }
+}
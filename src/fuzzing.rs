@@ -0,0 +1,174 @@
+//! # Fuzzing support
+//!
+//! [`Arbitrary`] implementations for keys, VRF inputs and outputs and the
+//! Tiny, Thin and Pedersen proofs, so that fuzzers can drive decoders and
+//! verifiers without building values from the raw field APIs.
+//!
+//! Points are multiples of the suite generator, so generated values are always
+//! valid; proofs are made of arbitrary components and are not expected to
+//! verify. [`Encoded`] yields byte strings close to a valid encoding, to reach
+//! the decoders' validity checks.
+//!
+//! Ring VRF proofs are not covered, as building one requires a ring setup.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::{fuzzing::Encoded, tiny, suites::bandersnatch::*};
+//!
+//! libfuzzer_sys::fuzz_target!(|case: (Public, VrfIo, Vec<u8>, Encoded<TinyProof>)| {
+//!     let (public, io, ad, proof) = case;
+//!     if let Ok(proof) = ark_vrf::utils::decode_canonical::<TinyProof>(&proof.bytes) {
+//!         let _ = tiny::Verifier::verify(&public, io, &ad, &proof);
+//!     }
+//! });
+//! ```
+
+use crate::*;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use core::marker::PhantomData;
+
+/// Arbitrary scalar.
+///
+/// Reduction of 64 arbitrary bytes, which is close to uniform for all the
+/// supported scalar fields.
+pub fn scalar<S: Suite>(u: &mut Unstructured) -> Result<ScalarField<S>> {
+    let bytes: [u8; 64] = u.arbitrary()?;
+    Ok(ScalarField::<S>::from_le_bytes_mod_order(&bytes))
+}
+
+/// Arbitrary point, as an arbitrary multiple of the suite generator.
+pub fn point<S: Suite>(u: &mut Unstructured) -> Result<AffinePoint<S>> {
+    Ok((S::generator() * scalar::<S>(u)?).into_affine())
+}
+
+#[cfg(feature = "prove")]
+impl<'a, S: Suite> Arbitrary<'a> for Secret<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Secret::from_seed(u.arbitrary()?))
+    }
+}
+
+impl<'a, S: Suite> Arbitrary<'a> for Public<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Public(point::<S>(u)?))
+    }
+}
+
+impl<'a, S: Suite> Arbitrary<'a> for Input<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Input(point::<S>(u)?))
+    }
+}
+
+impl<'a, S: Suite> Arbitrary<'a> for Output<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Output(point::<S>(u)?))
+    }
+}
+
+impl<'a, S: Suite> Arbitrary<'a> for VrfIo<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(VrfIo {
+            input: u.arbitrary()?,
+            output: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a, S: tiny::TinySuite> Arbitrary<'a> for tiny::Proof<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Challenges are truncated, as the ones of a proof.
+        let mut c = [0; utils::common::CHALLENGE_LEN];
+        u.fill_buffer(&mut c)?;
+        Ok(tiny::Proof {
+            c: ScalarField::<S>::from_le_bytes_mod_order(&c),
+            s: scalar::<S>(u)?,
+        })
+    }
+}
+
+impl<'a, S: thin::ThinVrfSuite> Arbitrary<'a> for thin::Proof<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(thin::Proof {
+            r: point::<S>(u)?,
+            s: scalar::<S>(u)?,
+        })
+    }
+}
+
+impl<'a, S: pedersen::PedersenSuite> Arbitrary<'a> for pedersen::Proof<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(pedersen::Proof {
+            pk_com: point::<S>(u)?,
+            r: point::<S>(u)?,
+            ok: point::<S>(u)?,
+            s: scalar::<S>(u)?,
+            sb: scalar::<S>(u)?,
+        })
+    }
+}
+
+/// Byte string close to the compressed encoding of an arbitrary `T`.
+///
+/// Either the encoding itself, or the encoding with a flipped bit, truncated,
+/// extended, or replaced by arbitrary bytes.
+pub struct Encoded<T> {
+    /// Encoded bytes.
+    pub bytes: Vec<u8>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> core::fmt::Debug for Encoded<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Encoded").field(&self.bytes).finish()
+    }
+}
+
+impl<'a, T: Arbitrary<'a> + CanonicalSerialize> Arbitrary<'a> for Encoded<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut bytes = Vec::new();
+        T::arbitrary(u)?
+            .serialize_compressed(&mut bytes)
+            .expect("serialization into a vector can't fail");
+        match u.int_in_range(0..=4)? {
+            0 => (),
+            1 if !bytes.is_empty() => {
+                let i = u.choose_index(bytes.len())?;
+                bytes[i] ^= 1 << u.int_in_range(0..=7)?;
+            }
+            2 => bytes.truncate(u.choose_index(bytes.len() + 1)?),
+            3 => {
+                let len = u.int_in_range(1..=32)?;
+                bytes.extend_from_slice(u.bytes(len)?);
+            }
+            _ => bytes = u.arbitrary()?,
+        }
+        Ok(Self {
+            bytes,
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use suites::testing::TestSuite;
+
+    #[test]
+    fn arbitrary_values() {
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+
+        let secret: Secret<TestSuite> = u.arbitrary().unwrap();
+        let io = secret.vrf_io(u.arbitrary().unwrap());
+        let proof: tiny::Proof<TestSuite> = u.arbitrary().unwrap();
+        assert!(tiny::Verifier::verify(&secret.public(), io, b"", &proof).is_err());
+
+        for _ in 0..16 {
+            let encoded: Encoded<pedersen::Proof<TestSuite>> = u.arbitrary().unwrap();
+            let _ = utils::decode_canonical::<pedersen::Proof<TestSuite>>(&encoded.bytes);
+        }
+    }
+}
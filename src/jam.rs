@@ -0,0 +1,183 @@
+//! # JAM conformance
+//!
+//! Runs the published JAM Bandersnatch ring VRF test vectors against this
+//! crate's verifier.
+//!
+//! Vectors are JSON arrays of objects with hex encoded fields, as in
+//! `data/vectors/bandersnatch_sha-512_ell2_ring.json`. For each vector the
+//! input, output hash and ring commitment are recomputed and compared with
+//! the published values, then the ring proof (`proof_*` fields followed by
+//! `ring_proof`) is verified against the published commitment. Secret keys
+//! and blinding factors are ignored.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::jam;
+//!
+//! let count = jam::verify_vectors(&json, &ring_setup).expect("JAM conformance");
+//! ```
+
+use crate::ring::Verifier;
+use crate::suites::bandersnatch::{
+    BandersnatchSha512Ell2, Input, Output, Public, RingCommitment, RingProof, RingSetup, VrfIo,
+};
+use crate::utils::decode_canonical;
+use crate::*;
+use serde_json::Value;
+
+/// Length of the VRF output hash (`beta`) used by JAM.
+pub const OUTPUT_HASH_LEN: usize = 32;
+
+/// A JAM ring VRF test vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingVector {
+    /// Free form description.
+    pub comment: String,
+    /// VRF input data.
+    pub alpha: Vec<u8>,
+    /// Additional data.
+    pub ad: Vec<u8>,
+    /// Encoded input point.
+    pub h: Vec<u8>,
+    /// Encoded output point.
+    pub gamma: Vec<u8>,
+    /// Output hash.
+    pub beta: Vec<u8>,
+    /// Encoded ring proof.
+    pub proof: Vec<u8>,
+    /// Concatenated encoded ring public keys.
+    pub ring_pks: Vec<u8>,
+    /// Encoded ring commitment.
+    pub ring_pks_com: Vec<u8>,
+}
+
+fn hex_decode(hex: &str) -> Result<Vec<u8>, Error> {
+    let nibble = |c: u8| match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::InvalidData),
+    };
+    let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    if hex.len() % 2 != 0 {
+        return Err(Error::InvalidData);
+    }
+    hex.chunks(2)
+        .map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?))
+        .collect()
+}
+
+fn encode<T: CanonicalSerialize>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut buf = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut buf)?;
+    Ok(buf)
+}
+
+impl RingVector {
+    /// Build a vector from a JSON object.
+    ///
+    /// Returns `Error::InvalidData` if a field is missing or isn't hex.
+    pub fn from_json(value: &Value) -> Result<Self, Error> {
+        let field = |name: &str| {
+            value
+                .get(name)
+                .and_then(Value::as_str)
+                .ok_or(Error::InvalidData)
+        };
+        let bytes = |name: &str| hex_decode(field(name)?);
+        let mut proof = Vec::new();
+        for name in [
+            "proof_pk_com",
+            "proof_r",
+            "proof_ok",
+            "proof_s",
+            "proof_sb",
+            "ring_proof",
+        ] {
+            proof.extend(bytes(name)?);
+        }
+        Ok(Self {
+            comment: field("comment").unwrap_or_default().into(),
+            alpha: bytes("alpha")?,
+            ad: bytes("ad")?,
+            h: bytes("h")?,
+            gamma: bytes("gamma")?,
+            beta: bytes("beta")?,
+            proof,
+            ring_pks: bytes("ring_pks")?,
+            ring_pks_com: bytes("ring_pks_com")?,
+        })
+    }
+
+    /// Check the vector against `setup`.
+    ///
+    /// Returns `Error::VerificationFailure` if a recomputed value doesn't
+    /// match the published one or if the proof doesn't verify.
+    pub fn verify(&self, setup: &RingSetup) -> Result<(), Error> {
+        let input = Input::new(&self.alpha).ok_or(Error::HashToCurveFailure)?;
+        let output: Output = decode_canonical(&self.gamma)?;
+        if encode(&input)? != self.h || output.hash::<OUTPUT_HASH_LEN>()[..] != self.beta[..] {
+            return Err(Error::VerificationFailure);
+        }
+
+        let pk_len = BandersnatchSha512Ell2::generator().compressed_size();
+        if self.ring_pks.len() % pk_len != 0 {
+            return Err(Error::InvalidData);
+        }
+        let ring_pks = self
+            .ring_pks
+            .chunks(pk_len)
+            .map(|pk| decode_canonical::<Public>(pk).map(|pk| pk.0))
+            .collect::<Result<Vec<_>, _>>()?;
+        let commitment: RingCommitment = decode_canonical(&self.ring_pks_com)?;
+        if encode(&setup.verifier_key(&ring_pks)?.commitment())? != self.ring_pks_com {
+            return Err(Error::VerificationFailure);
+        }
+
+        let proof: RingProof = decode_canonical(&self.proof)?;
+        let verifier = setup
+            .ring_context()
+            .ring_verifier(setup.verifier_key_from_commitment(commitment));
+        let io = VrfIo { input, output };
+        Public::verify(io, &self.ad, &proof, &verifier)
+    }
+}
+
+/// Parse a JSON array of ring vectors.
+pub fn parse_vectors(json: &str) -> Result<Vec<RingVector>, Error> {
+    let values: Vec<Value> = serde_json::from_str(json).map_err(|_| Error::InvalidData)?;
+    values.iter().map(RingVector::from_json).collect()
+}
+
+/// Parse and check a JSON array of ring vectors against `setup`.
+///
+/// Returns the number of checked vectors, or the index of the first failing
+/// vector together with the failure.
+pub fn verify_vectors(json: &str, setup: &RingSetup) -> Result<usize, (usize, Error)> {
+    let vectors = parse_vectors(json).map_err(|err| (0, err))?;
+    for (index, vector) in vectors.iter().enumerate() {
+        vector.verify(setup).map_err(|err| (index, err))?;
+    }
+    Ok(vectors.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::vectors::RingSuiteExt;
+
+    #[test]
+    fn repo_vectors() {
+        let path = crate::testing::VECTORS_DIR.to_string() + "/bandersnatch_sha-512_ell2_ring.json";
+        let json = std::fs::read_to_string(path).unwrap();
+        let setup = BandersnatchSha512Ell2::ring_setup();
+        assert_eq!(verify_vectors(&json, setup), Ok(7));
+
+        let mut vectors = parse_vectors(&json).unwrap();
+        vectors[0].ad.push(0);
+        assert_eq!(vectors[0].verify(setup), Err(Error::VerificationFailure));
+        vectors[1].ring_pks_com[0] ^= 1;
+        assert!(vectors[1].verify(setup).is_err());
+    }
+}
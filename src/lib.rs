@@ -64,13 +64,43 @@
 //!
 //! ## Features
 //!
-//! - `default`: `std`
-//! - `full`: Enables all features listed below except `secret-split`, `parallel`, `asm`, `test-vectors`.
+//! - `default`: `std`, `prove`
+//! - `full`: Enables all features listed below except `secret-split`, `secret-split-custom-rng`, `getrandom`, `parallel`, `asm`, `test-vectors`, `vectors`, `arbitrary`, `fixtures`, `test-utils`, `cli`, `input-cache`, `static-tables`, `tai-fixed`, `tracing`.
+//!   The bindings (`ffi`, `wasm`, `uniffi`), `jam` and the `shake128` suite are
+//!   not listed here and are not enabled by `full` either.
+//! - `prove`: Secret keys and provers. Without it only the verification side
+//!   is compiled, for verification-only consumers (light clients, contract
+//!   hosts) building with the default features disabled. The features handling secret keys (`secret-split`,
+//!   `secret-split-custom-rng`, `getrandom`,
+//!   `ffi`, `wasm`, `uniffi`, `cli`, `vectors`, `fixtures`) enable it. The tests
+//!   require it.
 //! - `secret-split`: Split-secret scalar multiplication. Secret scalar is split into the sum
 //!   of two scalars, which randomly mutate but retain the same sum. Incurs 2x penalty in some internal
-//!   sensible scalar multiplications, but provides side channel defenses.
+//!   sensible scalar multiplications, but provides side channel defenses. Masks are drawn from
+//!   the OS randomness (enables `getrandom`).
+//! - `secret-split-custom-rng`: Same as `secret-split`, with the masks drawn from a source
+//!   registered by the application with [`register_secret_split_rng`] instead of the OS
+//!   randomness, e.g. on `wasm32-unknown-unknown` (see [`utils::secret_split`]).
+//! - `getrandom`: OS randomness (`OsRng`) for the `secret-split` masks. Not available on
+//!   `wasm32-unknown-unknown`.
 //! - `ring`: Ring-VRF for the curves supporting it.
 //! - `test-vectors`: Deterministic ring-vrf proof. Useful for reproducible test vectors generation.
+//! - `vectors`: Public test vectors generation and processing API (see [`vectors`]).
+//! - `arbitrary`: [`arbitrary::Arbitrary`] implementations for fuzzing (see `fuzzing`).
+//! - `fixtures`: Deterministic keys, proofs and ring setups for downstream
+//!   tests (see `fixtures`). Insecure, never enable outside of tests.
+//! - `test-utils`: Small BLS12-381 SRS with a known secret embedded in the crate,
+//!   for ring proofs in tests (see `ring::srs::insecure_testing_ring_setup`).
+//!   Insecure, never enable outside of tests.
+//! - `cli`: `ark-vrf` command line tool for Bandersnatch key and proof operations.
+//! - `input-cache`: LRU cache of hash-to-curve results (see [`utils::input_cache`]).
+//! - `tai-fixed`: Input independent Try-And-Increment running time for the
+//!   TAI suites (see [`Suite::TAI_FIXED`]). Same outputs, slower hash-to-curve.
+//! - `static-tables`: Process-wide fixed-base tables of the generator and
+//!   blinding base, built on first use (see [`utils::fixed_base`]). Each
+//!   table holds a few thousand points for the whole process lifetime.
+//! - `tracing`: `DEBUG` level [`tracing`](https://docs.rs/tracing) spans around
+//!   proving, verification, hash-to-curve, SRS loading and ring key construction.
 //!
 //! ### Curves
 //!
@@ -82,7 +112,9 @@
 //!
 //! ### Arkworks optimizations
 //!
-//! - `parallel`: Parallel execution where worth using `rayon`.
+//! - `parallel`: Parallel execution where worth using `rayon`. Also enables the
+//!   parallel FFTs and MSMs of the ring proof backend, which dominate the ring
+//!   proving time.
 //! - `asm`: Assembly implementation of some low level operations.
 //!
 //! ## License
@@ -100,7 +132,23 @@ use ark_std::vec::Vec;
 use utils::transcript::Transcript;
 use zeroize::Zeroize;
 
+/// Enter a `tracing` span lasting until the end of the enclosing scope.
+///
+/// Expands to nothing if the `tracing` feature is disabled.
+#[cfg(feature = "tracing")]
+macro_rules! trace_span {
+    ($name:literal $(, $($fields:tt)*)?) => {
+        let _span = tracing::debug_span!($name $(, $($fields)*)?).entered();
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace_span {
+    ($name:literal $(, $($fields:tt)*)?) => {};
+}
+
 pub mod pedersen;
+pub mod remote;
 pub mod suites;
 pub mod thin;
 pub mod tiny;
@@ -109,6 +157,33 @@ pub mod utils;
 #[cfg(feature = "ring")]
 pub mod ring;
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+
+#[cfg(feature = "signature")]
+pub mod rustcrypto;
+
+#[cfg(feature = "jam")]
+pub mod jam;
+
+#[cfg(any(test, feature = "vectors"))]
+pub mod vectors;
+
+#[cfg(feature = "arbitrary")]
+pub mod fuzzing;
+
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!("ark_vrf");
+
 #[cfg(test)]
 mod testing;
 
@@ -130,21 +205,106 @@ pub type ScalarField<S> = <AffinePoint<S> as AffineRepr>::ScalarField;
 pub type CurveConfig<S> = <AffinePoint<S> as AffineRepr>::Config;
 
 /// Crate error type.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Error), uniffi(flat_error))]
 pub enum Error {
     /// Proof verification failed.
     VerificationFailure,
-    /// Invalid input data (e.g. point not in the prime-order subgroup,
-    /// deserialization failure, ring size exceeding parameters).
+    /// Invalid input data (e.g. deserialization failure, ring size exceeding
+    /// parameters).
     InvalidData,
+    /// Point coordinates do not satisfy the curve equation.
+    PointNotOnCurve,
+    /// Point is on the curve but not in the prime-order subgroup.
+    PointNotInSubgroup,
+    /// Scalar encoding is not the canonical (fully reduced) one.
+    NonCanonicalScalar,
+    /// Encoded proof (or proof buffer) has the wrong length.
+    InvalidProofLength {
+        /// Expected length in bytes.
+        expected: usize,
+        /// Actual length in bytes.
+        got: usize,
+    },
+    /// Not enough SRS powers for the requested parameters.
+    SrsTooShort {
+        /// Number of powers required.
+        needed: usize,
+        /// Number of powers available.
+        got: usize,
+    },
+    /// Hash-to-curve did not produce a valid point.
+    HashToCurveFailure,
+    /// Point is the identity.
+    IdentityPoint,
+    /// Encoding is not the canonical one of the decoded value.
+    NonCanonicalEncoding,
+    /// Ring key index is not in the ring.
+    KeyIndexOutOfRange {
+        /// Key index.
+        index: usize,
+        /// Ring size.
+        ring_size: usize,
+    },
+    /// Ring key at the prover key index is not the prover public key.
+    KeyNotInRing,
+    /// Ring exceeds the max ring size of the parameters.
+    RingTooLarge {
+        /// Ring size.
+        size: usize,
+        /// Max ring size.
+        max: usize,
+    },
+    /// Suite is not a built-in suite enabled by features.
+    UnsupportedSuite,
 }
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::VerificationFailure => f.write_str("proof verification failed"),
+            Error::InvalidData => f.write_str("invalid data"),
+            Error::PointNotOnCurve => f.write_str("point is not on the curve"),
+            Error::PointNotInSubgroup => f.write_str("point is not in the prime-order subgroup"),
+            Error::NonCanonicalScalar => f.write_str("non-canonical scalar encoding"),
+            Error::InvalidProofLength { expected, got } => {
+                write!(
+                    f,
+                    "invalid proof length: expected {expected} bytes, got {got}"
+                )
+            }
+            Error::SrsTooShort { needed, got } => {
+                write!(f, "SRS too short: needed {needed} powers, got {got}")
+            }
+            Error::HashToCurveFailure => f.write_str("hash-to-curve failed"),
+            Error::IdentityPoint => f.write_str("point is the identity"),
+            Error::NonCanonicalEncoding => f.write_str("non-canonical encoding"),
+            Error::KeyIndexOutOfRange { index, ring_size } => {
+                write!(f, "key index {index} out of ring of size {ring_size}")
+            }
+            Error::KeyNotInRing => f.write_str("public key not in the ring at the key index"),
+            Error::RingTooLarge { size, max } => {
+                write!(f, "ring too large: {size} keys, max {max}")
+            }
+            Error::UnsupportedSuite => f.write_str("unsupported suite"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
 impl From<ark_serialize::SerializationError> for Error {
     fn from(_err: ark_serialize::SerializationError) -> Self {
         Error::InvalidData
     }
 }
 
+impl From<utils::TaiError> for Error {
+    fn from(_err: utils::TaiError) -> Self {
+        Error::HashToCurveFailure
+    }
+}
+
 /// Defines a cipher suite.
 ///
 /// Configures the elliptic curve, transcript, and core operations (nonce
@@ -159,6 +319,19 @@ pub trait Suite: Copy {
     /// Constructed via [`suites::SuiteId::new`] from (curve, hash, h2c, version) bytes.
     const SUITE_ID: suites::SuiteId;
 
+    /// Thin VRF transcript domain separation tags.
+    ///
+    /// Defaults to [`thin::Domain::DEFAULT`]. Suites overriding the tags
+    /// define a distinct Thin VRF instance (see [`thin::SchemeId`]).
+    const THIN_VRF_DOMAIN: thin::Domain = thin::Domain::DEFAULT;
+
+    /// Use the fixed-iteration Try-And-Increment in [`Self::data_to_point`].
+    ///
+    /// Both variants map data to the same point, so the switch doesn't affect
+    /// the suite outputs, only the hash-to-curve running time. Defaults to
+    /// the `tai-fixed` feature, which turns it on for the built-in TAI suites.
+    const TAI_FIXED: bool = cfg!(feature = "tai-fixed");
+
     /// Curve point in affine representation.
     ///
     /// The point is guaranteed to be in the correct prime order subgroup
@@ -179,12 +352,23 @@ pub trait Suite: Copy {
         Self::Affine::generator()
     }
 
+    /// Process-wide fixed-base table of [`Suite::generator`].
+    ///
+    /// Used by [`Secret::from_scalar`] and, when no [`utils::SuitePrecomp`]
+    /// is given, by the Pedersen prover and verifier. Defaults to `None`.
+    /// Built-in suites return a [`utils::StaticTable`], which is only built
+    /// with the `static-tables` feature.
+    fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        None
+    }
+
     /// Generate a nonce scalar from the secret key and transcript state.
     ///
     /// The transcript typically carries shared state from `vrf_transcript`,
     /// binding the nonce to the I/O pairs and additional data.
     ///
     /// Defaults to [`utils::nonce`] (deterministic, inspired by RFC-8032 section 5.1.6).
+    /// See [`utils::nonce_rfc_6979`] for an RFC-6979 alternative.
     #[inline(always)]
     fn nonce(sk: &ScalarField<Self>, transcript: Option<Self::Transcript>) -> ScalarField<Self> {
         utils::nonce::<Self>(sk, transcript)
@@ -196,6 +380,7 @@ pub trait Suite: Copy {
     /// The transcript typically carries shared state from `vrf_transcript`.
     ///
     /// Defaults to [`utils::challenge`] (inspired by RFC-9381 section 5.4.3).
+    /// See [`utils::challenge_framed`] for a variant with length-prefixed points.
     #[inline(always)]
     fn challenge(
         pts: &[&AffinePoint<Self>],
@@ -204,16 +389,32 @@ pub trait Suite: Copy {
         utils::challenge::<Self>(pts, transcript)
     }
 
+    /// Absorb the additional data into the VRF transcript.
+    ///
+    /// Defaults to [`utils::absorb_ad`] (length-prefixed data).
+    /// See [`utils::absorb_ad_prehashed`] for a variant absorbing a digest of
+    /// the data.
+    #[inline(always)]
+    fn absorb_ad(transcript: &mut Self::Transcript, ad: &[u8]) {
+        utils::absorb_ad::<Self>(transcript, ad)
+    }
+
     /// Hash data to a curve point.
     ///
     /// The input `data` is the raw pre-image; any salting must be applied
     /// by the caller before invoking this method.
     ///
-    /// Defaults to [`utils::hash_to_curve_tai`] (try-and-increment).
+    /// Defaults to [`utils::hash_to_curve_tai`] (try-and-increment), or to
+    /// [`utils::hash_to_curve_tai_fixed`] (input independent TAI running time)
+    /// if [`Self::TAI_FIXED`] is set.
     /// Override for alternative methods like [`utils::hash_to_curve_ell2_xmd`] (Elligator2).
     #[inline(always)]
     fn data_to_point(data: &[u8]) -> Option<AffinePoint<Self>> {
-        utils::hash_to_curve_tai::<Self>(data)
+        if Self::TAI_FIXED {
+            utils::hash_to_curve_tai_fixed::<Self>(data)
+        } else {
+            utils::hash_to_curve_tai::<Self>(data)
+        }
     }
 
     /// Map a curve point to a hash value.
@@ -229,6 +430,9 @@ pub trait Suite: Copy {
 ///
 /// Contains the private scalar and cached public key.
 /// Implements automatic zeroization on drop.
+///
+/// Requires the `prove` feature.
+#[cfg(feature = "prove")]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Secret<S: Suite> {
     /// Secret scalar.
@@ -237,12 +441,14 @@ pub struct Secret<S: Suite> {
     pub(crate) public: Public<S>,
 }
 
+#[cfg(feature = "prove")]
 impl<S: Suite> Drop for Secret<S> {
     fn drop(&mut self) {
         self.scalar.zeroize()
     }
 }
 
+#[cfg(feature = "prove")]
 impl<S: Suite> CanonicalSerialize for Secret<S> {
     fn serialize_with_mode<W: ark_std::io::prelude::Write>(
         &self,
@@ -257,29 +463,37 @@ impl<S: Suite> CanonicalSerialize for Secret<S> {
     }
 }
 
+#[cfg(feature = "prove")]
 impl<S: Suite> CanonicalDeserialize for Secret<S> {
     fn deserialize_with_mode<R: ark_std::io::prelude::Read>(
         reader: R,
-        compress: ark_serialize::Compress,
-        validate: ark_serialize::Validate,
+        _compress: ark_serialize::Compress,
+        _validate: ark_serialize::Validate,
     ) -> Result<Self, ark_serialize::SerializationError> {
-        let scalar = <ScalarField<S> as CanonicalDeserialize>::deserialize_with_mode(
-            reader, compress, validate,
-        )?;
-        Ok(Self::from_scalar(scalar))
+        // Both the modes share the scalar encoding. Zero is rejected regardless
+        // of `validate`, as it would yield the identity public key.
+        utils::common::read_secret_scalar::<S>(reader).map(Self::from_scalar)
     }
 }
 
+#[cfg(feature = "prove")]
 impl<S: Suite> ark_serialize::Valid for Secret<S> {
     fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        if self.scalar.is_zero() {
+            return Err(ark_serialize::SerializationError::InvalidData);
+        }
         self.scalar.check()
     }
 }
 
+#[cfg(feature = "prove")]
 impl<S: Suite> Secret<S> {
     /// Construct a `Secret` from the given scalar.
+    ///
+    /// The scalar is not validated: a zero scalar yields the identity public
+    /// key. Use [`Self::try_from_bytes`] for untrusted encodings.
     pub fn from_scalar(scalar: ScalarField<S>) -> Self {
-        let public = Public((S::generator() * scalar).into_affine());
+        let public = Public(utils::generator_mul::<S>(scalar).into_affine());
         Self { scalar, public }
     }
 
@@ -316,6 +530,15 @@ impl<S: Suite> Secret<S> {
         Self::from_scalar(scalar)
     }
 
+    /// Construct a `Secret` from the compressed encoding of its scalar.
+    ///
+    /// Returns `Error::NonCanonicalScalar` if `bytes` is not the canonical
+    /// encoding of a scalar (wrong length or not fully reduced) or if the
+    /// scalar is zero.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        utils::decode_secret_scalar::<S>(bytes).map(Self::from_scalar)
+    }
+
     /// Construct an ephemeral `Secret` using the provided randomness source.
     pub fn from_rand(rng: &mut impl ark_std::rand::RngCore) -> Self {
         let mut seed = [0u8; 32];
@@ -323,6 +546,39 @@ impl<S: Suite> Secret<S> {
         Self::from_seed(seed)
     }
 
+    /// Derive the child secret for `context` (e.g. a protocol role).
+    ///
+    /// The child seed is squeezed from the suite transcript absorbing a
+    /// dedicated domain separation tag, the secret scalar and the
+    /// length-prefixed `context` (little-endian `u32`), then expanded via
+    /// [`Self::from_seed`]. Children of different contexts are independent,
+    /// and the child public key can't be derived from the parent public key
+    /// (hardened derivation only).
+    pub fn derive_child(&self, context: &[u8]) -> Self {
+        let context_len = u32::try_from(context.len()).expect("context too long");
+        let mut transcript = S::Transcript::new(S::SUITE_ID);
+        transcript.absorb_raw(&[utils::common::DomSep::ChildKey as u8]);
+        transcript.absorb_serialize(&self.scalar);
+        transcript.absorb_raw(&context_len.to_le_bytes());
+        transcript.absorb_raw(context);
+        let mut seed = [0u8; 32];
+        transcript.squeeze_raw(&mut seed);
+        let child = Self::from_seed(seed);
+        seed.zeroize();
+        child
+    }
+
+    /// Convert to the same key of the suite `T` sharing the curve.
+    ///
+    /// See [`suites::SameCurve`] for the consequences of reusing a key
+    /// across suites.
+    pub fn to_suite<T: Suite>(&self) -> Secret<T>
+    where
+        S: suites::SameCurve<T>,
+    {
+        Secret::from_scalar(self.scalar)
+    }
+
     /// Get the secret scalar.
     pub fn scalar(&self) -> &ScalarField<S> {
         &self.scalar
@@ -338,6 +594,47 @@ impl<S: Suite> Secret<S> {
         Output(smul!(input.0, self.scalar).into_affine())
     }
 
+    /// Get the VRF output points relative to many inputs.
+    ///
+    /// Same as [`Self::output`] for each input, but faster for more than a
+    /// few inputs (see [`utils::msm::fixed_scalar_mul`]).
+    ///
+    /// With the `secret-split` feature the secret scalar is split once for
+    /// all the inputs, doubling the cost.
+    pub fn output_batch(&self, inputs: &[Input<S>]) -> Vec<Output<S>> {
+        trace_span!("output_batch", inputs = inputs.len());
+        const WINDOW: usize = 4;
+        let points: Vec<_> = inputs.iter().map(|input| input.0).collect();
+        #[cfg(not(any(feature = "secret-split", feature = "secret-split-custom-rng")))]
+        let outputs = utils::msm::fixed_scalar_mul(&points, &self.scalar, WINDOW);
+        #[cfg(any(feature = "secret-split", feature = "secret-split-custom-rng"))]
+        let outputs = {
+            let x1: ScalarField<S> = utils::secret_split::mask();
+            let x2 = self.scalar - x1;
+            let outputs1 = utils::msm::fixed_scalar_mul(&points, &x1, WINDOW);
+            let outputs2 = utils::msm::fixed_scalar_mul(&points, &x2, WINDOW);
+            let sums: Vec<_> = outputs1.iter().zip(outputs2).map(|(a, b)| *a + b).collect();
+            CurveGroup::normalize_batch(&sums)
+        };
+        outputs.into_iter().map(Output).collect()
+    }
+
+    /// Keyed PRF of `msg`, for applications never proving the result.
+    ///
+    /// Hashes (see [`Output::hash_n`]) the secret multiple of the point
+    /// derived from `msg` by Try-And-Increment under a dedicated PRF tag,
+    /// which replaces the hash-to-curve one. No [`Input::new`] data maps to
+    /// these points, so VRF outputs, even of inputs crafted by a third
+    /// party, never reveal PRF values.
+    ///
+    /// Only the secret key owner can compute or check the result: use a VRF
+    /// proof instead whenever a third party must be convinced of it.
+    ///
+    /// Returns `None` if hash-to-curve fails.
+    pub fn prf<const N: usize>(&self, msg: &[u8]) -> Option<[u8; N]> {
+        utils::hash_to_curve::prf_to_point::<S>(msg).map(|pt| self.output(Input(pt)).hash_n())
+    }
+
     /// Get the VRF input-output pair relative to input.
     pub fn vrf_io(&self, input: Input<S>) -> VrfIo<S> {
         VrfIo {
@@ -350,30 +647,54 @@ impl<S: Suite> Secret<S> {
 /// Public key generic over the cipher suite.
 ///
 /// Elliptic curve point representing the public component of a VRF key pair.
-#[derive(Debug, Copy, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Debug, Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Public<S: Suite>(pub AffinePoint<S>);
 
 impl<S: Suite> Public<S> {
     /// Construct from an affine point with subgroup validation.
     ///
-    /// Returns `Error::InvalidData` if the point is not in the prime-order subgroup.
+    /// Returns `Error::PointNotOnCurve` or `Error::PointNotInSubgroup` if the
+    /// point is not in the prime-order subgroup.
     pub fn from_affine(value: AffinePoint<S>) -> Result<Self, Error> {
-        ark_serialize::Valid::check(&value).map_err(|_| Error::InvalidData)?;
+        utils::common::check_point::<S>(&value)?;
         Ok(Self(value))
     }
 
+    /// Construct from an affine point with subgroup and identity validation.
+    ///
+    /// Same as [`Self::from_affine`], but also returns `Error::IdentityPoint`
+    /// for the identity point, which verifiers reject.
+    pub fn try_from_affine(value: AffinePoint<S>) -> Result<Self, Error> {
+        if value.is_zero() {
+            return Err(Error::IdentityPoint);
+        }
+        Self::from_affine(value)
+    }
+
     /// Construct from an affine point without subgroup checks.
     ///
     /// The caller must ensure `value` is in the prime-order subgroup.
     pub fn from_affine_unchecked(value: AffinePoint<S>) -> Self {
         Self(value)
     }
+
+    /// Convert to the same key of the suite `T` sharing the curve.
+    ///
+    /// Returns `None` if the key has no image in the `T` curve model.
+    /// See [`suites::SameCurve`] for the consequences of reusing a key
+    /// across suites.
+    pub fn to_suite<T: Suite>(&self) -> Option<Public<T>>
+    where
+        S: suites::SameCurve<T>,
+    {
+        S::map_point(&self.0).map(Public)
+    }
 }
 
 /// VRF input point generic over the cipher suite.
 ///
 /// Elliptic curve point representing the VRF input.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Debug, Clone, Copy, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Input<S: Suite>(pub AffinePoint<S>);
 
 impl<S: Suite> Input<S> {
@@ -381,24 +702,98 @@ impl<S: Suite> Input<S> {
     ///
     /// Maps arbitrary data to a curve point via hash-to-curve.
     pub fn new(data: &[u8]) -> Option<Self> {
+        trace_span!("hash_to_curve", data_len = data.len());
         S::data_to_point(data).map(Input)
     }
+
+    /// Construct from an application context and payload.
+    ///
+    /// Hashes the framed pre-image
+    /// `tag || len(domain) || domain || epoch || len(payload) || payload`
+    /// (lengths as little-endian `u32`, epoch as little-endian `u64`) via
+    /// [`Self::new`]. Binding the domain and epoch into the input prevents
+    /// replaying a proof in a different application or epoch.
+    pub fn from_context(ctx: &InputContext, payload: &[u8]) -> Option<Self> {
+        let domain_len = u32::try_from(ctx.domain.len()).ok()?;
+        let payload_len = u32::try_from(payload.len()).ok()?;
+        let mut data = Vec::with_capacity(17 + ctx.domain.len() + payload.len());
+        data.push(utils::common::DomSep::InputContext as u8);
+        data.extend_from_slice(&domain_len.to_le_bytes());
+        data.extend_from_slice(ctx.domain);
+        data.extend_from_slice(&ctx.epoch.to_le_bytes());
+        data.extend_from_slice(&payload_len.to_le_bytes());
+        data.extend_from_slice(payload);
+        Self::new(&data)
+    }
+
+    /// Construct from a salt and data.
+    ///
+    /// Hashes the framed pre-image `tag || len(salt) || salt || data` (length
+    /// as little-endian `u32`) via [`Self::new`], so that the salt and data
+    /// boundary is unambiguous. Unlike the additional data of the proofs, the
+    /// salt changes the input and thus the VRF output.
+    pub fn new_salted(salt: &[u8], data: &[u8]) -> Option<Self> {
+        let salt_len = u32::try_from(salt.len()).ok()?;
+        let mut buf = Vec::with_capacity(5 + salt.len() + data.len());
+        buf.push(utils::common::DomSep::InputSalt as u8);
+        buf.extend_from_slice(&salt_len.to_le_bytes());
+        buf.extend_from_slice(salt);
+        buf.extend_from_slice(data);
+        Self::new(&buf)
+    }
+
+    /// Construct from data salted with the `public` key compressed encoding.
+    ///
+    /// Binds the input to the prover public key, as the RFC-9381
+    /// `encode_to_curve_salt`: the same data gives a different input for each
+    /// public key.
+    pub fn new_public_salted(public: &Public<S>, data: &[u8]) -> Option<Self> {
+        Self::new_salted(&utils::common::encode_compressed(&public.0), data)
+    }
+}
+
+/// Application context bound into VRF inputs by [`Input::from_context`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputContext<'a> {
+    /// Application domain (e.g. protocol and purpose).
+    pub domain: &'a [u8],
+    /// Epoch (or round, slot, ...) the input is valid for.
+    pub epoch: u64,
+}
+
+impl<'a> InputContext<'a> {
+    /// Construct a new context.
+    pub const fn new(domain: &'a [u8], epoch: u64) -> Self {
+        Self { domain, epoch }
+    }
 }
 
 impl<S: Suite> Input<S> {
     /// Construct from an affine point with subgroup validation.
     ///
-    /// Returns `Error::InvalidData` if the point is not in the prime-order subgroup.
+    /// Returns `Error::PointNotOnCurve` or `Error::PointNotInSubgroup` if the
+    /// point is not in the prime-order subgroup.
     ///
     /// Note: this only validates subgroup membership, not that the point was
     /// produced by hash-to-curve. The caller is still responsible for ensuring
     /// the point is not in a known discrete-log relation with the suite
     /// generator (required for Thin-VRF soundness).
     pub fn from_affine(value: AffinePoint<S>) -> Result<Self, Error> {
-        ark_serialize::Valid::check(&value).map_err(|_| Error::InvalidData)?;
+        utils::common::check_point::<S>(&value)?;
         Ok(Self(value))
     }
 
+    /// Construct from an affine point with subgroup and identity validation.
+    ///
+    /// Same as [`Self::from_affine`], but also returns `Error::IdentityPoint`
+    /// for the identity point.
+    pub fn try_from_affine(value: AffinePoint<S>) -> Result<Self, Error> {
+        if value.is_zero() {
+            return Err(Error::IdentityPoint);
+        }
+        Self::from_affine(value)
+    }
+
     /// Construct from an affine point without subgroup checks.
     ///
     /// # Safety
@@ -416,18 +811,30 @@ impl<S: Suite> Input<S> {
 /// VRF output point generic over the cipher suite.
 ///
 /// Elliptic curve point representing the VRF output.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Debug, Clone, Copy, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Output<S: Suite>(pub AffinePoint<S>);
 
 impl<S: Suite> Output<S> {
     /// Construct from an affine point with subgroup validation.
     ///
-    /// Returns `Error::InvalidData` if the point is not in the prime-order subgroup.
+    /// Returns `Error::PointNotOnCurve` or `Error::PointNotInSubgroup` if the
+    /// point is not in the prime-order subgroup.
     pub fn from_affine(value: AffinePoint<S>) -> Result<Self, Error> {
-        ark_serialize::Valid::check(&value).map_err(|_| Error::InvalidData)?;
+        utils::common::check_point::<S>(&value)?;
         Ok(Self(value))
     }
 
+    /// Construct from an affine point with subgroup and identity validation.
+    ///
+    /// Same as [`Self::from_affine`], but also returns `Error::IdentityPoint`
+    /// for the identity point, which verifiers reject.
+    pub fn try_from_affine(value: AffinePoint<S>) -> Result<Self, Error> {
+        if value.is_zero() {
+            return Err(Error::IdentityPoint);
+        }
+        Self::from_affine(value)
+    }
+
     /// Construct from an affine point without subgroup checks.
     ///
     /// The caller must ensure `value` is in the prime-order subgroup.
@@ -441,8 +848,114 @@ impl<S: Suite> Output<S> {
     pub fn hash<const N: usize>(&self) -> [u8; N] {
         S::point_to_hash(&self.0)
     }
+
+    /// Hash the output point to an `N` bytes string bound to its length.
+    ///
+    /// Unlike [`Self::hash`], whose shorter hashes are prefixes of the
+    /// longer ones, hashes of different lengths are unrelated (see
+    /// [`utils::point_to_hash_n`]). Prefer this to truncating [`Self::hash`]
+    /// when different consumers need randomness of different lengths.
+    pub fn hash_n<const N: usize>(&self) -> [u8; N] {
+        utils::point_to_hash_n::<S, N>(&self.0)
+    }
+
+    /// Hash the output point to a uniform scalar (see [`utils::point_to_scalar`]).
+    ///
+    /// Prefer this to reducing [`Self::hash`] bytes modulo the scalar field
+    /// order, which is biased.
+    pub fn to_scalar(&self) -> ScalarField<S> {
+        utils::point_to_scalar::<S>(&self.0)
+    }
+
+    /// Hash the output point to a uniform base field element (see
+    /// [`utils::point_to_base_field`]).
+    pub fn to_base_field(&self) -> BaseField<S>
+    where
+        BaseField<S>: PrimeField,
+    {
+        utils::point_to_base_field::<S>(&self.0)
+    }
+
+    /// Verify that this is the output of `input` under `public`.
+    ///
+    /// Verifies `proof` for the single I/O pair `(input, self)` and `ad`,
+    /// with the verifier of the proof scheme.
+    ///
+    /// Returns `Err(Error::VerificationFailure)` if the proof is invalid.
+    pub fn verify<P: OutputProof<S>>(
+        &self,
+        public: &Public<S>,
+        input: Input<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &P,
+    ) -> Result<(), Error> {
+        let io = VrfIo {
+            input,
+            output: *self,
+        };
+        proof.verify_output(public, io, ad.as_ref())
+    }
+
+    /// Returns `true` if [`Self::verify`] succeeds.
+    pub fn is_consistent<P: OutputProof<S>>(
+        &self,
+        public: &Public<S>,
+        input: Input<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &P,
+    ) -> bool {
+        self.verify(public, input, ad, proof).is_ok()
+    }
+}
+
+/// Proof of a VRF evaluation under a public key.
+///
+/// Implemented by the Tiny and Thin VRF proofs, so that [`Output::verify`]
+/// works without importing the `Verifier` trait of the proof scheme.
+pub trait OutputProof<S: Suite> {
+    /// Verify the proof for `io` and `ad` under `public`.
+    fn verify_output(&self, public: &Public<S>, io: VrfIo<S>, ad: &[u8]) -> Result<(), Error>;
+}
+
+// Comparison traits for the point wrappers, not requiring them on the suite
+// type. Ordering is lexicographic over the compressed encoding, so that it is
+// stable across implementations (e.g. for sorted nullifier sets).
+macro_rules! impl_point_cmp {
+    ($($type:ident),*) => {$(
+        impl<S: Suite> PartialEq for $type<S> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<S: Suite> Eq for $type<S> {}
+
+        impl<S: Suite> core::hash::Hash for $type<S> {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        impl<S: Suite> PartialOrd for $type<S> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<S: Suite> Ord for $type<S> {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                if self.0 == other.0 {
+                    return core::cmp::Ordering::Equal;
+                }
+                let encode = utils::common::encode_compressed::<AffinePoint<S>>;
+                encode(&self.0).cmp(&encode(&other.0))
+            }
+        }
+    )*};
 }
 
+impl_point_cmp!(Public, Input, Output);
+
 /// VRF input-output pair.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct VrfIo<S: Suite> {
@@ -456,12 +969,34 @@ impl<S: Suite> AsRef<[VrfIo<S>]> for VrfIo<S> {
     }
 }
 
+/// Expand the items only if the `prove` feature is enabled.
+///
+/// Evaluated against the features of this crate, also when expanded by
+/// exported macros in downstream crates.
+#[cfg(feature = "prove")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! cfg_prove {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "prove"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! cfg_prove {
+    ($($item:item)*) => {};
+}
+
 /// Type aliases for the given suite.
 #[macro_export]
 macro_rules! suite_types {
     ($suite:ident) => {
-        #[allow(dead_code)]
-        pub type Secret = $crate::Secret<$suite>;
+        $crate::cfg_prove! {
+            #[allow(dead_code)]
+            pub type Secret = $crate::Secret<$suite>;
+        }
         #[allow(dead_code)]
         pub type Public = $crate::Public<$suite>;
         #[allow(dead_code)]
@@ -490,6 +1025,14 @@ macro_rules! suite_types {
         pub type ThinBatchVerifier = $crate::thin::BatchVerifier<$suite>;
         #[allow(dead_code)]
         pub type VrfIo = $crate::VrfIo<$suite>;
+        #[allow(dead_code)]
+        pub type SuitePrecomp = $crate::utils::SuitePrecomp<$suite>;
+        #[allow(dead_code)]
+        pub type VerifierContext<'a> = $crate::utils::VerifierContext<'a, $suite>;
+        #[allow(dead_code)]
+        pub type ProveRequest = $crate::remote::ProveRequest<$suite>;
+        #[allow(dead_code)]
+        pub type ProveResponse = $crate::remote::ProveResponse<$suite>;
     };
 }
 
@@ -513,6 +1056,148 @@ mod tests {
         assert_eq!(expected, hex::encode(output.hash::<32>()));
     }
 
+    #[test]
+    fn output_batch() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let inputs: Vec<_> = (0..9_u8).map(|i| Input::new(&[i]).unwrap()).collect();
+        let outputs = secret.output_batch(&inputs);
+        assert_eq!(outputs.len(), inputs.len());
+        for (input, output) in inputs.iter().zip(&outputs) {
+            assert_eq!(*output, secret.output(*input));
+        }
+        assert!(secret.output_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn derive_child() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let child = secret.derive_child(b"role");
+        assert_eq!(child, secret.derive_child(b"role"));
+        assert_ne!(child, secret);
+        assert_ne!(child, secret.derive_child(b"other-role"));
+        assert_ne!(child, secret.derive_child(b""));
+        assert_ne!(child, Secret::from_seed([1; 32]).derive_child(b"role"));
+        assert_ne!(child.derive_child(b"role"), child);
+    }
+
+    #[test]
+    fn prf() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let value = secret.prf::<32>(b"foo").unwrap();
+        assert_eq!(value, secret.prf::<32>(b"foo").unwrap());
+        assert_ne!(value, secret.prf::<32>(b"bar").unwrap());
+        assert_ne!(value, Secret::from_seed([1; 32]).prf::<32>(b"foo").unwrap());
+
+        // Inputs framed as the PRF ones don't reach the PRF points.
+        let tag = utils::common::DomSep::Prf as u8;
+        for data in [
+            [&[tag][..], &3u32.to_le_bytes(), b"foo"].concat(),
+            [&[tag][..], b"foo"].concat(),
+            b"foo".to_vec(),
+        ] {
+            let input = Input::new(&data).unwrap();
+            assert_ne!(value, secret.output(input).hash_n::<32>());
+        }
+        let output = secret.output(Input::new(b"foo").unwrap());
+        assert_ne!(value, output.hash_n::<32>());
+    }
+
+    #[test]
+    fn output_hash_n() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let output = secret.output(Input::new(b"foo").unwrap());
+        let h16 = output.hash_n::<16>();
+        let h32 = output.hash_n::<32>();
+        assert_ne!(h16[..], h32[..16]);
+        assert_ne!(h32, output.hash::<32>());
+        assert_eq!(h32, output.hash_n::<32>());
+        let h64 = output.hash_n::<64>();
+        assert_ne!(h32[..], h64[..32]);
+    }
+
+    #[test]
+    fn output_to_field() {
+        use ark_ff::PrimeField;
+        use utils::{FromUniformBytes, Transcript};
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let output = secret.output(Input::new(b"foo").unwrap());
+        let scalar = output.to_scalar();
+        let other = secret.output(Input::new(b"bar").unwrap());
+        assert_eq!(scalar, output.to_scalar());
+        assert_ne!(scalar, other.to_scalar());
+
+        // Wide reduction of the tagged output hash.
+        let mut t = <TestSuite as Suite>::Transcript::new(TestSuite::SUITE_ID);
+        t.absorb_raw(&[utils::dst::OUTPUT_SCALAR]);
+        t.absorb_serialize(&output.0);
+        let mut buf = vec![0; ScalarField::<TestSuite>::UNIFORM_LEN];
+        t.squeeze_raw(&mut buf);
+        assert_eq!(
+            scalar,
+            ScalarField::<TestSuite>::from_le_bytes_mod_order(&buf)
+        );
+
+        // Independent of the scalar hash.
+        let base = output.to_base_field();
+        assert_eq!(base, output.to_base_field());
+        assert_ne!(base, BaseField::<TestSuite>::from_le_bytes_mod_order(&buf));
+    }
+
+    #[test]
+    fn output_verify() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let public = secret.public();
+        let input = Input::new(b"foo").unwrap();
+        let io = secret.vrf_io(input);
+
+        let proof = secret.prove(io, b"bar");
+        assert!(io.output.verify(&public, input, b"bar", &proof).is_ok());
+        let proof = thin::Prover::prove(&secret, io, b"bar");
+        assert!(io.output.is_consistent(&public, input, b"bar", &proof));
+        assert!(!io.output.is_consistent(&public, input, b"baz", &proof));
+
+        let other = secret.output(Input::new(b"baz").unwrap());
+        assert_eq!(
+            other.verify(&public, input, b"bar", &proof),
+            Err(Error::VerificationFailure)
+        );
+    }
+
+    #[test]
+    fn secret_decoding() {
+        use ark_serialize::Valid;
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let mut buf = Vec::new();
+        secret.serialize_compressed(&mut buf).unwrap();
+        assert_eq!(Secret::try_from_bytes(&buf).unwrap(), secret);
+        assert_eq!(Secret::deserialize_compressed(&buf[..]).unwrap(), secret);
+
+        let zero = vec![0; buf.len()];
+        assert_eq!(
+            Secret::try_from_bytes(&zero),
+            Err(Error::NonCanonicalScalar)
+        );
+        assert!(Secret::deserialize_compressed(&zero[..]).is_err());
+        assert!(Secret::deserialize_compressed_unchecked(&zero[..]).is_err());
+        assert!(
+            Secret::from_scalar(ScalarField::<TestSuite>::zero())
+                .check()
+                .is_err()
+        );
+
+        let unreduced = vec![0xff; buf.len()];
+        assert_eq!(
+            Secret::try_from_bytes(&unreduced),
+            Err(Error::NonCanonicalScalar)
+        );
+        assert_eq!(
+            Secret::try_from_bytes(&buf[1..]),
+            Err(Error::NonCanonicalScalar)
+        );
+    }
+
     #[test]
     fn prove_uniqueness_vulnerability() {
         use ark_ff::BigInteger;
@@ -614,4 +1299,225 @@ mod tests {
         // Two different outputs for the same input and public key.
         assert_ne!(honest_output.hash::<32>(), malicious_output.hash::<32>());
     }
+
+    #[test]
+    fn from_affine_errors() {
+        use ark_std::{One, Zero};
+
+        type S = TestSuite;
+
+        let generator = S::generator();
+        assert!(crate::Public::<S>::from_affine(generator).is_ok());
+
+        let off_curve =
+            AffinePoint::<S>::new_unchecked(generator.x, generator.y + BaseField::<S>::one());
+        assert_eq!(
+            crate::Public::<S>::from_affine(off_curve),
+            Err(Error::PointNotOnCurve)
+        );
+
+        // Order 2 point (0, -1).
+        let low_order =
+            AffinePoint::<S>::new_unchecked(BaseField::<S>::zero(), -BaseField::<S>::one());
+        assert_eq!(
+            crate::Input::<S>::from_affine(low_order),
+            Err(Error::PointNotInSubgroup)
+        );
+        assert_eq!(
+            crate::Output::<S>::from_affine(low_order),
+            Err(Error::PointNotInSubgroup)
+        );
+
+        let identity = AffinePoint::<S>::zero();
+        assert!(crate::Public::<S>::from_affine(identity).is_ok());
+        assert_eq!(
+            crate::Public::<S>::try_from_affine(identity),
+            Err(Error::IdentityPoint)
+        );
+        assert_eq!(
+            crate::Input::<S>::try_from_affine(identity),
+            Err(Error::IdentityPoint)
+        );
+        assert_eq!(
+            crate::Output::<S>::try_from_affine(identity),
+            Err(Error::IdentityPoint)
+        );
+        assert_eq!(
+            crate::Output::<S>::try_from_affine(low_order),
+            Err(Error::PointNotInSubgroup)
+        );
+        assert!(crate::Input::<S>::try_from_affine(generator).is_ok());
+    }
+
+    #[test]
+    fn verifiers_reject_identity() {
+        type S = TestSuite;
+
+        // Identity input and output satisfy the DLEQ relation for any secret.
+        let identity_io = VrfIo {
+            input: Input::from_affine_unchecked(AffinePoint::<S>::zero()),
+            output: Output::from_affine_unchecked(AffinePoint::<S>::zero()),
+        };
+        let secret = Secret::from_seed(TEST_SEED);
+        let public = secret.public();
+
+        let proof = secret.prove(identity_io, b"foo");
+        assert_eq!(
+            public.verify(identity_io, b"foo", &proof),
+            Err(Error::VerificationFailure)
+        );
+
+        let proof = thin::Prover::prove(&secret, identity_io, b"foo");
+        assert_eq!(
+            thin::Verifier::verify(&public, identity_io, b"foo", &proof),
+            Err(Error::VerificationFailure)
+        );
+        let mut batch = thin::BatchVerifier::new();
+        batch.push(&public, identity_io, b"foo", &proof);
+        assert_eq!(batch.verify(), Err(Error::VerificationFailure));
+
+        let (proof, _) = pedersen::Prover::prove(&secret, identity_io, b"foo");
+        assert_eq!(
+            <crate::Public<S> as pedersen::Verifier<S>>::verify(identity_io, b"foo", &proof),
+            Err(Error::VerificationFailure)
+        );
+        let mut batch = pedersen::BatchVerifier::new();
+        batch.push(identity_io, b"foo", &proof);
+        assert_eq!(batch.verify(), Err(Error::VerificationFailure));
+
+        // Identity public key.
+        let identity_public = crate::Public::<S>::from_affine_unchecked(AffinePoint::<S>::zero());
+        let io = secret.vrf_io(Input::new(b"bar").unwrap());
+        let proof = secret.prove(io, b"foo");
+        assert_eq!(
+            identity_public.verify(io, b"foo", &proof),
+            Err(Error::VerificationFailure)
+        );
+    }
+
+    #[test]
+    fn proof_canonicality() {
+        type S = TestSuite;
+
+        // Identity with the x sign flag set decodes to the identity.
+        let mut buf = Vec::new();
+        AffinePoint::<S>::zero()
+            .serialize_compressed(&mut buf)
+            .unwrap();
+        assert!(utils::decode_canonical::<AffinePoint<S>>(&buf).is_ok());
+        *buf.last_mut().unwrap() |= 0x80;
+        assert_eq!(
+            utils::decode_canonical::<AffinePoint<S>>(&buf),
+            Err(Error::NonCanonicalEncoding)
+        );
+        *buf.last_mut().unwrap() &= 0x7f;
+        buf.push(0);
+        assert_eq!(
+            utils::decode_canonical::<AffinePoint<S>>(&buf),
+            Err(Error::NonCanonicalEncoding)
+        );
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+
+        let mut proof = secret.prove(io, b"bar");
+        assert!(proof.is_canonical());
+        proof.c = -proof.c;
+        assert_eq!(proof.check_canonical(), Err(Error::NonCanonicalScalar));
+
+        let mut proof = thin::Prover::prove(&secret, io, b"bar");
+        assert!(proof.is_canonical());
+        let mut buf = Vec::new();
+        proof.serialize_compressed(&mut buf).unwrap();
+        assert!(utils::decode_canonical::<thin::Proof<S>>(&buf).is_ok());
+        proof.r = AffinePoint::<S>::new_unchecked(proof.r.x, proof.r.x);
+        assert_eq!(proof.check_canonical(), Err(Error::PointNotOnCurve));
+
+        let (proof, _) = pedersen::Prover::prove(&secret, io, b"bar");
+        assert!(proof.is_canonical());
+    }
+
+    #[test]
+    fn proof_structure() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let (proof, _) = pedersen::Prover::prove(&secret, io, b"bar");
+        assert_eq!(proof.check_structure(), Ok(()));
+
+        let mut bad = proof.clone();
+        bad.ok = AffinePoint::<TestSuite>::zero();
+        assert!(bad.is_canonical());
+        assert_eq!(bad.check_structure(), Err(Error::IdentityPoint));
+        let mut bad = proof.clone();
+        bad.r = AffinePoint::<TestSuite>::new_unchecked(proof.r.x, proof.r.x);
+        assert_eq!(bad.check_structure(), Err(Error::PointNotOnCurve));
+    }
+
+    #[test]
+    fn input_from_context() {
+        let ctx = InputContext::new(b"app", 7);
+        let input = Input::from_context(&ctx, b"payload").unwrap();
+        assert_eq!(Input::from_context(&ctx, b"payload"), Some(input));
+
+        // Bound to epoch, domain and payload.
+        let other_epoch = InputContext::new(b"app", 8);
+        assert_ne!(Input::from_context(&other_epoch, b"payload"), Some(input));
+        let other_domain = InputContext::new(b"app2", 7);
+        assert_ne!(Input::from_context(&other_domain, b"payload"), Some(input));
+        assert_ne!(Input::from_context(&ctx, b"payload2"), Some(input));
+
+        // Unambiguous domain/payload boundary.
+        let a = Input::from_context(&InputContext::new(b"ab", 0), b"c");
+        let b = Input::from_context(&InputContext::new(b"a", 0), b"bc");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn input_salted() {
+        let input = Input::new_salted(b"salt", b"data").unwrap();
+        assert_ne!(Input::new(b"saltdata"), Some(input));
+        assert_ne!(Input::new_salted(b"", b"data"), Input::new(b"data"));
+
+        // Unambiguous salt/data boundary.
+        let a = Input::new_salted(b"ab", b"c");
+        let b = Input::new_salted(b"a", b"bc");
+        assert_ne!(a, b);
+
+        let public = Secret::from_seed(TEST_SEED).public();
+        let mut salt = Vec::new();
+        public.0.serialize_compressed(&mut salt).unwrap();
+        let input = Input::new_public_salted(&public, b"data");
+        assert_eq!(input, Input::new_salted(&salt, b"data"));
+        let other = Secret::from_seed([1; 32]).public();
+        assert_ne!(Input::new_public_salted(&other, b"data"), input);
+    }
+
+    #[test]
+    fn points_as_keys() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let outputs: Vec<_> = (0..8u8)
+            .map(|i| secret.output(Input::new(&[i]).unwrap()))
+            .collect();
+        let hashed: HashSet<_> = outputs.iter().chain(&outputs).collect();
+        assert_eq!(hashed.len(), outputs.len());
+
+        // Sorted by compressed encoding.
+        let sorted: Vec<_> = outputs
+            .iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let mut encoded: Vec<_> = outputs
+            .iter()
+            .map(utils::common::encode_compressed)
+            .collect();
+        encoded.sort();
+        let sorted_encoded: Vec<_> = sorted
+            .into_iter()
+            .map(utils::common::encode_compressed)
+            .collect();
+        assert_eq!(sorted_encoded, encoded);
+    }
 }
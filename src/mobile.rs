@@ -0,0 +1,126 @@
+//! # Mobile bindings
+//!
+//! [UniFFI](https://mozilla.github.io/uniffi-rs/) interface for Kotlin and
+//! Swift applications, covering key generation and Tiny VRF (IETF) proving
+//! and verification with the Bandersnatch SHA-512 Elligator2 suite
+//! ([`BandersnatchSha512Ell2`]).
+//!
+//! Keys, outputs and proofs are exchanged as byte arrays in the suite's
+//! compressed encoding, decoded with [`decode_canonical`]. Failures are
+//! surfaced as the crate [`Error`], exported as a flat error enum.
+//!
+//! The interface is declared with UniFFI proc-macros, so no UDL file is
+//! needed. Build a `cdylib`/`staticlib` wrapper crate with the `uniffi`
+//! feature and generate the foreign bindings from the compiled library:
+//!
+//! ```sh
+//! uniffi-bindgen generate --library libark_vrf_mobile.so --language kotlin --out-dir out
+//! uniffi-bindgen generate --library libark_vrf_mobile.a --language swift --out-dir out
+//! ```
+
+use crate::suites::bandersnatch::BandersnatchSha512Ell2;
+use crate::tiny::{Prover, Verifier};
+use crate::utils::decode_canonical;
+use crate::*;
+
+type S = BandersnatchSha512Ell2;
+
+/// Length of the hash returned by [`output_hash`].
+pub const OUTPUT_HASH_LEN: usize = 32;
+
+fn encode<T: CanonicalSerialize>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut buf = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut buf)?;
+    Ok(buf)
+}
+
+fn input(data: &[u8]) -> Result<Input<S>, Error> {
+    Input::new(data).ok_or(Error::HashToCurveFailure)
+}
+
+/// Derive a secret key from a 32-byte seed.
+#[uniffi::export]
+pub fn secret_from_seed(seed: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let seed = seed.try_into().map_err(|_| Error::InvalidData)?;
+    encode(&Secret::<S>::from_seed(seed))
+}
+
+/// Public key of a secret key.
+#[uniffi::export]
+pub fn public_from_secret(secret: Vec<u8>) -> Result<Vec<u8>, Error> {
+    encode(&decode_canonical::<Secret<S>>(&secret)?.public())
+}
+
+/// VRF output point for the input derived from `data`.
+#[uniffi::export]
+pub fn output(secret: Vec<u8>, data: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let secret: Secret<S> = decode_canonical(&secret)?;
+    encode(&secret.output(input(&data)?))
+}
+
+/// Hash of a VRF output point, [`OUTPUT_HASH_LEN`] bytes long.
+#[uniffi::export]
+pub fn output_hash(output: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let output: Output<S> = decode_canonical(&output)?;
+    Ok(output.hash::<OUTPUT_HASH_LEN>().to_vec())
+}
+
+/// Tiny VRF proof for the input derived from `data`.
+#[uniffi::export]
+pub fn tiny_prove(secret: Vec<u8>, data: Vec<u8>, ad: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let secret: Secret<S> = decode_canonical(&secret)?;
+    let io = secret.vrf_io(input(&data)?);
+    encode(&secret.prove(io, &ad))
+}
+
+/// Verify a Tiny VRF proof.
+///
+/// Returns `false` if the proof doesn't verify and an error if any of the
+/// arguments is malformed.
+#[uniffi::export]
+pub fn tiny_verify(
+    public: Vec<u8>,
+    data: Vec<u8>,
+    output: Vec<u8>,
+    ad: Vec<u8>,
+    proof: Vec<u8>,
+) -> Result<bool, Error> {
+    let public: Public<S> = decode_canonical(&public)?;
+    let io = VrfIo {
+        input: input(&data)?,
+        output: decode_canonical(&output)?,
+    };
+    let proof: tiny::Proof<S> = decode_canonical(&proof)?;
+    match public.verify(io, &ad, &proof) {
+        Ok(()) => Ok(true),
+        Err(Error::VerificationFailure) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prove_verify() {
+        let secret = secret_from_seed(vec![0; 32]).unwrap();
+        let public = public_from_secret(secret.clone()).unwrap();
+        let out = output(secret.clone(), b"foo".to_vec()).unwrap();
+        assert_eq!(output_hash(out.clone()).unwrap().len(), OUTPUT_HASH_LEN);
+
+        let proof = tiny_prove(secret, b"foo".to_vec(), b"bar".to_vec()).unwrap();
+        let verify = |ad: &[u8]| {
+            tiny_verify(
+                public.clone(),
+                b"foo".to_vec(),
+                out.clone(),
+                ad.to_vec(),
+                proof.clone(),
+            )
+        };
+        assert_eq!(verify(b"bar"), Ok(true));
+        assert_eq!(verify(b"baz"), Ok(false));
+        assert_eq!(secret_from_seed(vec![0; 31]), Err(Error::InvalidData));
+    }
+}
@@ -24,16 +24,15 @@
 //! let result = Public::verify(io, b"aux data", &proof);
 //!
 //! // Unblinding: verify the proof was created using a specific public key
-//! let expected = (public.0 + BandersnatchSha512Ell2::BLINDING_BASE * blinding).into_affine();
-//! assert_eq!(proof.key_commitment(), expected);
+//! let result = public.verify_with_key(io, b"aux data", &proof, blinding);
 //! ```
 
 use crate::Suite;
 use crate::utils;
+use crate::utils::SuitePrecomp;
 use crate::utils::common::DomSep;
 use crate::utils::straus::short_msm;
 use crate::*;
-use ark_ec::VariableBaseMSM;
 
 /// Seed hashed to curve to produce [`PedersenSuite::BLINDING_BASE`] in built-in suites.
 pub const PEDERSEN_BLINDING_BASE_SEED: &[u8] = b"pedersen-blinding";
@@ -41,10 +40,21 @@ pub const PEDERSEN_BLINDING_BASE_SEED: &[u8] = b"pedersen-blinding";
 /// Suite extension for Pedersen VRF support.
 ///
 /// Provides the additional cryptographic parameters required by the Pedersen VRF scheme.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't support the Pedersen VRF",
+    note = "Pedersen VRF requires a suite implementing `PedersenSuite`, see `SuiteInfo::SUPPORTS_PEDERSEN`"
+)]
 pub trait PedersenSuite: Suite {
     /// Blinding base.
     const BLINDING_BASE: AffinePoint<Self>;
 
+    /// Process-wide fixed-base table of [`PedersenSuite::BLINDING_BASE`].
+    ///
+    /// Same as [`Suite::generator_table`], for the blinding base.
+    fn blinding_base_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        None
+    }
+
     /// Pedersen blinding factor.
     ///
     /// Default implementation is deterministic. All parameters but `secret` are public.
@@ -52,6 +62,23 @@ pub trait PedersenSuite: Suite {
         transcript.absorb_raw(&[DomSep::PedersenBlinding as u8]);
         Self::nonce(secret, Some(transcript))
     }
+
+    /// Pedersen blinding factor bound to a caller supplied `context`.
+    ///
+    /// Used by [`Prover::prove_with_context`], so that the key commitments of
+    /// the same key and I/O pairs differ across contexts (e.g. epochs) and
+    /// can't be linked. Default implementation absorbs the length-prefixed
+    /// context (little-endian `u32`) and invokes [`Self::blinding`].
+    fn blinding_with_context(
+        secret: &ScalarField<Self>,
+        mut transcript: Self::Transcript,
+        context: &[u8],
+    ) -> ScalarField<Self> {
+        let context_len = u32::try_from(context.len()).expect("context too long");
+        transcript.absorb_raw(&context_len.to_le_bytes());
+        transcript.absorb_raw(context);
+        Self::blinding(secret, transcript)
+    }
 }
 
 /// Pedersen VRF proof.
@@ -65,23 +92,121 @@ pub trait PedersenSuite: Suite {
 ///
 /// Deserialization via [`CanonicalDeserialize`] includes subgroup checks for
 /// curve points, so deserialized proofs are guaranteed to contain valid points.
-#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Proof<S: PedersenSuite> {
-    pk_com: AffinePoint<S>,
-    r: AffinePoint<S>,
-    ok: AffinePoint<S>,
-    s: ScalarField<S>,
-    sb: ScalarField<S>,
+    pub(crate) pk_com: AffinePoint<S>,
+    pub(crate) r: AffinePoint<S>,
+    pub(crate) ok: AffinePoint<S>,
+    pub(crate) s: ScalarField<S>,
+    pub(crate) sb: ScalarField<S>,
 }
 
+/// Pedersen VRF proof components: `(pk_com, r, ok, s, sb)`.
+pub type ProofParts<S> = (
+    AffinePoint<S>,
+    AffinePoint<S>,
+    AffinePoint<S>,
+    ScalarField<S>,
+    ScalarField<S>,
+);
+
 impl<S: PedersenSuite> Proof<S> {
+    /// Add the hex encoded components to a `Debug` output.
+    pub(crate) fn debug_fields(&self, f: &mut core::fmt::DebugStruct<'_, '_>) {
+        use utils::common::{Hex, encode_compressed};
+        f.field("pk_com", &Hex(&encode_compressed(&self.pk_com)))
+            .field("r", &Hex(&encode_compressed(&self.r)))
+            .field("ok", &Hex(&encode_compressed(&self.ok)))
+            .field("s", &Hex(&encode_compressed(&self.s)))
+            .field("sb", &Hex(&encode_compressed(&self.sb)));
+    }
+}
+
+impl<S: PedersenSuite> core::fmt::Debug for Proof<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut f = f.debug_struct("Proof");
+        f.field("suite", &format_args!("{:08x}", S::SUITE_ID));
+        self.debug_fields(&mut f);
+        f.finish()
+    }
+}
+
+impl<S: PedersenSuite> core::fmt::Display for Proof<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        utils::common::fmt_proof::<S>(self, f)
+    }
+}
+
+impl<S: PedersenSuite> Proof<S> {
+    /// Check that the proof has a unique canonical encoding.
+    ///
+    /// Scalars are always reduced, so this checks that the points are valid
+    /// prime-order subgroup points (the only values the decoder accepts).
+    /// Verifiers requiring a unique byte representation per accepted proof
+    /// should call this on proofs not obtained through
+    /// [`decode_canonical`](utils::decode_canonical).
+    pub fn check_canonical(&self) -> Result<(), Error> {
+        [self.pk_com, self.r, self.ok]
+            .iter()
+            .try_for_each(utils::common::check_point::<S>)
+    }
+
+    /// Returns `true` if [`Self::check_canonical`] succeeds.
+    pub fn is_canonical(&self) -> bool {
+        self.check_canonical().is_ok()
+    }
+
+    /// Check the proof invariants, without verifying it.
+    ///
+    /// Checks that the proof is canonical (see [`Self::check_canonical`]) and
+    /// that no point is the identity, which honest proofs only produce with
+    /// negligible probability. Costs a few subgroup checks, so that e.g.
+    /// mempools can cheaply discard malformed proofs before verification.
+    ///
+    /// Passing the checks doesn't mean that the proof is valid.
+    pub fn check_structure(&self) -> Result<(), Error> {
+        self.check_canonical()?;
+        if [self.pk_com, self.r, self.ok].iter().any(|p| p.is_zero()) {
+            return Err(Error::IdentityPoint);
+        }
+        Ok(())
+    }
+
     /// Get public key commitment from proof.
     pub fn key_commitment(&self) -> AffinePoint<S> {
         self.pk_com
     }
+
+    /// Get nonce commitment for the generator and blinding base (`R`).
+    pub fn r(&self) -> AffinePoint<S> {
+        self.r
+    }
+
+    /// Get nonce commitment for the input point (`O_k`).
+    pub fn nonce_output(&self) -> AffinePoint<S> {
+        self.ok
+    }
+
+    /// Get response scalar for the secret key.
+    pub fn s(&self) -> ScalarField<S> {
+        self.s
+    }
+
+    /// Get response scalar for the blinding factor.
+    pub fn sb(&self) -> ScalarField<S> {
+        self.sb
+    }
+
+    /// Split the proof into its components.
+    ///
+    /// Components are in encoding order (see [`ProofParts`]).
+    pub fn into_parts(self) -> ProofParts<S> {
+        (self.pk_com, self.r, self.ok, self.s, self.sb)
+    }
 }
 
 /// Trait for types that can generate Pedersen VRF proofs.
+#[cfg(feature = "prove")]
 pub trait Prover<S: PedersenSuite> {
     /// Generate a proof for the given VRF I/O pairs and additional data.
     ///
@@ -93,6 +218,25 @@ pub trait Prover<S: PedersenSuite> {
         ios: impl AsRef<[VrfIo<S>]>,
         ad: impl AsRef<[u8]>,
     ) -> (Proof<S>, ScalarField<S>);
+
+    /// Same as [`Prover::prove`], using precomputed fixed-base tables.
+    fn prove_with_precomp(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        precomp: &SuitePrecomp<S>,
+    ) -> (Proof<S>, ScalarField<S>);
+
+    /// Same as [`Prover::prove`], with the blinding factor bound to `context`
+    /// (see [`PedersenSuite::blinding_with_context`]).
+    ///
+    /// The context is not part of the proof: verification is unchanged.
+    fn prove_with_context(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        context: &[u8],
+    ) -> (Proof<S>, ScalarField<S>);
 }
 
 /// Trait for entities that can verify Pedersen VRF proofs.
@@ -111,6 +255,8 @@ pub trait Prover<S: PedersenSuite> {
 /// Using unchecked constructors (e.g. [`Input::from_affine_unchecked`]) places
 /// the burden of subgroup validation on the caller. Passing points with
 /// cofactor components leads to undefined verification behavior.
+///
+/// Proofs involving an identity VRF output are always rejected.
 pub trait Verifier<S: PedersenSuite> {
     /// Verify a proof for the given VRF I/O pairs and additional data.
     ///
@@ -122,106 +268,220 @@ pub trait Verifier<S: PedersenSuite> {
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> Result<(), Error>;
+
+    /// Same as [`Verifier::verify`], using precomputed fixed-base tables.
+    fn verify_with_precomp(
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+        precomp: &SuitePrecomp<S>,
+    ) -> Result<(), Error>;
 }
 
+#[cfg(feature = "prove")]
 impl<S: PedersenSuite> Prover<S> for Secret<S> {
     fn prove(
         &self,
         ios: impl AsRef<[VrfIo<S>]>,
         ad: impl AsRef<[u8]>,
     ) -> (Proof<S>, ScalarField<S>) {
-        let (mut t, io) = utils::vrf_transcript::<S>(DomSep::PedersenVrf, ios, ad);
-
-        // Build blinding factor from T.fork()
-        let blinding = S::blinding(&self.scalar, t.clone());
-
-        // Yb = x*G + b*B = PK + b*B
-        let bb = smul!(S::BLINDING_BASE, blinding);
-        let pk_com = (self.public.0.into_group() + bb).into_affine();
-
-        // Absorb Yb into the transcript
-        t.absorb_serialize(&pk_com);
-
-        // Nonces from T.fork()
-        let k = S::nonce(&self.scalar, Some(t.clone()));
-        let kb = S::nonce(&blinding, Some(t.clone()));
-
-        // R = k*G + kb*B
-        let kg = smul!(S::generator(), k);
-        let kbb = smul!(S::BLINDING_BASE, kb);
-        let r = kg + kbb;
-
-        // Ok = k*I
-        let ok = smul!(io.input.0, k);
-
-        let norms = CurveGroup::normalize_batch(&[r, ok]);
-        let (r, ok) = (norms[0], norms[1]);
-
-        // c = challenge([R, Ok], T)
-        let c = S::challenge(&[&r, &ok], Some(t));
+        prove(self, ios, ad, None, None)
+    }
 
-        // s = k + c*x
-        let s = k + c * self.scalar;
-        // sb = kb + c*b
-        let sb = kb + c * blinding;
+    fn prove_with_precomp(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        precomp: &SuitePrecomp<S>,
+    ) -> (Proof<S>, ScalarField<S>) {
+        prove(self, ios, ad, Some(precomp), None)
+    }
 
-        let proof = Proof {
-            pk_com,
-            r,
-            ok,
-            s,
-            sb,
-        };
-        (proof, blinding)
+    fn prove_with_context(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        context: &[u8],
+    ) -> (Proof<S>, ScalarField<S>) {
+        prove(self, ios, ad, None, Some(context))
     }
 }
 
+#[cfg(feature = "prove")]
+fn prove<S: PedersenSuite>(
+    secret: &Secret<S>,
+    ios: impl AsRef<[VrfIo<S>]>,
+    ad: impl AsRef<[u8]>,
+    precomp: Option<&SuitePrecomp<S>>,
+    context: Option<&[u8]>,
+) -> (Proof<S>, ScalarField<S>) {
+    trace_span!("pedersen_prove", ios = ios.as_ref().len());
+    let g_table = precomp
+        .map(|p| p.generator())
+        .or_else(|| S::generator_table());
+    let b_table = precomp
+        .and_then(|p| p.blinding_base())
+        .or_else(|| S::blinding_base_table());
+    let g_mul = |s: ScalarField<S>| match g_table {
+        Some(table) => smul!(table, s),
+        None => smul!(S::generator(), s),
+    };
+    let b_mul = |s: ScalarField<S>| match b_table {
+        Some(table) => smul!(table, s),
+        None => smul!(S::BLINDING_BASE, s),
+    };
+
+    let (mut t, io) = utils::vrf_transcript::<S>(DomSep::PedersenVrf, ios, ad);
+
+    // Build blinding factor from T.fork()
+    let blinding = match context {
+        Some(context) => S::blinding_with_context(&secret.scalar, t.clone(), context),
+        None => S::blinding(&secret.scalar, t.clone()),
+    };
+
+    // Yb = x*G + b*B = PK + b*B
+    let bb = b_mul(blinding);
+    let pk_com = (secret.public.0.into_group() + bb).into_affine();
+
+    // Absorb Yb into the transcript
+    t.absorb_serialize(&pk_com);
+
+    // Nonces from T.fork()
+    let k = S::nonce(&secret.scalar, Some(t.clone()));
+    let kb = S::nonce(&blinding, Some(t.clone()));
+
+    // R = k*G + kb*B
+    let kg = g_mul(k);
+    let kbb = b_mul(kb);
+    let r = kg + kbb;
+
+    // Ok = k*I
+    let ok = smul!(io.input.0, k);
+
+    let norms = CurveGroup::normalize_batch(&[r, ok]);
+    let (r, ok) = (norms[0], norms[1]);
+
+    // c = challenge([R, Ok], T)
+    let c = S::challenge(&[&r, &ok], Some(t));
+
+    // s = k + c*x
+    let s = k + c * secret.scalar;
+    // sb = kb + c*b
+    let sb = kb + c * blinding;
+
+    let proof = Proof {
+        pk_com,
+        r,
+        ok,
+        s,
+        sb,
+    };
+    (proof, blinding)
+}
+
 impl<S: PedersenSuite> Verifier<S> for Public<S> {
     fn verify(
         ios: impl AsRef<[VrfIo<S>]>,
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> Result<(), Error> {
-        let Proof {
-            pk_com,
-            r,
-            ok,
-            s,
-            sb,
-        } = proof;
-
-        let (mut t, io) = utils::vrf_transcript::<S>(DomSep::PedersenVrf, ios, ad);
-
-        // Absorb Yb into the transcript
-        t.absorb_serialize(pk_com);
+        verify(ios, ad, proof, None)
+    }
 
-        // c = challenge([R, Ok], T)
-        let c = S::challenge(&[r, ok], Some(t));
+    fn verify_with_precomp(
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+        precomp: &SuitePrecomp<S>,
+    ) -> Result<(), Error> {
+        verify(ios, ad, proof, Some(precomp))
+    }
+}
 
-        let neg_c = -c;
+fn verify<S: PedersenSuite>(
+    ios: impl AsRef<[VrfIo<S>]>,
+    ad: impl AsRef<[u8]>,
+    proof: &Proof<S>,
+    precomp: Option<&SuitePrecomp<S>>,
+) -> Result<(), Error> {
+    let Proof {
+        pk_com,
+        r,
+        ok,
+        s,
+        sb,
+    } = proof;
+
+    let ios = ios.as_ref();
+    trace_span!("pedersen_verify", ios = ios.len());
+    utils::check_non_identity::<S>(None, ios)?;
+    let (mut t, io) = utils::vrf_transcript::<S>(DomSep::PedersenVrf, ios, ad);
+
+    // Absorb Yb into the transcript
+    t.absorb_serialize(pk_com);
+
+    // c = challenge([R, Ok], T)
+    let c = S::challenge(&[r, ok], Some(t));
+
+    let neg_c = -c;
+
+    // Eq1: s*I - c*O == Ok
+    // Verifies that the VRF output O is correctly derived from the input I
+    // using the same secret scalar x committed in the proof. Expanding the
+    // response s = k + c*x gives s*I = k*I + c*x*I = Ok + c*O.
+    let lhs1 = short_msm(&[io.input.0, io.output.0], &[*s, neg_c], 2);
+    if lhs1 != ok.into_group() {
+        return Err(Error::VerificationFailure);
+    }
 
-        // Eq1: s*I - c*O == Ok
-        // Verifies that the VRF output O is correctly derived from the input I
-        // using the same secret scalar x committed in the proof. Expanding the
-        // response s = k + c*x gives s*I = k*I + c*x*I = Ok + c*O.
-        let lhs1 = short_msm(&[io.input.0, io.output.0], &[*s, neg_c], 2);
-        if lhs1 != ok.into_group() {
-            return Err(Error::VerificationFailure);
+    // Eq2: s*G + sb*B - c*Yb == R
+    // Verifies knowledge of both the secret key x and blinding factor b
+    // committed in the public key commitment Yb = x*G + b*B. Expanding
+    // s = k + c*x and sb = kb + c*b gives s*G + sb*B = R + c*Yb.
+    let g_table = precomp
+        .map(|p| p.generator())
+        .or_else(|| S::generator_table());
+    let b_table = precomp
+        .and_then(|p| p.blinding_base())
+        .or_else(|| S::blinding_base_table());
+    let lhs2 = match (g_table, b_table) {
+        (Some(g_table), Some(b_table)) => g_table.mul(s) + b_table.mul(sb) + *pk_com * neg_c,
+        (Some(g_table), None) => {
+            g_table.mul(s) + short_msm(&[S::BLINDING_BASE, *pk_com], &[*sb, neg_c], 1)
         }
-
-        // Eq2: s*G + sb*B - c*Yb == R
-        // Verifies knowledge of both the secret key x and blinding factor b
-        // committed in the public key commitment Yb = x*G + b*B. Expanding
-        // s = k + c*x and sb = kb + c*b gives s*G + sb*B = R + c*Yb.
-        let lhs2 = short_msm(
+        _ => short_msm(
             &[S::generator(), S::BLINDING_BASE, *pk_com],
             &[*s, *sb, neg_c],
             1,
-        );
-        if lhs2 != r.into_group() {
+        ),
+    };
+    if lhs2 != r.into_group() {
+        return Err(Error::VerificationFailure);
+    }
+
+    Ok(())
+}
+
+impl<S: PedersenSuite> Public<S> {
+    /// Verify a proof and that it was created using this public key.
+    ///
+    /// Same as [`Verifier::verify`], additionally checking that the proof key
+    /// commitment opens to this key with the `blinding` factor returned by
+    /// [`Prover::prove`], i.e. `pk_com == pk + blinding * B`.
+    ///
+    /// Returns `Err(Error::VerificationFailure)` if either check fails.
+    pub fn verify_with_key(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+        blinding: ScalarField<S>,
+    ) -> Result<(), Error> {
+        verify(ios, ad, proof, None)?;
+        let pk_com = self.0 + S::BLINDING_BASE * blinding;
+        if pk_com != proof.pk_com.into_group() {
             return Err(Error::VerificationFailure);
         }
-
         Ok(())
     }
 }
@@ -231,6 +491,8 @@ impl<S: PedersenSuite> Verifier<S> for Public<S> {
 /// Captures all the information needed to verify a single Pedersen proof,
 /// allowing multiple proofs to be verified together via a single MSM.
 pub struct BatchItem<S: PedersenSuite> {
+    // Some VRF output is the identity (see `utils::check_non_identity`).
+    identity: bool,
     c: ScalarField<S>,
     input: AffinePoint<S>,
     output: AffinePoint<S>,
@@ -248,13 +510,25 @@ pub struct BatchItem<S: PedersenSuite> {
 ///
 /// The same subgroup membership assumptions as [`Verifier`] apply to all
 /// points fed into the batch (I/O pairs and proof points).
+///
+/// The verifier can be reused across rounds: [`Self::verify_and_clear`]
+/// empties the batch while retaining the internal allocations.
 pub struct BatchVerifier<S: PedersenSuite> {
     items: Vec<BatchItem<S>>,
+    // Scratch MSM buffers reused by `verify_and_clear`.
+    bases: Vec<AffinePoint<S>>,
+    scalars: Vec<ScalarField<S>>,
+    msm_config: utils::msm::MsmConfig,
 }
 
 impl<S: PedersenSuite> Default for BatchVerifier<S> {
     fn default() -> Self {
-        Self { items: Vec::new() }
+        Self {
+            items: Vec::new(),
+            bases: Vec::new(),
+            scalars: Vec::new(),
+            msm_config: utils::msm::MsmConfig::DEFAULT,
+        }
     }
 }
 
@@ -264,6 +538,35 @@ impl<S: PedersenSuite> BatchVerifier<S> {
         Self::default()
     }
 
+    /// Create a new empty batch verifier with room for `n` proofs.
+    pub fn with_capacity(n: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(n),
+            ..Self::default()
+        }
+    }
+
+    /// Set the configuration of the batch multi-scalar multiplication.
+    pub fn with_msm_config(mut self, config: utils::msm::MsmConfig) -> Self {
+        self.msm_config = config;
+        self
+    }
+
+    /// Number of proofs in the batch.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the batch contains no proofs.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Remove all the proofs from the batch, retaining the allocated memory.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
     /// Prepare a proof for batch verification.
     ///
     /// Computes the challenge and packages all data needed for deferred
@@ -274,10 +577,13 @@ impl<S: PedersenSuite> BatchVerifier<S> {
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> BatchItem<S> {
+        let ios = ios.as_ref();
+        let identity = utils::check_non_identity::<S>(None, ios).is_err();
         let (mut t, io) = utils::vrf_transcript::<S>(DomSep::PedersenVrf, ios, ad);
         t.absorb_serialize(&proof.pk_com);
         let c = S::challenge(&[&proof.r, &proof.ok], Some(t));
         BatchItem {
+            identity,
             c,
             input: io.input.0,
             output: io.output.0,
@@ -300,6 +606,26 @@ impl<S: PedersenSuite> BatchVerifier<S> {
         self.push_prepared(entry);
     }
 
+    /// Prepare and push several proofs, in order.
+    ///
+    /// Under the `parallel` feature the proofs are prepared in parallel.
+    pub fn push_all_par<I, A>(&mut self, items: &[(I, A, &Proof<S>)])
+    where
+        I: AsRef<[VrfIo<S>]> + Sync,
+        A: AsRef<[u8]> + Sync,
+    {
+        let prepare = |(ios, ad, proof): &(I, A, &Proof<S>)| Self::prepare(ios, ad, proof);
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            self.items.par_extend(items.par_iter().map(prepare));
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.items.extend(items.iter().map(prepare));
+        }
+    }
+
     /// Batch-verify multiple Pedersen proofs using a single multi-scalar multiplication.
     ///
     /// For each proof i, two equations are checked with independent random scalars
@@ -311,10 +637,65 @@ impl<S: PedersenSuite> BatchVerifier<S> {
     ///
     /// Returns `Ok(())` if all proofs verify, `Err(VerificationFailure)` otherwise.
     pub fn verify(&self) -> Result<(), Error> {
-        let items = &self.items;
+        Self::verify_items(
+            &self.items,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &self.msm_config,
+            None,
+        )
+    }
+
+    /// Same as [`Self::verify`], with the random weights also derived from
+    /// a caller supplied `seed`.
+    ///
+    /// Weights are otherwise derived from the batch contents only. A seed
+    /// shared by the nodes (e.g. a randomness beacon output) makes the batch
+    /// verification reproducible and auditable, while a seed unknown to the
+    /// provers prevents them from grinding the weights.
+    pub fn verify_with_seed(&self, seed: &[u8; 32]) -> Result<(), Error> {
+        Self::verify_items(
+            &self.items,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &self.msm_config,
+            Some(seed),
+        )
+    }
+
+    /// Batch-verify all collected proofs, then clear the batch.
+    ///
+    /// Same as [`Self::verify`], but reuses the verifier's internal buffers
+    /// and leaves it empty, ready for the next round. The batch is cleared
+    /// regardless of the verification outcome.
+    pub fn verify_and_clear(&mut self) -> Result<(), Error> {
+        let res = Self::verify_items(
+            &self.items,
+            &mut self.bases,
+            &mut self.scalars,
+            &self.msm_config,
+            None,
+        );
+        self.items.clear();
+        self.bases.clear();
+        self.scalars.clear();
+        res
+    }
+
+    fn verify_items(
+        items: &[BatchItem<S>],
+        bases: &mut Vec<AffinePoint<S>>,
+        scalars: &mut Vec<ScalarField<S>>,
+        msm_config: &utils::msm::MsmConfig,
+        seed: Option<&[u8; 32]>,
+    ) -> Result<(), Error> {
+        trace_span!("pedersen_batch_verify", proofs = items.len());
         if items.is_empty() {
             return Ok(());
         }
+        if items.iter().any(|e| e.identity) {
+            return Err(Error::VerificationFailure);
+        }
 
         let n = items.len();
 
@@ -324,6 +705,9 @@ impl<S: PedersenSuite> BatchVerifier<S> {
         // response scalars s and sb need to be included separately.
         let mut t = S::Transcript::new(S::SUITE_ID);
         t.absorb_raw(&[DomSep::PedersenBatch as u8]);
+        if let Some(seed) = seed {
+            t.absorb_raw(seed);
+        }
         for e in items {
             t.absorb_serialize(&e.c);
             t.absorb_serialize(&e.s);
@@ -345,8 +729,10 @@ impl<S: PedersenSuite> BatchVerifier<S> {
             .collect();
 
         // Build MSM: 5N per-proof points + 2 shared bases (G, B)
-        let mut bases = Vec::with_capacity(5 * n + 2);
-        let mut scalars = Vec::with_capacity(5 * n + 2);
+        bases.clear();
+        bases.reserve(5 * n + 2);
+        scalars.clear();
+        scalars.reserve(5 * n + 2);
 
         let mut g_scalar = ScalarField::<S>::zero();
         let mut b_scalar = ScalarField::<S>::zero();
@@ -381,7 +767,7 @@ impl<S: PedersenSuite> BatchVerifier<S> {
         bases.push(S::BLINDING_BASE);
         scalars.push(-b_scalar);
 
-        let result = <S::Affine as AffineRepr>::Group::msm_unchecked(&bases, &scalars);
+        let result = utils::msm::batch_msm_with_config(bases, scalars, msm_config);
         if !result.is_zero() {
             return Err(Error::VerificationFailure);
         }
@@ -390,10 +776,158 @@ impl<S: PedersenSuite> BatchVerifier<S> {
     }
 }
 
+#[cfg(any(test, feature = "vectors"))]
+pub mod vectors {
+    use super::*;
+    use crate::vectors::{self as common, SuiteExt};
+
+    /// Pedersen VRF test vector.
+    pub struct TestVector<S: PedersenSuite> {
+        pub base: common::TestVector<S>,
+        pub blind: ScalarField<S>,
+        pub proof: Proof<S>,
+    }
+
+    impl<S: PedersenSuite> core::fmt::Debug for TestVector<S> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("TestVector")
+                .field("base", &self.base)
+                .field("blinding", &self.blind)
+                .field("proof_pk_com", &self.proof.pk_com)
+                .field("proof_r", &self.proof.r)
+                .field("proof_ok", &self.proof.ok)
+                .field("proof_s", &self.proof.s)
+                .field("proof_sb", &self.proof.sb)
+                .finish()
+        }
+    }
+
+    impl<S> common::TestVectorTrait for TestVector<S>
+    where
+        S: PedersenSuite + SuiteExt + std::fmt::Debug,
+    {
+        type Suite = S;
+
+        fn name() -> String {
+            S::SUITE_NAME.to_string() + "_pedersen"
+        }
+
+        fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
+            use super::Prover;
+            let base = common::TestVector::new(comment, seed, alpha, ad);
+            let io = VrfIo {
+                input: Input::<S>::from_affine_unchecked(base.h),
+                output: Output::from_affine_unchecked(base.gamma),
+            };
+            let secret = Secret::from_scalar(base.sk);
+            let (proof, blind) = secret.prove(io, ad);
+            Self { base, blind, proof }
+        }
+
+        fn from_map(map: &common::TestVectorMap) -> Self {
+            let base = common::TestVector::from_map(map);
+            let blind = common::scalar_decode::<S>(&map.get_bytes("blinding"));
+            let pk_com = common::point_decode::<S>(&map.get_bytes("proof_pk_com")).unwrap();
+            let r = common::point_decode::<S>(&map.get_bytes("proof_r")).unwrap();
+            let ok = common::point_decode::<S>(&map.get_bytes("proof_ok")).unwrap();
+            let s = common::scalar_decode::<S>(&map.get_bytes("proof_s"));
+            let sb = common::scalar_decode::<S>(&map.get_bytes("proof_sb"));
+            let proof = Proof {
+                pk_com,
+                r,
+                ok,
+                s,
+                sb,
+            };
+            Self { base, blind, proof }
+        }
+
+        fn to_map(&self) -> common::TestVectorMap {
+            let items = [
+                (
+                    "blinding",
+                    hex::encode(common::scalar_encode::<S>(&self.blind)),
+                ),
+                (
+                    "proof_pk_com",
+                    hex::encode(common::point_encode::<S>(&self.proof.pk_com)),
+                ),
+                (
+                    "proof_r",
+                    hex::encode(common::point_encode::<S>(&self.proof.r)),
+                ),
+                (
+                    "proof_ok",
+                    hex::encode(common::point_encode::<S>(&self.proof.ok)),
+                ),
+                (
+                    "proof_s",
+                    hex::encode(common::scalar_encode::<S>(&self.proof.s)),
+                ),
+                (
+                    "proof_sb",
+                    hex::encode(common::scalar_encode::<S>(&self.proof.sb)),
+                ),
+            ];
+            let mut map = self.base.to_map();
+            items.into_iter().for_each(|(name, value)| {
+                map.0.insert(name.to_string(), value);
+            });
+            map
+        }
+
+        fn run(&self) {
+            self.base.run();
+            let io = VrfIo {
+                input: Input::<S>::from_affine_unchecked(self.base.h),
+                output: Output::from_affine_unchecked(self.base.gamma),
+            };
+            let sk = Secret::from_scalar(self.base.sk);
+            let (proof, blind) = sk.prove(io, &self.base.ad);
+            assert_eq!(self.blind, blind, "Blinding factor mismatch");
+            assert_eq!(self.proof.pk_com, proof.pk_com, "Proof pkb mismatch");
+            assert_eq!(self.proof.r, proof.r, "Proof r mismatch");
+            assert_eq!(self.proof.ok, proof.ok, "Proof ok mismatch");
+            assert_eq!(self.proof.s, proof.s, "Proof s mismatch");
+            assert_eq!(self.proof.sb, proof.sb, "Proof sb mismatch");
+
+            assert!(Public::verify(io, &self.base.ad, &proof).is_ok());
+        }
+
+        fn verify_map(map: &common::TestVectorMap) -> Result<(), Error> {
+            let io = common::TestVector::<S>::verify_io(map)?;
+            let mut proof = Vec::new();
+            for field in ["proof_pk_com", "proof_r", "proof_ok", "proof_s", "proof_sb"] {
+                proof.extend(map.try_get_bytes(field)?);
+            }
+            let proof: Proof<S> = utils::decode_canonical(&proof)?;
+            Public::verify(io, map.try_get_bytes("ad")?, &proof)
+        }
+
+        fn wrong_suite_id(&self) -> Option<common::TestVectorMap> {
+            use super::Prover;
+            let io = VrfIo {
+                input: Input::<common::WrongSuiteId<S>>::from_affine_unchecked(self.base.h),
+                output: Output::from_affine_unchecked(self.base.gamma),
+            };
+            let (proof, blind) = Secret::from_scalar(self.base.sk).prove(io, &self.base.ad);
+            let proof = Proof {
+                pk_com: proof.pk_com,
+                r: proof.r,
+                ok: proof.ok,
+                s: proof.s,
+                sb: proof.sb,
+            };
+            let base = self.base.clone();
+            Some(Self { base, blind, proof }.to_map())
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod testing {
     use super::*;
-    use crate::testing::{self as common, CheckPoint, SuiteExt, TEST_SEED, random_val};
+    use crate::testing::{TEST_SEED, random_val};
 
     pub fn prove_verify<S: PedersenSuite>() {
         use pedersen::{Prover, Verifier};
@@ -410,6 +944,80 @@ pub(crate) mod testing {
             proof.key_commitment(),
             (secret.public().0 + S::BLINDING_BASE * blinding).into()
         );
+
+        // Components re-encode to the proof encoding.
+        let mut buf = Vec::new();
+        proof.serialize_compressed(&mut buf).unwrap();
+        let parts = (
+            proof.key_commitment(),
+            proof.r(),
+            proof.nonce_output(),
+            proof.s(),
+            proof.sb(),
+        );
+        let mut parts_buf = Vec::new();
+        parts.serialize_compressed(&mut parts_buf).unwrap();
+        assert_eq!(buf, parts_buf);
+        assert_eq!(proof.into_parts(), parts);
+    }
+
+    pub fn verify_with_key<S: PedersenSuite>() {
+        use pedersen::Prover;
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let (proof, blinding) = secret.prove(io, b"bar");
+
+        assert!(public.verify_with_key(io, b"bar", &proof, blinding).is_ok());
+        let one = ScalarField::<S>::from(1u8);
+        let res = public.verify_with_key(io, b"bar", &proof, blinding + one);
+        assert_eq!(res, Err(Error::VerificationFailure));
+        let other = Secret::<S>::from_seed([1; 32]).public();
+        let res = other.verify_with_key(io, b"bar", &proof, blinding);
+        assert_eq!(res, Err(Error::VerificationFailure));
+        let res = public.verify_with_key(io, b"baz", &proof, blinding);
+        assert_eq!(res, Err(Error::VerificationFailure));
+    }
+
+    pub fn prove_with_context<S: PedersenSuite>() {
+        use pedersen::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let (proof, blinding) = secret.prove_with_context(io, b"bar", b"epoch-1");
+        assert!(Public::verify(io, b"bar", &proof).is_ok());
+        let public = secret.public();
+        assert!(public.verify_with_key(io, b"bar", &proof, blinding).is_ok());
+
+        let (same, _) = secret.prove_with_context(io, b"bar", b"epoch-1");
+        assert_eq!(same.key_commitment(), proof.key_commitment());
+        let (other, _) = secret.prove_with_context(io, b"bar", b"epoch-2");
+        assert_ne!(other.key_commitment(), proof.key_commitment());
+        let (plain, _) = secret.prove(io, b"bar");
+        assert_ne!(plain.key_commitment(), proof.key_commitment());
+    }
+
+    pub fn prove_verify_with_precomp<S: PedersenSuite>() {
+        use pedersen::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from_affine_unchecked(random_val(None));
+        let io = secret.vrf_io(input);
+        let precomp = SuitePrecomp::<S>::new_pedersen();
+
+        let (proof, blinding) = secret.prove(io, b"foo");
+        let (proof_pre, blinding_pre) = secret.prove_with_precomp(io, b"foo", &precomp);
+        assert_eq!(blinding, blinding_pre);
+        assert_eq!(proof.key_commitment(), proof_pre.key_commitment());
+        assert!(Public::verify(io, b"foo", &proof_pre).is_ok());
+
+        assert!(Public::verify_with_precomp(io, b"foo", &proof, &precomp).is_ok());
+        assert!(Public::verify_with_precomp(io, b"bar", &proof, &precomp).is_err());
+
+        // Generator table only.
+        let precomp = SuitePrecomp::<S>::new();
+        assert!(Public::verify_with_precomp(io, b"foo", &proof, &precomp).is_ok());
     }
 
     pub fn batch_verify<S: PedersenSuite>() {
@@ -440,6 +1048,24 @@ pub(crate) mod testing {
         batch.push_prepared(entry2);
         assert!(batch.verify().is_ok());
 
+        // Batch using push_all_par.
+        let mut batch = BatchVerifier::new();
+        batch.push_all_par(&[(io, b"foo", &proof1), (io, b"bar", &proof2)]);
+        assert_eq!(batch.len(), 2);
+        assert!(batch.verify().is_ok());
+        batch.push_all_par(&[(io, b"baz", &proof1)]);
+        assert!(batch.verify().is_err());
+
+        // Custom MSM window.
+        let config = utils::msm::MsmConfig {
+            window: Some(4),
+            ..Default::default()
+        };
+        let mut batch = BatchVerifier::new().with_msm_config(config);
+        batch.push(io, b"foo", &proof1);
+        batch.push(io, b"bar", &proof2);
+        assert!(batch.verify().is_ok());
+
         // Empty batch is ok.
         let batch = BatchVerifier::<S>::new();
         assert!(batch.verify().is_ok());
@@ -449,6 +1075,28 @@ pub(crate) mod testing {
         batch.push(io, b"foo", &proof1);
         batch.push(io, b"wrong", &proof2);
         assert!(batch.verify().is_err());
+
+        // Reusable batch: verify_and_clear empties the batch.
+        let mut batch = BatchVerifier::with_capacity(2);
+        batch.push(io, b"foo", &proof1);
+        batch.push(io, b"wrong", &proof2);
+        assert_eq!(batch.len(), 2);
+        assert!(batch.verify_and_clear().is_err());
+        assert!(batch.is_empty());
+        batch.push(io, b"foo", &proof1);
+        batch.push(io, b"bar", &proof2);
+        assert!(batch.verify_and_clear().is_ok());
+        assert!(batch.is_empty());
+        batch.push(io, b"wrong", &proof1);
+        batch.clear();
+        assert!(batch.verify().is_ok());
+
+        // Caller supplied weights seed.
+        batch.push(io, b"foo", &proof1);
+        batch.push(io, b"bar", &proof2);
+        assert!(batch.verify_with_seed(&[7; 32]).is_ok());
+        batch.push(io, b"wrong", &proof2);
+        assert!(batch.verify_with_seed(&[7; 32]).is_err());
     }
 
     /// N=1 slice produces same proof as passing a single `VrfIo`.
@@ -525,17 +1173,14 @@ pub(crate) mod testing {
         assert!(Public::verify(ios, b"baz", &proof).is_err());
     }
 
-    pub fn blinding_base_check<S: PedersenSuite>()
-    where
-        AffinePoint<S>: CheckPoint,
-    {
+    pub fn blinding_base_check<S: PedersenSuite>() {
         // Check that point has been computed using the magic spell.
         assert_eq!(
             S::BLINDING_BASE,
             S::data_to_point(PEDERSEN_BLINDING_BASE_SEED).unwrap()
         );
-        // Check that the point is on curve.
-        assert!(S::BLINDING_BASE.check(true).is_ok());
+        // Check that the point is on curve and in the prime order subgroup.
+        assert_eq!(utils::common::check_point::<S>(&S::BLINDING_BASE), Ok(()));
     }
 
     #[macro_export]
@@ -564,6 +1209,21 @@ pub(crate) mod testing {
                     $crate::pedersen::testing::prove_verify_multi_empty::<$suite>();
                 }
 
+                #[test]
+                fn verify_with_key() {
+                    $crate::pedersen::testing::verify_with_key::<$suite>();
+                }
+
+                #[test]
+                fn prove_with_context() {
+                    $crate::pedersen::testing::prove_with_context::<$suite>();
+                }
+
+                #[test]
+                fn prove_verify_with_precomp() {
+                    $crate::pedersen::testing::prove_verify_with_precomp::<$suite>();
+                }
+
                 #[test]
                 fn batch_verify() {
                     $crate::pedersen::testing::batch_verify::<$suite>();
@@ -574,119 +1234,8 @@ pub(crate) mod testing {
                     $crate::pedersen::testing::blinding_base_check::<$suite>();
                 }
 
-                $crate::test_vectors!($crate::pedersen::testing::TestVector<$suite>);
+                $crate::test_vectors!($crate::pedersen::vectors::TestVector<$suite>);
             }
         };
     }
-
-    pub struct TestVector<S: PedersenSuite> {
-        pub base: common::TestVector<S>,
-        pub blind: ScalarField<S>,
-        pub proof: Proof<S>,
-    }
-
-    impl<S: PedersenSuite> core::fmt::Debug for TestVector<S> {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            f.debug_struct("TestVector")
-                .field("base", &self.base)
-                .field("blinding", &self.blind)
-                .field("proof_pk_com", &self.proof.pk_com)
-                .field("proof_r", &self.proof.r)
-                .field("proof_ok", &self.proof.ok)
-                .field("proof_s", &self.proof.s)
-                .field("proof_sb", &self.proof.sb)
-                .finish()
-        }
-    }
-
-    impl<S> common::TestVectorTrait for TestVector<S>
-    where
-        S: PedersenSuite + SuiteExt + std::fmt::Debug,
-    {
-        fn name() -> String {
-            S::SUITE_NAME.to_string() + "_pedersen"
-        }
-
-        fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
-            use super::Prover;
-            let base = common::TestVector::new(comment, seed, alpha, ad);
-            let io = VrfIo {
-                input: Input::<S>::from_affine_unchecked(base.h),
-                output: Output::from_affine_unchecked(base.gamma),
-            };
-            let secret = Secret::from_scalar(base.sk);
-            let (proof, blind) = secret.prove(io, ad);
-            Self { base, blind, proof }
-        }
-
-        fn from_map(map: &common::TestVectorMap) -> Self {
-            let base = common::TestVector::from_map(map);
-            let blind = common::scalar_decode::<S>(&map.get_bytes("blinding"));
-            let pk_com = common::point_decode::<S>(&map.get_bytes("proof_pk_com")).unwrap();
-            let r = common::point_decode::<S>(&map.get_bytes("proof_r")).unwrap();
-            let ok = common::point_decode::<S>(&map.get_bytes("proof_ok")).unwrap();
-            let s = common::scalar_decode::<S>(&map.get_bytes("proof_s"));
-            let sb = common::scalar_decode::<S>(&map.get_bytes("proof_sb"));
-            let proof = Proof {
-                pk_com,
-                r,
-                ok,
-                s,
-                sb,
-            };
-            Self { base, blind, proof }
-        }
-
-        fn to_map(&self) -> common::TestVectorMap {
-            let items = [
-                (
-                    "blinding",
-                    hex::encode(common::scalar_encode::<S>(&self.blind)),
-                ),
-                (
-                    "proof_pk_com",
-                    hex::encode(common::point_encode::<S>(&self.proof.pk_com)),
-                ),
-                (
-                    "proof_r",
-                    hex::encode(common::point_encode::<S>(&self.proof.r)),
-                ),
-                (
-                    "proof_ok",
-                    hex::encode(common::point_encode::<S>(&self.proof.ok)),
-                ),
-                (
-                    "proof_s",
-                    hex::encode(common::scalar_encode::<S>(&self.proof.s)),
-                ),
-                (
-                    "proof_sb",
-                    hex::encode(common::scalar_encode::<S>(&self.proof.sb)),
-                ),
-            ];
-            let mut map = self.base.to_map();
-            items.into_iter().for_each(|(name, value)| {
-                map.0.insert(name.to_string(), value);
-            });
-            map
-        }
-
-        fn run(&self) {
-            self.base.run();
-            let io = VrfIo {
-                input: Input::<S>::from_affine_unchecked(self.base.h),
-                output: Output::from_affine_unchecked(self.base.gamma),
-            };
-            let sk = Secret::from_scalar(self.base.sk);
-            let (proof, blind) = sk.prove(io, &self.base.ad);
-            assert_eq!(self.blind, blind, "Blinding factor mismatch");
-            assert_eq!(self.proof.pk_com, proof.pk_com, "Proof pkb mismatch");
-            assert_eq!(self.proof.r, proof.r, "Proof r mismatch");
-            assert_eq!(self.proof.ok, proof.ok, "Proof ok mismatch");
-            assert_eq!(self.proof.s, proof.s, "Proof s mismatch");
-            assert_eq!(self.proof.sb, proof.sb, "Proof sb mismatch");
-
-            assert!(Public::verify(io, &self.base.ad, &proof).is_ok());
-        }
-    }
 }
@@ -0,0 +1,298 @@
+//! # Remote prover
+//!
+//! Delegates proving to a key-management service holding the secret key,
+//! while verification stays local.
+//!
+//! The crate defines the wire format of requests ([`ProveRequest`]) and
+//! responses ([`ProveResponse`]); moving the bytes is left to a
+//! [`Transport`] implementation (gRPC, HTTP, IPC, ...). On the service side,
+//! [`serve`] turns a request into a response using the secret key.
+//!
+//! [`RemoteProver`] verifies every returned proof against the expected key
+//! before handing it out, so a misbehaving service can't make the client
+//! accept invalid proofs.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::remote::{RemoteProver, Transport};
+//!
+//! let prover = RemoteProver::new(public, transport);
+//! let (ios, proof) = prover.prove_tiny(&[input], b"ad").await?;
+//! ```
+
+use crate::*;
+use core::future::Future;
+use pedersen::PedersenSuite;
+use utils::decode_canonical;
+
+/// Proof scheme requested from the remote prover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Scheme {
+    /// Tiny VRF.
+    Tiny = 0,
+    /// Thin VRF.
+    Thin = 1,
+    /// Pedersen VRF.
+    Pedersen = 2,
+}
+
+impl TryFrom<u8> for Scheme {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(Self::Tiny),
+            1 => Ok(Self::Thin),
+            2 => Ok(Self::Pedersen),
+            _ => Err(Error::InvalidData),
+        }
+    }
+}
+
+/// Proving request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProveRequest<S: Suite> {
+    /// Requested scheme.
+    pub scheme: Scheme,
+    /// Public key identifying the secret key to prove with.
+    pub public: Public<S>,
+    /// VRF inputs.
+    pub inputs: Vec<Input<S>>,
+    /// Additional data.
+    pub ad: Vec<u8>,
+}
+
+type RequestRepr<S> = (u8, Public<S>, Vec<Input<S>>, Vec<u8>);
+
+impl<S: Suite> ProveRequest<S> {
+    /// Encode the request.
+    pub fn encode(&self) -> Vec<u8> {
+        let repr: RequestRepr<S> = (
+            self.scheme as u8,
+            self.public,
+            self.inputs.clone(),
+            self.ad.clone(),
+        );
+        let mut buf = Vec::with_capacity(repr.compressed_size());
+        repr.serialize_compressed(&mut buf)
+            .expect("serialization into a vector can't fail");
+        buf
+    }
+
+    /// Decode a request, accepting only the canonical encoding.
+    pub fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        let (scheme, public, inputs, ad) = decode_canonical::<RequestRepr<S>>(bytes)?;
+        Ok(Self {
+            scheme: scheme.try_into()?,
+            public,
+            inputs,
+            ad,
+        })
+    }
+}
+
+/// Proving response.
+///
+/// Carries the VRF outputs, in the order of the requested inputs, and the
+/// encoded proof.
+#[derive(Debug, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ProveResponse<S: Suite> {
+    /// VRF outputs.
+    pub outputs: Vec<Output<S>>,
+    /// Encoded proof.
+    pub proof: Vec<u8>,
+}
+
+impl<S: Suite> ProveResponse<S> {
+    /// Encode the response.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut buf)
+            .expect("serialization into a vector can't fail");
+        buf
+    }
+
+    /// Decode a response, accepting only the canonical encoding.
+    pub fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        decode_canonical(bytes)
+    }
+}
+
+/// Handle a proving request with `secret`.
+///
+/// Returns the encoded response. Fails with `Error::InvalidData` if the
+/// request targets a different key.
+#[cfg(feature = "prove")]
+pub fn serve<S: PedersenSuite>(secret: &Secret<S>, request: &[u8]) -> Result<Vec<u8>, Error> {
+    let request = ProveRequest::<S>::decode(request)?;
+    if request.public.0 != secret.public().0 {
+        return Err(Error::InvalidData);
+    }
+    let ios: Vec<_> = request.inputs.iter().map(|&i| secret.vrf_io(i)).collect();
+    let ad = &request.ad;
+    let mut proof = Vec::new();
+    match request.scheme {
+        Scheme::Tiny => tiny::Prover::prove(secret, &ios, ad).serialize_compressed(&mut proof),
+        Scheme::Thin => thin::Prover::prove(secret, &ios, ad).serialize_compressed(&mut proof),
+        Scheme::Pedersen => pedersen::Prover::prove(secret, &ios, ad)
+            .0
+            .serialize_compressed(&mut proof),
+    }?;
+    let outputs = ios.iter().map(|io| io.output).collect();
+    Ok(ProveResponse { outputs, proof }.encode())
+}
+
+/// Request transport to the remote prover.
+pub trait Transport {
+    /// Transport error.
+    type Error;
+
+    /// Send an encoded request and wait for the encoded response.
+    fn send(&self, request: Vec<u8>) -> impl Future<Output = Result<Vec<u8>, Self::Error>>;
+}
+
+/// Remote proving error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteError<E> {
+    /// Transport failure.
+    Transport(E),
+    /// Malformed response or invalid proof.
+    Vrf(Error),
+}
+
+impl<E> From<Error> for RemoteError<E> {
+    fn from(err: Error) -> Self {
+        Self::Vrf(err)
+    }
+}
+
+/// Prover delegating to a remote service.
+pub struct RemoteProver<S: Suite, T: Transport> {
+    public: Public<S>,
+    transport: T,
+}
+
+impl<S: PedersenSuite, T: Transport> RemoteProver<S, T> {
+    /// Remote prover for the key `public`.
+    pub fn new(public: Public<S>, transport: T) -> Self {
+        Self { public, transport }
+    }
+
+    /// Public key of the remote secret.
+    pub fn public(&self) -> &Public<S> {
+        &self.public
+    }
+
+    async fn request<P: CanonicalSerialize + CanonicalDeserialize>(
+        &self,
+        scheme: Scheme,
+        inputs: &[Input<S>],
+        ad: &[u8],
+    ) -> Result<(Vec<VrfIo<S>>, P), RemoteError<T::Error>> {
+        let request = ProveRequest {
+            scheme,
+            public: self.public,
+            inputs: inputs.to_vec(),
+            ad: ad.to_vec(),
+        };
+        let response = self
+            .transport
+            .send(request.encode())
+            .await
+            .map_err(RemoteError::Transport)?;
+        let response = ProveResponse::<S>::decode(&response)?;
+        if response.outputs.len() != inputs.len() {
+            return Err(Error::InvalidData.into());
+        }
+        let ios = inputs
+            .iter()
+            .zip(response.outputs)
+            .map(|(&input, output)| VrfIo { input, output })
+            .collect();
+        Ok((ios, decode_canonical(&response.proof)?))
+    }
+
+    /// Tiny VRF proof, verified against [`Self::public`].
+    pub async fn prove_tiny(
+        &self,
+        inputs: &[Input<S>],
+        ad: &[u8],
+    ) -> Result<(Vec<VrfIo<S>>, tiny::Proof<S>), RemoteError<T::Error>> {
+        let (ios, proof) = self.request(Scheme::Tiny, inputs, ad).await?;
+        tiny::Verifier::verify(&self.public, &ios, ad, &proof)?;
+        Ok((ios, proof))
+    }
+
+    /// Thin VRF proof, verified against [`Self::public`].
+    pub async fn prove_thin(
+        &self,
+        inputs: &[Input<S>],
+        ad: &[u8],
+    ) -> Result<(Vec<VrfIo<S>>, thin::Proof<S>), RemoteError<T::Error>> {
+        let (ios, proof) = self.request(Scheme::Thin, inputs, ad).await?;
+        thin::Verifier::verify(&self.public, &ios, ad, &proof)?;
+        Ok((ios, proof))
+    }
+
+    /// Pedersen VRF proof.
+    ///
+    /// The proof is verified, but being key-hiding it can't be bound to
+    /// [`Self::public`]; the blinding factor stays with the service.
+    pub async fn prove_pedersen(
+        &self,
+        inputs: &[Input<S>],
+        ad: &[u8],
+    ) -> Result<(Vec<VrfIo<S>>, pedersen::Proof<S>), RemoteError<T::Error>> {
+        let (ios, proof) = self.request(Scheme::Pedersen, inputs, ad).await?;
+        <Public<S> as pedersen::Verifier<S>>::verify(&ios, ad, &proof)?;
+        Ok((ios, proof))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_SEED;
+    use suites::testing::TestSuite;
+
+    struct Loopback(Secret<TestSuite>);
+
+    impl Transport for Loopback {
+        type Error = Error;
+
+        async fn send(&self, request: Vec<u8>) -> Result<Vec<u8>, Error> {
+            serve(&self.0, &request)
+        }
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        use core::task::{Context, Poll, Waker};
+        let mut fut = core::pin::pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(out) => out,
+            Poll::Pending => panic!("loopback transport never blocks"),
+        }
+    }
+
+    #[test]
+    fn remote_prove() {
+        let secret = Secret::<TestSuite>::from_seed(TEST_SEED);
+        let prover = RemoteProver::new(secret.public(), Loopback(secret.clone()));
+        let inputs = [Input::new(b"foo").unwrap(), Input::new(b"bar").unwrap()];
+
+        let (ios, _) = block_on(prover.prove_tiny(&inputs, b"ad")).unwrap();
+        assert_eq!(ios[1], secret.vrf_io(inputs[1]));
+        assert!(block_on(prover.prove_thin(&inputs, b"ad")).is_ok());
+        assert!(block_on(prover.prove_pedersen(&inputs, b"ad")).is_ok());
+
+        let other = Secret::<TestSuite>::from_seed([1; 32]);
+        let prover = RemoteProver::new(other.public(), Loopback(secret));
+        assert_eq!(
+            block_on(prover.prove_tiny(&inputs, b"ad")).unwrap_err(),
+            RemoteError::Transport(Error::InvalidData)
+        );
+    }
+}
@@ -4,7 +4,9 @@
 //! from [CSSV22](https://eprint.iacr.org/2022/1362). Proves that a single blinded
 //! key is a member of a committed ring without revealing which one.
 //!
-//! This module is gated by the `ring` feature.
+//! This module is gated by the `ring` feature. Proving is dominated by the
+//! FFTs and MSMs of the ring proof backend, which run on `rayon` only with the
+//! `parallel` feature.
 //!
 //! ## Usage
 //!
@@ -33,7 +35,7 @@
 //! let prover_key = ring_setup.prover_key(&ring).unwrap();
 //! let prover = ring_ctx.ring_prover(prover_key, prover_key_index);
 //! let io = secret.vrf_io(input);
-//! let proof = secret.prove(io, b"aux data", &prover);
+//! let proof = secret.prove(io, b"aux data", &prover).unwrap();
 //!
 //! // Verification
 //! use ark_vrf::ring::Verifier;
@@ -66,6 +68,10 @@ pub const PADDING_SEED: &[u8] = b"ring-padding";
 ///
 /// This trait provides the cryptographic primitives needed for ring VRF signatures.
 /// All required bounds are expressed directly on the associated type for better ergonomics.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't support the Ring VRF",
+    note = "Ring VRF requires a pairing friendly suite implementing `RingSuite`, see `SuiteInfo::SUPPORTS_RING`"
+)]
 pub trait RingSuite:
     PedersenSuite<
     Affine: AffineRepr<BaseField: ark_ff::PrimeField, Config: TECurveConfig + Clone>
@@ -106,14 +112,154 @@ pub type PiopParams<S> = ring_proof::PiopParams<BaseField<S>, CurveConfig<S>>;
 /// Ring keys commitment.
 pub type RingCommitment<S> = ring_proof::FixedColumnsCommitted<BaseField<S>, PcsCommitment<S>>;
 
+/// Ring commitment identifier.
+///
+/// Hash of the compressed encoding of a [`RingCommitment`], for parties to
+/// cheaply check that they agree on a ring. The commitment type is defined by
+/// the ring proof backend, which doesn't provide equality: compare the
+/// identifiers instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RingId(pub [u8; 32]);
+
+impl RingId {
+    /// Identifier of `commitment`.
+    ///
+    /// The suite can't be inferred from the commitment type, so it must be
+    /// specified (e.g. `RingId::new::<S>(&commitment)`).
+    pub fn new<S: RingSuite>(commitment: &RingCommitment<S>) -> Self {
+        use utils::common::DomSep;
+        let mut t = S::Transcript::new(S::SUITE_ID);
+        t.absorb_raw(&[DomSep::RingCommitment as u8]);
+        t.absorb_serialize(commitment);
+        let mut id = [0; 32];
+        t.squeeze_raw(&mut id);
+        Self(id)
+    }
+}
+
 /// Ring prover key.
 pub type RingProverKey<S> = ring_proof::ProverKey<BaseField<S>, Kzg<S>, TEAffine<CurveConfig<S>>>;
 
 /// Ring verifier key.
 pub type RingVerifierKey<S> = ring_proof::VerifierKey<BaseField<S>, Kzg<S>>;
 
+/// Raw ring prover.
+pub type RingBareProver<S> =
+    ring_proof::ring_prover::RingProver<BaseField<S>, Kzg<S>, CurveConfig<S>>;
+
 /// Ring prover.
-pub type RingProver<S> = ring_proof::ring_prover::RingProver<BaseField<S>, Kzg<S>, CurveConfig<S>>;
+///
+/// [`RingBareProver`] for the key at a given ring position, together with
+/// the size of the ring the prover key was built for and the ring key at that
+/// position, to check the proving preconditions before running the backend
+/// (see [`Prover::prove`]).
+pub struct RingProver<S: RingSuite> {
+    backend: ProverBackend<S>,
+    key_index: usize,
+    #[cfg_attr(not(feature = "prove"), allow(dead_code))]
+    max_ring_size: usize,
+    #[cfg_attr(not(feature = "prove"), allow(dead_code))]
+    ring_size: usize,
+    #[cfg_attr(not(feature = "prove"), allow(dead_code))]
+    key: Option<TEAffine<CurveConfig<S>>>,
+}
+
+/// Backend of a [`RingProver`].
+#[allow(clippy::large_enum_variant)]
+enum ProverBackend<S: RingSuite> {
+    /// Backend prover with the plain ring proof transcript.
+    Plain(RingBareProver<S>),
+    /// Backend prover built per proof, over a transcript bound to the
+    /// context and to the proof instance (see [`bound_ring_transcript`]).
+    #[cfg_attr(not(feature = "prove"), allow(dead_code))]
+    Bound {
+        prover_key: RingProverKey<S>,
+        piop_params: PiopParams<S>,
+        context: Vec<u8>,
+    },
+}
+
+impl<S: RingSuite> RingProver<S> {
+    fn new(
+        backend: ProverBackend<S>,
+        key_index: usize,
+        max_ring_size: usize,
+        ring: &[TEAffine<CurveConfig<S>>],
+    ) -> Self {
+        Self {
+            backend,
+            key_index,
+            max_ring_size,
+            ring_size: ring.len(),
+            key: ring.get(key_index).copied(),
+        }
+    }
+
+    /// Prover key position in the ring.
+    pub fn key_index(&self) -> usize {
+        self.key_index
+    }
+
+    /// Underlying backend prover.
+    ///
+    /// `None` for provers bound to a context, whose backend prover is built
+    /// per proof (see [`RingContext::ring_prover_with_context`]).
+    pub fn bare(&self) -> Option<&RingBareProver<S>> {
+        match &self.backend {
+            ProverBackend::Plain(bare) => Some(bare),
+            ProverBackend::Bound { .. } => None,
+        }
+    }
+
+    /// Ring proof of the Pedersen proof `pedersen_proof` for `ios` and `ad`.
+    #[cfg(feature = "prove")]
+    fn prove_ring(
+        &self,
+        ios: &[VrfIo<S>],
+        ad: &[u8],
+        pedersen_proof: &PedersenProof<S>,
+        secret_blinding: ScalarField<S>,
+    ) -> RingBareProof<S> {
+        match &self.backend {
+            ProverBackend::Plain(bare) => bare.prove(secret_blinding),
+            ProverBackend::Bound {
+                prover_key,
+                piop_params,
+                context,
+            } => {
+                let transcript = bound_ring_transcript::<S>(context, pedersen_proof, ios, ad);
+                RingBareProver::<S>::init(
+                    prover_key.clone(),
+                    piop_params.clone(),
+                    self.key_index,
+                    transcript,
+                )
+                .prove(secret_blinding)
+            }
+        }
+    }
+
+    /// Check the proving preconditions for `public`.
+    #[cfg(feature = "prove")]
+    fn check(&self, public: &Public<S>) -> Result<(), Error> {
+        if self.ring_size > self.max_ring_size {
+            return Err(Error::RingTooLarge {
+                size: self.ring_size,
+                max: self.max_ring_size,
+            });
+        }
+        if self.key_index >= self.ring_size {
+            return Err(Error::KeyIndexOutOfRange {
+                index: self.key_index,
+                ring_size: self.ring_size,
+            });
+        }
+        match public.0.into_te() {
+            Some(pk) if self.key == Some(pk) => Ok(()),
+            _ => Err(Error::KeyNotInRing),
+        }
+    }
+}
 
 /// Ring verifier.
 pub type RingVerifier<S> =
@@ -148,17 +294,94 @@ pub struct Proof<S: RingSuite> {
     pub ring_proof: RingBareProof<S>,
 }
 
+impl<S: RingSuite> core::fmt::Debug for Proof<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use utils::common::{Hex, encode_compressed};
+        let mut f = f.debug_struct("Proof");
+        f.field("suite", &format_args!("{:08x}", S::SUITE_ID));
+        self.pedersen_proof.debug_fields(&mut f);
+        f.field("ring_proof", &Hex(&encode_compressed(&self.ring_proof)))
+            .finish()
+    }
+}
+
+impl<S: RingSuite> core::fmt::Display for Proof<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        utils::common::fmt_proof::<S>(self, f)
+    }
+}
+
+impl<S: RingSuite> Proof<S> {
+    /// Check that the proof has a unique canonical encoding.
+    ///
+    /// Checks the Pedersen proof (see [`PedersenProof::check_canonical`]) and
+    /// that the ring proof survives an encoding round trip.
+    pub fn check_canonical(&self) -> Result<(), Error> {
+        self.pedersen_proof.check_canonical()?;
+        let mut buf = Vec::new();
+        self.ring_proof.serialize_compressed(&mut buf)?;
+        utils::decode_canonical::<RingBareProof<S>>(&buf).map(|_| ())
+    }
+
+    /// Returns `true` if [`Self::check_canonical`] succeeds.
+    pub fn is_canonical(&self) -> bool {
+        self.check_canonical().is_ok()
+    }
+
+    /// Check the proof invariants, without verifying it.
+    ///
+    /// Checks the Pedersen proof invariants (see
+    /// [`PedersenProof::check_structure`]), that the key commitment has a
+    /// Twisted Edwards image for the ring proof, and that the ring proof is
+    /// canonical. Much cheaper than the ring proof verification, so that e.g.
+    /// mempools can discard malformed proofs before verification.
+    ///
+    /// Passing the checks doesn't mean that the proof is valid.
+    pub fn check_structure(&self) -> Result<(), Error> {
+        self.pedersen_proof.check_structure()?;
+        let _ = self
+            .pedersen_proof
+            .key_commitment()
+            .into_te()
+            .ok_or(Error::InvalidData)?;
+        self.check_canonical()
+    }
+}
+
 /// Trait for types that can generate Ring VRF proofs.
+#[cfg(feature = "prove")]
 pub trait Prover<S: RingSuite> {
     /// Generate a proof for the given VRF I/O pairs and additional data.
     ///
     /// Multiple I/O pairs are delinearized into a single merged pair before proving.
+    ///
+    /// The ring preconditions are checked before proving, returning:
+    /// - `Error::RingTooLarge` if the ring exceeds the prover max ring size;
+    /// - `Error::KeyIndexOutOfRange` if the prover key index is not in the ring;
+    /// - `Error::KeyNotInRing` if the ring key at the prover key index is not
+    ///   the prover public key.
+    ///
+    /// The ring is the one the prover key was built for (see
+    /// [`RingContext::ring_prover`]).
     fn prove(
         &self,
         ios: impl AsRef<[VrfIo<S>]>,
         ad: impl AsRef<[u8]>,
         prover: &RingProver<S>,
-    ) -> Proof<S>;
+    ) -> Result<Proof<S>, Error>;
+
+    /// Generate a proof for the input derived from `salt` and `data`.
+    ///
+    /// The input is [`Input::new_salted`], returned with its output in the
+    /// proven I/O pair. Returns `Error::HashToCurveFailure` if the input can't
+    /// be derived, and the [`Prover::prove`] errors.
+    fn prove_salted(
+        &self,
+        salt: &[u8],
+        data: &[u8],
+        ad: impl AsRef<[u8]>,
+        prover: &RingProver<S>,
+    ) -> Result<(VrfIo<S>, Proof<S>), Error>;
 }
 
 /// Trait for entities that can verify Ring VRF proofs.
@@ -177,6 +400,8 @@ pub trait Prover<S: RingSuite> {
 /// Using unchecked constructors (e.g. [`Input::from_affine_unchecked`]) places
 /// the burden of subgroup validation on the caller. Passing points with
 /// cofactor components leads to undefined verification behavior.
+///
+/// Proofs involving an identity VRF output are always rejected.
 pub trait Verifier<S: RingSuite> {
     /// Verify a proof for the given VRF I/O pairs and additional data.
     ///
@@ -189,22 +414,54 @@ pub trait Verifier<S: RingSuite> {
         sig: &Proof<S>,
         verifier: &RingVerifier<S>,
     ) -> Result<(), Error>;
+
+    /// Verify a proof for `output` of the input derived from `salt` and `data`.
+    ///
+    /// Same as [`Verifier::verify`] for the I/O pair of [`Input::new_salted`] and
+    /// `output`. Returns `Error::HashToCurveFailure` if the input can't be
+    /// derived.
+    fn verify_salted(
+        salt: &[u8],
+        data: &[u8],
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+        verifier: &RingVerifier<S>,
+    ) -> Result<(), Error> {
+        let input = Input::new_salted(salt, data).ok_or(Error::HashToCurveFailure)?;
+        Self::verify(VrfIo { input, output }, ad, proof, verifier)
+    }
 }
 
+#[cfg(feature = "prove")]
 impl<S: RingSuite> Prover<S> for Secret<S> {
     fn prove(
         &self,
         ios: impl AsRef<[VrfIo<S>]>,
         ad: impl AsRef<[u8]>,
         ring_prover: &RingProver<S>,
-    ) -> Proof<S> {
+    ) -> Result<Proof<S>, Error> {
         use pedersen::Prover as PedersenProver;
+        trace_span!("ring_prove", ios = ios.as_ref().len());
+        ring_prover.check(&self.public)?;
+        let (ios, ad) = (ios.as_ref(), ad.as_ref());
         let (pedersen_proof, secret_blinding) = <Self as PedersenProver<S>>::prove(self, ios, ad);
-        let ring_proof = ring_prover.prove(secret_blinding);
-        Proof {
+        let ring_proof = ring_prover.prove_ring(ios, ad, &pedersen_proof, secret_blinding);
+        Ok(Proof {
             pedersen_proof,
             ring_proof,
-        }
+        })
+    }
+
+    fn prove_salted(
+        &self,
+        salt: &[u8],
+        data: &[u8],
+        ad: impl AsRef<[u8]>,
+        prover: &RingProver<S>,
+    ) -> Result<(VrfIo<S>, Proof<S>), Error> {
+        let io = self.vrf_io(Input::new_salted(salt, data).ok_or(Error::HashToCurveFailure)?);
+        Ok((io, self.prove(io, ad, prover)?))
     }
 }
 
@@ -215,17 +472,130 @@ impl<S: RingSuite> Verifier<S> for Public<S> {
         proof: &Proof<S>,
         verifier: &RingVerifier<S>,
     ) -> Result<(), Error> {
-        use pedersen::Verifier as PedersenVerifier;
-        <Self as PedersenVerifier<S>>::verify(ios, ad, &proof.pedersen_proof)?;
-        let key_commitment = proof
-            .pedersen_proof
-            .key_commitment()
-            .into_te()
-            .ok_or(Error::InvalidData)?;
-        if !verifier.verify(proof.ring_proof.clone(), key_commitment) {
-            return Err(Error::VerificationFailure);
-        }
-        Ok(())
+        verify(ios, ad, proof, verifier, None)
+    }
+}
+
+fn verify<S: RingSuite>(
+    ios: impl AsRef<[VrfIo<S>]>,
+    ad: impl AsRef<[u8]>,
+    proof: &Proof<S>,
+    verifier: &RingVerifier<S>,
+    precomp: Option<&utils::SuitePrecomp<S>>,
+) -> Result<(), Error> {
+    use pedersen::Verifier as PedersenVerifier;
+    trace_span!("ring_verify", ios = ios.as_ref().len());
+    match precomp {
+        Some(precomp) => <Public<S> as PedersenVerifier<S>>::verify_with_precomp(
+            ios,
+            ad,
+            &proof.pedersen_proof,
+            precomp,
+        )?,
+        None => <Public<S> as PedersenVerifier<S>>::verify(ios, ad, &proof.pedersen_proof)?,
+    }
+    let key_commitment = proof
+        .pedersen_proof
+        .key_commitment()
+        .into_te()
+        .ok_or(Error::InvalidData)?;
+    if !verifier.verify(proof.ring_proof.clone(), key_commitment) {
+        return Err(Error::VerificationFailure);
+    }
+    Ok(())
+}
+
+/// Ring verifier with the verification precomputation done once.
+///
+/// Bundles a [`RingVerifier`], whose construction prepares the KZG pairing
+/// inputs and holds the ring commitment, with the fixed-base tables of the
+/// generator and blinding base used by the Pedersen part of the proof.
+///
+/// Meant to be built once per ring and reused for all the proofs verified
+/// against it, rather than building a verifier per proof.
+pub struct PreparedRingVerifier<S: RingSuite> {
+    verifier: RingVerifier<S>,
+    precomp: utils::SuitePrecomp<S>,
+}
+
+impl<S: RingSuite> PreparedRingVerifier<S> {
+    /// Prepare the verifier for `verifier_key`.
+    pub fn new(ring_ctx: &RingContext<S>, verifier_key: RingVerifierKey<S>) -> Self {
+        Self::from_verifier(ring_ctx.ring_verifier(verifier_key))
+    }
+
+    /// Prepare an existing verifier.
+    pub fn from_verifier(verifier: RingVerifier<S>) -> Self {
+        Self::with_precomp(verifier, utils::SuitePrecomp::new_pedersen())
+    }
+
+    /// Prepare an existing verifier, with tables shared with other verifiers.
+    ///
+    /// The blinding base table is used only if built (see
+    /// [`SuitePrecomp::new_pedersen`](utils::SuitePrecomp::new_pedersen)).
+    pub fn with_precomp(verifier: RingVerifier<S>, precomp: utils::SuitePrecomp<S>) -> Self {
+        Self { verifier, precomp }
+    }
+
+    /// Underlying ring verifier.
+    pub fn verifier(&self) -> &RingVerifier<S> {
+        &self.verifier
+    }
+
+    /// Verify a proof for the given VRF I/O pairs and additional data.
+    ///
+    /// Same as [`Verifier::verify`].
+    pub fn verify(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        verify(ios, ad, proof, &self.verifier, Some(&self.precomp))
+    }
+}
+
+/// Verifier of ring proofs bound to a context.
+///
+/// Created by [`RingContext::ring_verifier_with_context`]. As the ring proof
+/// transcript is bound to the proof instance, the backend verifier is built
+/// per proof from a copy of the verifier key.
+#[derive(Clone)]
+pub struct BoundRingVerifier<S: RingSuite> {
+    verifier_key: RingVerifierKey<S>,
+    piop_params: PiopParams<S>,
+    context: Vec<u8>,
+}
+
+impl<S: RingSuite> BoundRingVerifier<S> {
+    /// Backend verifier for the ring proof coming with `pedersen_proof`.
+    fn ring_verifier(
+        &self,
+        ios: &[VrfIo<S>],
+        ad: &[u8],
+        pedersen_proof: &PedersenProof<S>,
+    ) -> RingVerifier<S> {
+        let transcript = bound_ring_transcript::<S>(&self.context, pedersen_proof, ios, ad);
+        RingVerifier::<S>::init(
+            self.verifier_key.clone(),
+            self.piop_params.clone(),
+            transcript,
+        )
+    }
+
+    /// Verify a proof for the given VRF I/O pairs and additional data.
+    ///
+    /// Same as [`Verifier::verify`], for proofs of a prover bound to the
+    /// same context.
+    pub fn verify(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        let (ios, ad) = (ios.as_ref(), ad.as_ref());
+        let verifier = self.ring_verifier(ios, ad, &proof.pedersen_proof);
+        verify(ios, ad, proof, &verifier, None)
     }
 }
 
@@ -259,13 +629,34 @@ impl<S: RingSuite> RingContext<S> {
         Self { piop_params }
     }
 
+    /// Construct context for the given PIOP domain size.
+    ///
+    /// The context handles rings up to
+    /// [`max_ring_size_from_piop_domain_size`] keys.
+    ///
+    /// Returns `Error::InvalidData` if `piop_domain_size` is not a power of
+    /// two larger than the [`piop_overhead`].
+    pub fn from_piop_domain_size(piop_domain_size: usize) -> Result<Self, Error> {
+        ring_size_from_piop_domain_size::<S>(piop_domain_size).map(Self::new)
+    }
+
     /// The max ring size this context is able to handle.
     #[inline(always)]
     pub fn max_ring_size(&self) -> usize {
         self.piop_params.keyset_part_size
     }
 
+    /// The PIOP domain size of this context.
+    #[inline(always)]
+    pub fn piop_domain_size(&self) -> usize {
+        piop_domain_size::<S>(self.max_ring_size())
+    }
+
     /// Create a prover instance for a specific position in the ring.
+    ///
+    /// The ring keys are read back from `prover_key`, so that proving checks
+    /// the key index against the size of the ring the key was built for, and
+    /// the prover public key against the ring key at that index.
     pub fn ring_prover(&self, prover_key: RingProverKey<S>, key_index: usize) -> RingProver<S> {
         self.clone().into_ring_prover(prover_key, key_index)
     }
@@ -277,22 +668,163 @@ impl<S: RingSuite> RingContext<S> {
 
     /// Create a prover instance, consuming the context to avoid cloning.
     pub fn into_ring_prover(self, prover_key: RingProverKey<S>, key_index: usize) -> RingProver<S> {
-        RingProver::<S>::init(
+        let max_ring_size = self.max_ring_size();
+        let ring = self.prover_key_ring(&prover_key);
+        let bare = RingBareProver::<S>::init(
             prover_key,
             self.piop_params,
             key_index,
-            ring_proof::ArkTranscript::new(const { &S::SUITE_ID.to_bytes() }),
-        )
+            ring_transcript::<S>(),
+        );
+        RingProver::new(ProverBackend::Plain(bare), key_index, max_ring_size, &ring)
     }
 
     /// Create a verifier instance, consuming the context to avoid cloning.
     pub fn into_ring_verifier(self, verifier_key: RingVerifierKey<S>) -> RingVerifier<S> {
-        RingVerifier::<S>::init(
+        RingVerifier::<S>::init(verifier_key, self.piop_params, ring_transcript::<S>())
+    }
+
+    /// Create a prover instance whose ring proofs are bound to `context`.
+    ///
+    /// Each ring proof transcript absorbs the context, together with the key
+    /// commitment, VRF I/O pairs and additional data of the Pedersen proof it
+    /// comes with. The ring proof then only verifies for that Pedersen proof
+    /// and with a verifier created for the same context (see
+    /// [`Self::ring_verifier_with_context`]).
+    ///
+    /// As the transcript differs for each proof, the backend prover is built
+    /// per proof from a copy of `prover_key`.
+    pub fn ring_prover_with_context(
+        &self,
+        prover_key: RingProverKey<S>,
+        key_index: usize,
+        context: &[u8],
+    ) -> RingProver<S> {
+        let ring = self.prover_key_ring(&prover_key);
+        let backend = ProverBackend::Bound {
+            prover_key,
+            piop_params: self.piop_params.clone(),
+            context: context.to_vec(),
+        };
+        RingProver::new(backend, key_index, self.max_ring_size(), &ring)
+    }
+
+    /// Ring keys `prover_key` was built for.
+    ///
+    /// The backend doesn't expose the prover key columns, so the keys are
+    /// read back from the prover key encoding: the committer key followed by
+    /// the points column, made of the ring keys padded with
+    /// [`RingSuite::PADDING`], and of the multiples of the blinding base.
+    ///
+    /// Trailing padding points are not counted as ring keys.
+    fn prover_key_ring(&self, prover_key: &RingProverKey<S>) -> Vec<TEAffine<CurveConfig<S>>> {
+        type CommitterKey<S> = <Kzg<S> as ring_proof::pcs::PCS<BaseField<S>>>::CK;
+        let mut buf = Vec::with_capacity(prover_key.uncompressed_size());
+        prover_key
+            .serialize_uncompressed(&mut buf)
+            .expect("Serialization to a vector never fails");
+        let (_, mut points) =
+            <(CommitterKey<S>, Vec<TEAffine<CurveConfig<S>>>)>::deserialize_uncompressed_unchecked(
+                &buf[..],
+            )
+            .expect("Prover key encoding starts with the points column");
+        let multiples = self.piop_params.power_of_2_multiples_of_h().len();
+        points.truncate(points.len().saturating_sub(multiples));
+        let padding = S::PADDING.into_te();
+        while points.last().is_some() && points.last().copied() == padding {
+            points.pop();
+        }
+        points
+    }
+
+    /// Create a verifier instance for ring proofs bound to `context`.
+    ///
+    /// See [`Self::ring_prover_with_context`].
+    pub fn ring_verifier_with_context(
+        &self,
+        verifier_key: RingVerifierKey<S>,
+        context: &[u8],
+    ) -> BoundRingVerifier<S> {
+        BoundRingVerifier {
             verifier_key,
-            self.piop_params,
-            ring_proof::ArkTranscript::new(const { &S::SUITE_ID.to_bytes() }),
-        )
+            piop_params: self.piop_params.clone(),
+            context: context.to_vec(),
+        }
+    }
+}
+
+/// Plain ring proof transcript.
+fn ring_transcript<S: RingSuite>() -> ring_proof::ArkTranscript {
+    ring_proof::ArkTranscript::new(const { &S::SUITE_ID.to_bytes() })
+}
+
+/// Ring proof transcript bound to `context` and to the proof instance.
+///
+/// On top of the plain transcript, absorbs the context together with the key
+/// commitment of `pedersen_proof` and the VRF I/O pairs and additional data
+/// it is a proof for, so that the ring proof can't be detached from them.
+fn bound_ring_transcript<S: RingSuite>(
+    context: &[u8],
+    pedersen_proof: &PedersenProof<S>,
+    ios: &[VrfIo<S>],
+    ad: &[u8],
+) -> ring_proof::ArkTranscript {
+    use w3f_plonk_common::transcript::PlonkTranscript;
+    let mut transcript = ring_transcript::<S>();
+    let instance = (
+        context.to_vec(),
+        pedersen_proof.key_commitment(),
+        ios.to_vec(),
+        ad.to_vec(),
+    );
+    <_ as PlonkTranscript<BaseField<S>, Kzg<S>>>::add_instance(&mut transcript, &instance);
+    transcript
+}
+
+/// Check that `pcs_params` has enough powers for `ring_size`.
+fn check_pcs_params<S: RingSuite>(
+    ring_size: usize,
+    pcs_params: &PcsParams<S>,
+) -> Result<(), Error> {
+    let pcs_domain_size = pcs_domain_size::<S>(ring_size);
+    if pcs_params.powers_in_g1.len() < pcs_domain_size {
+        return Err(Error::SrsTooShort {
+            needed: pcs_domain_size,
+            got: pcs_params.powers_in_g1.len(),
+        });
+    }
+    if pcs_params.powers_in_g2.len() < 2 {
+        return Err(Error::SrsTooShort {
+            needed: 2,
+            got: pcs_params.powers_in_g2.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Max ring size of a valid PIOP domain size.
+fn ring_size_from_piop_domain_size<S: RingSuite>(piop_domain_size: usize) -> Result<usize, Error> {
+    if !piop_domain_size.is_power_of_two() || piop_domain_size <= piop_overhead::<S>() {
+        return Err(Error::InvalidData);
+    }
+    Ok(max_ring_size_from_piop_domain_size::<S>(piop_domain_size))
+}
+
+/// Prover and verifier keys for the given ring of public keys.
+fn ring_index<S: RingSuite>(
+    pcs_params: &PcsParams<S>,
+    piop_params: &PiopParams<S>,
+    pks: &[AffinePoint<S>],
+) -> Result<(RingProverKey<S>, RingVerifierKey<S>), Error> {
+    trace_span!("ring_index", ring_size = pks.len());
+    if pks.len() > piop_params.keyset_part_size {
+        return Err(Error::RingTooLarge {
+            size: pks.len(),
+            max: piop_params.keyset_part_size,
+        });
     }
+    let pks = TEMapping::to_te_slice(pks).ok_or(Error::InvalidData)?;
+    Ok(ring_proof::index(pcs_params, piop_params, &pks))
 }
 
 /// Ring proof setup.
@@ -340,15 +872,14 @@ impl<S: RingSuite> RingSetup<S> {
 
     /// Construct ring proof params from existing KZG setup.
     ///
-    /// Truncates the setup if larger than needed, or returns an error if it is
-    /// insufficient for the specified ring size.
+    /// Truncates the setup if larger than needed, or returns
+    /// `Error::SrsTooShort` if it is insufficient for the specified ring size.
     pub fn from_pcs_params(ring_size: usize, mut pcs_params: PcsParams<S>) -> Result<Self, Error> {
-        let pcs_domain_size = pcs_domain_size::<S>(ring_size);
-        if pcs_params.powers_in_g1.len() < pcs_domain_size || pcs_params.powers_in_g2.len() < 2 {
-            return Err(Error::InvalidData);
-        }
+        check_pcs_params::<S>(ring_size, &pcs_params)?;
         // Keep only the required powers of tau
-        pcs_params.powers_in_g1.truncate(pcs_domain_size);
+        pcs_params
+            .powers_in_g1
+            .truncate(pcs_domain_size::<S>(ring_size));
         pcs_params.powers_in_g2.truncate(2);
 
         Ok(Self {
@@ -357,26 +888,37 @@ impl<S: RingSuite> RingSetup<S> {
         })
     }
 
+    /// Construct ring proof params for the given PIOP domain size from
+    /// existing KZG setup.
+    ///
+    /// Same as [`Self::from_pcs_params`] with the max ring size of the domain.
+    /// The setup requires [`pcs_domain_size_from_piop_domain_size`] powers.
+    ///
+    /// Returns `Error::InvalidData` if `piop_domain_size` is not a power of
+    /// two larger than the [`piop_overhead`], or `Error::SrsTooShort` if the
+    /// setup is insufficient for the domain.
+    pub fn from_piop_domain_size(
+        piop_domain_size: usize,
+        pcs_params: PcsParams<S>,
+    ) -> Result<Self, Error> {
+        let ring_size = ring_size_from_piop_domain_size::<S>(piop_domain_size)?;
+        Self::from_pcs_params(ring_size, pcs_params)
+    }
+
     /// Create a prover key for the given ring of public keys.
     ///
-    /// Returns `Error::InvalidData` if `pks` exceeds the max ring size.
+    /// Returns `Error::RingTooLarge` if `pks` exceeds the max ring size: keys
+    /// are never dropped to fit.
     pub fn prover_key(&self, pks: &[AffinePoint<S>]) -> Result<RingProverKey<S>, Error> {
-        if pks.len() > self.piop_params.keyset_part_size {
-            return Err(Error::InvalidData);
-        }
-        let pks = TEMapping::to_te_slice(pks).ok_or(Error::InvalidData)?;
-        Ok(ring_proof::index(&self.pcs_params, &self.piop_params, &pks).0)
+        ring_index::<S>(&self.pcs_params, &self.piop_params, pks).map(|keys| keys.0)
     }
 
     /// Create a verifier key for the given ring of public keys.
     ///
-    /// Returns `Error::InvalidData` if `pks` exceeds the max ring size.
+    /// Returns `Error::RingTooLarge` if `pks` exceeds the max ring size: keys
+    /// are never dropped to fit.
     pub fn verifier_key(&self, pks: &[AffinePoint<S>]) -> Result<RingVerifierKey<S>, Error> {
-        if pks.len() > self.piop_params.keyset_part_size {
-            return Err(Error::InvalidData);
-        }
-        let pks = TEMapping::to_te_slice(pks).ok_or(Error::InvalidData)?;
-        Ok(ring_proof::index(&self.pcs_params, &self.piop_params, &pks).1)
+        ring_index::<S>(&self.pcs_params, &self.piop_params, pks).map(|keys| keys.1)
     }
 
     /// Create a verifier key from a precomputed ring commitment.
@@ -582,6 +1124,7 @@ pub struct BatchItem<S: RingSuite> {
 pub struct BatchVerifier<S: RingSuite> {
     ring_batch: RingBatchVerifier<S>,
     pedersen_batch: pedersen::BatchVerifier<S>,
+    bound: Option<BoundRingVerifier<S>>,
 }
 
 impl<S: RingSuite> BatchVerifier<S> {
@@ -590,9 +1133,31 @@ impl<S: RingSuite> BatchVerifier<S> {
         Self {
             ring_batch: ring_verifier.kzg_batch_verifier(),
             pedersen_batch: pedersen::BatchVerifier::new(),
+            bound: None,
+        }
+    }
+
+    /// Create a new batch verifier for ring proofs bound to a context.
+    ///
+    /// See [`RingContext::ring_verifier_with_context`].
+    pub fn new_bound(verifier: BoundRingVerifier<S>) -> Self {
+        let ring_verifier = RingVerifier::<S>::init(
+            verifier.verifier_key.clone(),
+            verifier.piop_params.clone(),
+            ring_transcript::<S>(),
+        );
+        Self {
+            bound: Some(verifier),
+            ..Self::new(ring_verifier)
         }
     }
 
+    /// Set the configuration of the Pedersen batch multi-scalar multiplication.
+    pub fn with_msm_config(mut self, config: utils::msm::MsmConfig) -> Self {
+        self.pedersen_batch = self.pedersen_batch.with_msm_config(config);
+        self
+    }
+
     /// Prepare a proof for deferred batch verification.
     ///
     /// Performs the cheap per-proof work (hashing, transcript setup) without
@@ -606,15 +1171,21 @@ impl<S: RingSuite> BatchVerifier<S> {
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> Result<BatchItem<S>, Error> {
+        let (ios, ad) = (ios.as_ref(), ad.as_ref());
         let pedersen = pedersen::BatchVerifier::prepare(ios, ad, &proof.pedersen_proof);
         let key_commitment = proof
             .pedersen_proof
             .key_commitment()
             .into_te()
             .ok_or(Error::InvalidData)?;
-        let ring = self
-            .ring_batch
-            .prepare(proof.ring_proof.clone(), key_commitment);
+        let ring_proof = proof.ring_proof.clone();
+        let ring = match &self.bound {
+            Some(bound) => bound
+                .ring_verifier(ios, ad, &proof.pedersen_proof)
+                .kzg_batch_verifier()
+                .prepare(ring_proof, key_commitment),
+            None => self.ring_batch.prepare(ring_proof, key_commitment),
+        };
         Ok(BatchItem { ring, pedersen })
     }
 
@@ -639,11 +1210,36 @@ impl<S: RingSuite> BatchVerifier<S> {
         Ok(())
     }
 
+    /// Prepare and push several proofs, in order.
+    ///
+    /// Under the `parallel` feature the proofs are prepared in parallel.
+    ///
+    /// Returns the first [`Self::prepare`] error, pushing none of the proofs.
+    pub fn push_all_par<I, A>(&mut self, items: &[(I, A, &Proof<S>)]) -> Result<(), Error>
+    where
+        I: AsRef<[VrfIo<S>]> + Sync,
+        A: AsRef<[u8]> + Sync,
+    {
+        let prepare = |(ios, ad, proof): &(I, A, &Proof<S>)| self.prepare(ios, ad, proof);
+        #[cfg(feature = "parallel")]
+        let prepared: Vec<_> = {
+            use rayon::prelude::*;
+            items.par_iter().map(prepare).collect::<Result<_, _>>()?
+        };
+        #[cfg(not(feature = "parallel"))]
+        let prepared: Vec<_> = items.iter().map(prepare).collect::<Result<_, _>>()?;
+        prepared
+            .into_iter()
+            .for_each(|item| self.push_prepared(item));
+        Ok(())
+    }
+
     /// Verify all collected proofs in a single batch.
     ///
     /// Checks both the Pedersen proofs (via MSM) and the ring proofs (via pairing).
     /// Returns `Ok(())` if all proofs verify, `Err(VerificationFailure)` otherwise.
     pub fn verify(&self) -> Result<(), Error> {
+        trace_span!("ring_batch_verify", proofs = self.pedersen_batch.len());
         self.pedersen_batch.verify()?;
         self.ring_batch
             .verify()
@@ -677,11 +1273,29 @@ macro_rules! ring_suite_types {
         #[allow(dead_code)]
         pub type RingProof = $crate::ring::Proof<$suite>;
         #[allow(dead_code)]
+        pub type RingBareProof = $crate::ring::RingBareProof<$suite>;
+        #[allow(dead_code)]
         pub type RingVerifierKeyBuilder = $crate::ring::VerifierKeyBuilder<$suite>;
         #[allow(dead_code)]
         pub type RingBatchItem = $crate::ring::BatchItem<$suite>;
         #[allow(dead_code)]
         pub type RingBatchVerifier = $crate::ring::BatchVerifier<$suite>;
+        #[allow(dead_code)]
+        pub type RingBudgetedVerifier = $crate::ring::BudgetedVerifier<$suite>;
+        #[allow(dead_code)]
+        pub type RingTicket = $crate::ring::Ticket<$suite>;
+        #[allow(dead_code)]
+        pub type RingState = $crate::ring::RingState<$suite>;
+        #[allow(dead_code)]
+        pub type KeyRegistry = $crate::ring::KeyRegistry<$suite>;
+        #[allow(dead_code)]
+        pub type PreparedRingVerifier = $crate::ring::PreparedRingVerifier<$suite>;
+        #[allow(dead_code)]
+        pub type BoundRingVerifier = $crate::ring::BoundRingVerifier<$suite>;
+        #[allow(dead_code)]
+        pub type RingProverParams<'a> = $crate::ring::ProverParams<'a, $suite>;
+        #[allow(dead_code)]
+        pub type RingVerifierParams = $crate::ring::VerifierParams<$suite>;
     };
 }
 
@@ -775,37 +1389,280 @@ pub mod dom_utils {
 }
 pub use dom_utils::*;
 
-#[cfg(test)]
-pub(crate) mod testing {
+pub mod budget;
+pub use budget::{BudgetReport, BudgetedVerifier};
+#[cfg(feature = "std")]
+pub mod cache;
+pub mod canonical;
+pub use canonical::CanonicalRing;
+pub mod ceremony;
+pub use ceremony::{Ceremony, Contribution};
+pub mod membership;
+pub use membership::MembershipProof;
+#[cfg(feature = "std")]
+pub mod srs;
+
+pub mod params;
+pub use params::{KzgVerifierKey, ProverParams, VerifierParams};
+
+pub mod registry;
+pub use registry::{KeyRegistry, RingSnapshot};
+
+pub mod state;
+pub use state::RingState;
+
+pub mod ticket;
+pub use ticket::{Ticket, TicketContext, TicketId, ticket_threshold};
+
+#[cfg(any(test, feature = "vectors"))]
+pub mod vectors {
     use super::*;
     use crate::pedersen;
-    use crate::testing::{self as common, CheckPoint, TEST_SEED};
-    use ark_ec::{
-        short_weierstrass::{Affine as SWAffine, SWCurveConfig},
-        twisted_edwards::{Affine as TEAffine, TECurveConfig},
-    };
+    use crate::vectors::{self as common, SuiteExt};
+    use ark_std::UniformRand;
 
-    pub const TEST_RING_SIZE: usize = 8;
+    /// Ring size used by the vectors.
+    pub const RING_SIZE: usize = 8;
 
-    const MAX_AD_LEN: usize = 100;
+    /// Ring suite with a ring setup for the vectors.
+    pub trait RingSuiteExt: RingSuite + SuiteExt {
+        /// File holding the uncompressed PCS parameters.
+        const SRS_FILE: &str;
 
-    fn find_complement_point<C: SWCurveConfig>() -> SWAffine<C> {
-        use ark_ff::{One, Zero};
-        assert!(!C::cofactor_is_one());
-        let mut x = C::BaseField::zero();
-        loop {
-            if let Some(p) = SWAffine::get_point_from_x_unchecked(x, false)
-                .filter(|p| !p.is_in_correct_subgroup_assuming_on_curve())
-            {
-                return p;
-            }
-            x += C::BaseField::one();
+        /// Ring setup loaded from [`Self::SRS_FILE`].
+        fn ring_setup() -> &'static RingSetup<Self>;
+
+        #[allow(unused)]
+        fn load_ring_setup() -> RingSetup<Self> {
+            use ark_serialize::CanonicalDeserialize;
+            use std::{fs::File, io::Read};
+            let mut file = File::open(Self::SRS_FILE).unwrap();
+            let mut buf = Vec::new();
+            file.read_to_end(&mut buf).unwrap();
+            let pcs_params =
+                PcsParams::<Self>::deserialize_uncompressed_unchecked(&mut &buf[..]).unwrap();
+            RingSetup::from_pcs_params(RING_SIZE, pcs_params).unwrap()
         }
-    }
 
-    pub trait FindAccumulatorBase<S: Suite>: Sized {
-        const IN_PRIME_ORDER_SUBGROUP: bool;
-        fn find_accumulator_base(data: &[u8]) -> Option<Self>;
+        #[allow(unused)]
+        fn write_ring_setup(ring_setup: &RingSetup<Self>) {
+            use ark_serialize::CanonicalSerialize;
+            use std::{fs::File, io::Write};
+            let mut file = File::create(Self::SRS_FILE).unwrap();
+            let mut buf = Vec::new();
+            ring_setup
+                .pcs_params
+                .serialize_uncompressed(&mut buf)
+                .unwrap();
+            file.write_all(&buf).unwrap();
+        }
+    }
+
+    /// Ring VRF test vector.
+    pub struct TestVector<S: RingSuite> {
+        pub pedersen: pedersen::vectors::TestVector<S>,
+        pub ring_pks: [AffinePoint<S>; RING_SIZE],
+        pub ring_pks_com: RingCommitment<S>,
+        pub ring_proof: RingBareProof<S>,
+    }
+
+    impl<S: RingSuite> core::fmt::Debug for TestVector<S> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("TestVector")
+                .field("pedersen", &self.pedersen)
+                .field("ring_proof", &"...")
+                .finish()
+        }
+    }
+
+    impl<S> common::TestVectorTrait for TestVector<S>
+    where
+        S: RingSuiteExt + std::fmt::Debug + 'static,
+    {
+        type Suite = S;
+
+        fn name() -> String {
+            S::SUITE_NAME.to_string() + "_ring"
+        }
+
+        fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
+            use super::Prover;
+            let pedersen = pedersen::vectors::TestVector::new(comment, seed, alpha, ad);
+
+            let secret = Secret::<S>::from_scalar(pedersen.base.sk);
+            let public = secret.public();
+
+            let io = VrfIo {
+                input: Input::<S>::from_affine_unchecked(pedersen.base.h),
+                output: Output::from_affine_unchecked(pedersen.base.gamma),
+            };
+
+            let ring_setup = <S as RingSuiteExt>::ring_setup();
+
+            use ark_std::rand::SeedableRng;
+            let rng = &mut ark_std::rand::rngs::StdRng::from_seed([42; 32]);
+            let prover_idx = 3;
+            let mut ring_pks: Vec<_> = (0..RING_SIZE)
+                .map(|_| AffinePoint::<S>::rand(rng))
+                .collect();
+            ring_pks[prover_idx] = public.0;
+
+            let ring_ctx = ring_setup.ring_context();
+            let prover_key = ring_setup.prover_key(&ring_pks).unwrap();
+            let prover = ring_ctx.ring_prover(prover_key, prover_idx);
+            let proof = secret.prove(io, ad, &prover).unwrap();
+
+            let verifier_key = ring_setup.verifier_key(&ring_pks).unwrap();
+            let ring_pks_com = verifier_key.commitment();
+
+            {
+                // Just in case...
+                let mut p = (Vec::new(), Vec::new());
+                pedersen.proof.serialize_compressed(&mut p.0).unwrap();
+                proof.pedersen_proof.serialize_compressed(&mut p.1).unwrap();
+                assert_eq!(p.0, p.1);
+            }
+
+            Self {
+                pedersen,
+                ring_pks: ring_pks.try_into().unwrap(),
+                ring_pks_com,
+                ring_proof: proof.ring_proof,
+            }
+        }
+
+        fn from_map(map: &common::TestVectorMap) -> Self {
+            let pedersen = pedersen::vectors::TestVector::from_map(map);
+
+            let ring_pks = map.get::<[AffinePoint<S>; RING_SIZE]>("ring_pks");
+            let ring_pks_com = map.get::<RingCommitment<S>>("ring_pks_com");
+            let ring_proof = map.get::<RingBareProof<S>>("ring_proof");
+
+            Self {
+                pedersen,
+                ring_pks,
+                ring_pks_com,
+                ring_proof,
+            }
+        }
+
+        fn to_map(&self) -> common::TestVectorMap {
+            let mut map = self.pedersen.to_map();
+            map.set("ring_pks", &self.ring_pks);
+            map.set("ring_pks_com", &self.ring_pks_com);
+            map.set("ring_proof", &self.ring_proof);
+            map
+        }
+
+        fn run(&self) {
+            self.pedersen.run();
+
+            let io = VrfIo {
+                input: Input::<S>::from_affine_unchecked(self.pedersen.base.h),
+                output: Output::from_affine_unchecked(self.pedersen.base.gamma),
+            };
+            let secret = Secret::from_scalar(self.pedersen.base.sk);
+            let public = secret.public();
+            assert_eq!(public.0, self.pedersen.base.pk);
+
+            let ring_setup = <S as RingSuiteExt>::ring_setup();
+
+            let prover_idx = self.ring_pks.iter().position(|&pk| pk == public.0).unwrap();
+
+            let ring_ctx = ring_setup.ring_context();
+            let prover_key = ring_setup.prover_key(&self.ring_pks).unwrap();
+            let prover = ring_ctx.ring_prover(prover_key, prover_idx);
+
+            let verifier_key = ring_setup.verifier_key(&self.ring_pks).unwrap();
+            let verifier = ring_ctx.ring_verifier(verifier_key);
+
+            let proof = secret.prove(io, &self.pedersen.base.ad, &prover).unwrap();
+
+            {
+                // Check if Pedersen proof matches
+                let mut p = (Vec::new(), Vec::new());
+                self.pedersen.proof.serialize_compressed(&mut p.0).unwrap();
+                proof.pedersen_proof.serialize_compressed(&mut p.1).unwrap();
+                assert_eq!(p.0, p.1);
+            }
+
+            #[cfg(feature = "test-vectors")]
+            {
+                // Verify if the ring-proof matches. This check is performed only when
+                // deterministic proof generation is required for test vectors.
+                let mut p = (Vec::new(), Vec::new());
+                self.ring_proof.serialize_compressed(&mut p.0).unwrap();
+                proof.ring_proof.serialize_compressed(&mut p.1).unwrap();
+                assert_eq!(p.0, p.1);
+            }
+
+            assert!(Public::verify(io, &self.pedersen.base.ad, &proof, &verifier).is_ok());
+        }
+
+        fn verify_map(map: &common::TestVectorMap) -> Result<(), Error> {
+            pedersen::vectors::TestVector::<S>::verify_map(map)?;
+            let io = common::TestVector::<S>::verify_io(map)?;
+            let mut proof = Vec::new();
+            for field in ["proof_pk_com", "proof_r", "proof_ok", "proof_s", "proof_sb"] {
+                proof.extend(map.try_get_bytes(field)?);
+            }
+            proof.extend(map.try_get_bytes("ring_proof")?);
+            let proof: Proof<S> = utils::decode_canonical(&proof)?;
+
+            let ring_setup = <S as RingSuiteExt>::ring_setup();
+            let ring_pks: [AffinePoint<S>; RING_SIZE] = map.try_get("ring_pks")?;
+            let commitment = ring_setup.verifier_key(&ring_pks)?.commitment();
+            let mut buf = Vec::new();
+            commitment.serialize_compressed(&mut buf)?;
+            if buf != map.try_get_bytes("ring_pks_com")? {
+                return Err(Error::VerificationFailure);
+            }
+            let verifier_key = ring_setup.verifier_key_from_commitment(commitment);
+            let verifier = ring_setup.ring_context().ring_verifier(verifier_key);
+            Public::verify(io, map.try_get_bytes("ad")?, &proof, &verifier)
+        }
+
+        fn wrong_suite_id(&self) -> Option<common::TestVectorMap> {
+            let pedersen = self.pedersen.wrong_suite_id()?;
+            let mut map = self.to_map();
+            for (field, value) in pedersen.0 {
+                map.0.insert(field, value);
+            }
+            Some(map)
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use crate::testing::{self as common, CheckPoint, TEST_SEED};
+    use ark_ec::{
+        short_weierstrass::{Affine as SWAffine, SWCurveConfig},
+        twisted_edwards::{Affine as TEAffine, TECurveConfig},
+    };
+
+    pub const TEST_RING_SIZE: usize = super::vectors::RING_SIZE;
+
+    const MAX_AD_LEN: usize = 100;
+
+    fn find_complement_point<C: SWCurveConfig>() -> SWAffine<C> {
+        use ark_ff::{One, Zero};
+        assert!(!C::cofactor_is_one());
+        let mut x = C::BaseField::zero();
+        loop {
+            if let Some(p) = SWAffine::get_point_from_x_unchecked(x, false)
+                .filter(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+            {
+                return p;
+            }
+            x += C::BaseField::one();
+        }
+    }
+
+    pub trait FindAccumulatorBase<S: Suite>: Sized {
+        const IN_PRIME_ORDER_SUBGROUP: bool;
+        fn find_accumulator_base(data: &[u8]) -> Option<Self>;
     }
 
     impl<S, C> FindAccumulatorBase<S> for SWAffine<C>
@@ -854,7 +1711,7 @@ pub(crate) mod testing {
             let io = secret.vrf_io(input);
             let ad_len = common::random_val::<usize>(Some(rng)) % (MAX_AD_LEN + 1);
             let ad = common::random_vec(ad_len, Some(rng));
-            let proof = secret.prove(io, &ad, prover);
+            let proof = secret.prove(io, &ad, prover).unwrap();
             Self { io, ad, proof }
         }
     }
@@ -881,6 +1738,56 @@ pub(crate) mod testing {
         let verifier = ring_ctx.ring_verifier(verifier_key);
         let result = Public::verify(item.io, &item.ad, &item.proof, &verifier);
         assert!(result.is_ok());
+        assert!(item.proof.check_structure().is_ok());
+    }
+
+    #[allow(unused)]
+    pub fn prove_preconditions<S: RingSuite>() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng);
+        let ring_ctx = ring_setup.ring_context();
+        let max = ring_ctx.max_ring_size();
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let mut pks = common::random_vec::<AffinePoint<S>>(8, Some(rng));
+        pks[3] = secret.public().0;
+        let prover = |key_index| {
+            let prover_key = ring_setup.prover_key(&pks).unwrap();
+            ring_ctx.ring_prover(prover_key, key_index)
+        };
+
+        let proof = secret.prove(io, b"", &prover(3));
+        let verifier = ring_ctx.ring_verifier(ring_setup.verifier_key(&pks).unwrap());
+        assert!(Public::verify(io, b"", &proof.unwrap(), &verifier).is_ok());
+
+        let res = secret.prove(io, b"", &prover(2));
+        assert_eq!(res.err(), Some(Error::KeyNotInRing));
+        let prover_key = ring_setup.prover_key(&pks).unwrap();
+        let bound = ring_ctx.ring_prover_with_context(prover_key, 2, b"app");
+        let res = secret.prove(io, b"", &bound);
+        assert_eq!(res.err(), Some(Error::KeyNotInRing));
+        for index in [8, max] {
+            let res = secret.prove(io, b"", &prover(index));
+            assert_eq!(
+                res.err(),
+                Some(Error::KeyIndexOutOfRange {
+                    index,
+                    ring_size: 8
+                })
+            );
+        }
+
+        // Prover key built for a larger ring than the context handles.
+        let too_large = vec![pks[3]; max + 1];
+        let larger_setup = RingSetup::<S>::from_rand(max + 1, rng);
+        let prover_key = larger_setup.prover_key(&too_large).unwrap();
+        let res = secret.prove(io, b"", &ring_ctx.ring_prover(prover_key, 3));
+        let err = Error::RingTooLarge { size: max + 1, max };
+        assert_eq!(res.err(), Some(err));
+        assert_eq!(ring_setup.prover_key(&too_large).err(), Some(err));
+        assert_eq!(ring_setup.verifier_key(&too_large).err(), Some(err));
+        assert!(ring_setup.verifier_key(&too_large[..max]).is_ok());
     }
 
     /// N=3 multi proof via ring prove/verify.
@@ -916,7 +1823,7 @@ pub(crate) mod testing {
             output: Output(public.0),
         });
 
-        let proof = secret.prove(&ios[..], b"bar", &prover);
+        let proof = secret.prove(&ios[..], b"bar", &prover).unwrap();
         assert!(Public::verify(&ios[..], b"bar", &proof, &verifier).is_ok());
 
         // Tamper: wrong output on ios[1]
@@ -971,6 +1878,20 @@ pub(crate) mod testing {
             assert!(res.is_ok());
         }
 
+        // Push all at once
+        let items: Vec<_> = batch
+            .iter()
+            .map(|item| (item.io, item.ad.as_slice(), &item.proof))
+            .collect();
+        let verifier = ring_ctx.ring_verifier(ring_setup.verifier_key(&pks).unwrap());
+        let mut batch_verifier = BatchVerifier::<S>::new(verifier);
+        batch_verifier.push_all_par(&items).unwrap();
+        assert!(batch_verifier.verify().is_ok());
+        batch_verifier
+            .push_all_par(&[(batch[0].io, b"bad".as_slice(), &batch[0].proof)])
+            .unwrap();
+        assert!(batch_verifier.verify().is_err());
+
         println!("Batch size = {BATCH_SIZE}");
 
         println!("============================================================");
@@ -1012,6 +1933,49 @@ pub(crate) mod testing {
         println!("Total time: {:?}", start.elapsed());
     }
 
+    pub fn budgeted_verify<S: RingSuite>() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let mut pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        pks[3] = secret.public().0;
+        let ring_ctx = ring_setup.ring_context();
+        let prover = ring_ctx.ring_prover(ring_setup.prover_key(&pks).unwrap(), 3);
+        let verifier_key = ring_setup.verifier_key(&pks).unwrap();
+
+        let batch: Vec<_> = (0..6)
+            .map(|_| BatchItem::<S>::new(&secret, &prover, rng))
+            .collect();
+        // Item 4 has the wrong additional data.
+        let items: Vec<_> = batch
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let ad = if i == 4 { &b"bad"[..] } else { &item.ad[..] };
+                (i as u64 % 3, item.io, ad, &item.proof)
+            })
+            .collect();
+
+        let verifier = BudgetedVerifier::new(ring_ctx.clone(), verifier_key)
+            .with_batch_size(2)
+            .with_max_proofs(5);
+        let report = verifier.verify(&items, || false);
+        assert_eq!(report.verified, [2, 5, 1, 0]);
+        assert_eq!(report.rejected, [4]);
+        assert_eq!(report.deferred, [3]);
+
+        // Expired after the first batch.
+        let mut batches = 0;
+        let report = verifier.verify(&items, || {
+            batches += 1;
+            batches > 1
+        });
+        assert_eq!(report.verified, [2, 5]);
+        assert!(report.rejected.is_empty());
+        assert_eq!(report.deferred, [1, 4, 0, 3]);
+    }
+
     #[allow(unused)]
     pub fn padding_check<S: RingSuite>()
     where
@@ -1061,7 +2025,7 @@ pub(crate) mod testing {
 
         let prover_key = ring_setup.prover_key(&pks).unwrap();
         let prover = ring_ctx.ring_prover(prover_key, prover_idx);
-        let proof = secret.prove(io, b"foo", &prover);
+        let proof = secret.prove(io, b"foo", &prover).unwrap();
 
         // Incremental ring verifier key construction
         let (mut vk_builder, lookup) = ring_setup.verifier_key_builder();
@@ -1145,12 +2109,289 @@ pub(crate) mod testing {
             assert!(piop_domain_size::<S>(max_ring + 1) > piop_dom);
         }
 
+        // Contexts from explicit domain sizes
+        let piop_dom = piop_domain_size::<S>(TEST_RING_SIZE);
+        let ring_ctx = RingContext::<S>::from_piop_domain_size(piop_dom).unwrap();
+        assert_eq!(ring_ctx.piop_domain_size(), piop_dom);
+        assert_eq!(ring_ctx.max_ring_size(), max_ring_size::<S>(TEST_RING_SIZE));
+        assert!(RingContext::<S>::from_piop_domain_size(piop_dom + 1).is_err());
+        assert!(RingContext::<S>::from_piop_domain_size(overhead).is_err());
+
+        let rng = &mut ark_std::test_rng();
+        let pcs_params = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng).pcs_params;
+        let ring_setup = RingSetup::<S>::from_piop_domain_size(piop_dom, pcs_params.clone());
+        assert_eq!(ring_setup.unwrap().piop_domain_size(), piop_dom);
+        assert!(matches!(
+            RingSetup::<S>::from_piop_domain_size(2 * piop_dom, pcs_params),
+            Err(Error::SrsTooShort { .. })
+        ));
+
         // Edge case: ring_size = 0 (degenerate but shouldn't panic)
         let piop_zero = piop_domain_size::<S>(0);
         assert!(piop_zero.is_power_of_two());
         assert_eq!(piop_zero, overhead.next_power_of_two());
     }
 
+    pub fn prepared_verifier<S: RingSuite>() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let mut pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        let prover_idx = 3;
+        pks[prover_idx] = secret.public().0;
+
+        let ring_ctx = ring_setup.ring_context();
+        let prover = ring_ctx.ring_prover(ring_setup.prover_key(&pks).unwrap(), prover_idx);
+        let verifier_key = ring_setup.verifier_key(&pks).unwrap();
+        let verifier = PreparedRingVerifier::new(ring_ctx, verifier_key);
+
+        for i in 0..2 {
+            let item = BatchItem::<S>::new(&secret, &prover, rng);
+            assert!(verifier.verify(item.io, &item.ad, &item.proof).is_ok());
+            assert!(Public::verify(item.io, &item.ad, &item.proof, verifier.verifier()).is_ok());
+            let ad = [item.ad.as_slice(), &[i]].concat();
+            assert!(verifier.verify(item.io, &ad, &item.proof).is_err());
+        }
+    }
+
+    pub fn ring_state<S: RingSuite>() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng);
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+        let keys: Vec<_> = (1..=5u8)
+            .map(|i| Secret::<S>::from_seed([i; 32]).public())
+            .collect();
+
+        let mut state = RingState::new(ring_setup, 0, &keys).unwrap();
+        assert_eq!(state.index_of(&keys[3]), Some(3));
+        assert!(state.prover(&public).is_err());
+
+        state.apply(1, &keys[1..3], &[public]).unwrap();
+        assert_eq!(state.epoch(), 1);
+        assert_eq!(state.keys().len(), 4);
+        assert_eq!(state.index_of(&keys[1]), None);
+        assert_eq!(state.index_of(&keys[3]), Some(1));
+        assert_eq!(state.index_of(&public), Some(3));
+
+        // Invalid diffs leave the state unchanged.
+        let ring_id = state.ring_id();
+        assert!(state.apply(1, &[], &[]).is_err());
+        assert!(state.apply(2, &keys[1..2], &[]).is_err());
+        assert!(state.apply(2, &[], &[public]).is_err());
+        assert!(state.apply(2, &[keys[0], keys[0]], &[]).is_err());
+        assert_eq!(state.epoch(), 1);
+        let vk = state.setup().verifier_key(state.keys()).unwrap();
+        assert_eq!(RingId::new::<S>(&vk.commitment()), ring_id);
+        let initial: Vec<_> = keys.iter().map(|pk| pk.0).collect();
+        let vk = state.setup().verifier_key(&initial).unwrap();
+        assert_ne!(RingId::new::<S>(&vk.commitment()), ring_id);
+
+        let prover = state.prover(&public).unwrap();
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar", &prover).unwrap();
+        assert!(Public::verify(io, b"bar", &proof, &state.verifier()).is_ok());
+    }
+
+    pub fn key_registry<S: RingSuite>() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng);
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+        let keys: Vec<_> = (1..=5u8)
+            .map(|i| Secret::<S>::from_seed([i; 32]).public())
+            .collect();
+
+        let mut registry = KeyRegistry::new(ring_setup);
+        for (i, pk) in keys.iter().enumerate() {
+            assert_eq!(registry.insert(*pk), Ok(i));
+        }
+        assert_eq!(registry.insert(keys[0]), Err(Error::InvalidData));
+        assert!(registry.snapshot_at(10).is_none());
+        let ring_id = registry.commit(10).unwrap().ring_id();
+
+        // Epoch 20: `keys[1]` revoked, `public` inserted.
+        registry.revoke(&keys[1]).unwrap();
+        assert_eq!(registry.revoke(&keys[1]), Err(Error::InvalidData));
+        registry.insert(public).unwrap();
+        assert_eq!(registry.index_of(&keys[2]), Some(1));
+        assert!(registry.commit(10).is_err());
+        registry.commit(20).unwrap();
+
+        // Historical lookups.
+        assert!(registry.snapshot_at(9).is_none());
+        assert_eq!(registry.index_at(15, &keys[1]), Some(1));
+        assert_eq!(registry.index_at(15, &keys[2]), Some(2));
+        assert_eq!(registry.index_at(15, &public), None);
+        assert_eq!(registry.index_at(25, &keys[1]), None);
+        assert_eq!(registry.index_at(25, &keys[2]), Some(1));
+        assert_eq!(registry.index_at(20, &public), Some(4));
+        let commitment = registry.commitment_at(10).unwrap();
+        assert_eq!(RingId::new::<S>(&commitment), ring_id);
+        assert_ne!(registry.snapshot_at(20).unwrap().ring_id(), ring_id);
+
+        // Proof against the epoch 20 ring, verified after a later update.
+        let prover = registry.prover_at(20, &public).unwrap();
+        assert!(registry.prover_at(10, &public).is_err());
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar", &prover).unwrap();
+        registry.revoke(&public).unwrap();
+        registry.commit(30).unwrap();
+        let verify =
+            |epoch| Public::verify(io, b"bar", &proof, &registry.verifier_at(epoch).unwrap());
+        assert!(verify(20).is_ok());
+        assert!(verify(10).is_err());
+        assert!(verify(30).is_err());
+
+        registry.prune(25);
+        assert_eq!(registry.snapshots().len(), 2);
+        assert!(registry.snapshot_at(15).is_none());
+        assert_eq!(registry.index_at(25, &public), Some(4));
+    }
+
+    pub fn tickets<S: RingSuite>() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let mut pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        let prover_idx = 3;
+        pks[prover_idx] = secret.public().0;
+
+        let ring_ctx = ring_setup.ring_context();
+        let prover = ring_ctx.ring_prover(ring_setup.prover_key(&pks).unwrap(), prover_idx);
+        let verifier = ring_ctx.ring_verifier(ring_setup.verifier_key(&pks).unwrap());
+
+        let ctx = TicketContext::jam(b"entropy");
+        let (id, ticket) = Ticket::prove(&secret, &ctx, 1, &prover).unwrap();
+        assert_eq!(id, Ticket::id_for(&secret, &ctx, 1));
+        assert_eq!(ticket.verify(&ctx, &[0xff; 32], &verifier), Ok(id));
+
+        // Identifier above threshold.
+        assert_eq!(
+            ticket.verify(&ctx, &id, &verifier),
+            Err(Error::VerificationFailure)
+        );
+        // Different attempt or randomness.
+        let mut other = ticket.clone();
+        other.attempt = 0;
+        assert!(other.verify(&ctx, &[0xff; 32], &verifier).is_err());
+        let other_ctx = TicketContext::jam(b"other entropy");
+        assert!(ticket.verify(&other_ctx, &[0xff; 32], &verifier).is_err());
+    }
+
+    pub fn split_params<S: RingSuite>() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let mut pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        let prover_idx = 3;
+        pks[prover_idx] = secret.public().0;
+
+        // Longer SRS shared by reference.
+        let larger_ring_size = ring_setup.max_ring_size() + 1;
+        let pcs_params = RingSetup::<S>::from_rand(larger_ring_size, rng).pcs_params;
+        let prover_params = ProverParams::<S>::new(TEST_RING_SIZE, &pcs_params).unwrap();
+        let prover_key = prover_params.prover_key(&pks).unwrap();
+        let prover = prover_params
+            .ring_context()
+            .ring_prover(prover_key, prover_idx);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar", &prover).unwrap();
+
+        let commitment = prover_params.verifier_key(&pks).unwrap().commitment();
+        let verifier_params = prover_params.verifier_params();
+        let verifier = verifier_params.ring_verifier(commitment.clone());
+        assert!(Public::verify(io, b"bar", &proof, &verifier).is_ok());
+
+        // Streamed out of the encoded SRS.
+        use ark_serialize::Compress;
+        for compress in [Compress::No, Compress::Yes] {
+            let mut buf = Vec::new();
+            pcs_params.serialize_with_mode(&mut buf, compress).unwrap();
+            let params = VerifierParams::<S>::from_pcs_params_reader(
+                TEST_RING_SIZE,
+                &buf[..],
+                compress,
+                ark_serialize::Validate::Yes,
+            )
+            .unwrap();
+            assert_eq!(
+                params.kzg_verifier_key(),
+                verifier_params.kzg_verifier_key()
+            );
+        }
+
+        // Serialization round trip.
+        let mut buf = Vec::new();
+        verifier_params.serialize_compressed(&mut buf).unwrap();
+        let params = VerifierParams::<S>::deserialize_compressed(&mut &buf[..]).unwrap();
+        let verifier = params.ring_verifier(commitment);
+        assert!(Public::verify(io, b"bar", &proof, &verifier).is_ok());
+
+        // Same keys as the owned setup.
+        let vk = ring_setup.prover_params().verifier_key(&pks).unwrap();
+        assert_eq!(
+            RingId::new::<S>(&vk.commitment()),
+            RingId::new::<S>(&ring_setup.verifier_key(&pks).unwrap().commitment())
+        );
+
+        let short = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng).pcs_params;
+        assert!(matches!(
+            ProverParams::<S>::new(larger_ring_size, &short),
+            Err(Error::SrsTooShort { .. })
+        ));
+    }
+
+    pub fn context_binding<S: RingSuite>() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let mut pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        let prover_idx = 3;
+        pks[prover_idx] = secret.public().0;
+
+        let prover_key = ring_setup.prover_key(&pks).unwrap();
+        let verifier_key = ring_setup.verifier_key(&pks).unwrap();
+        let prover = ring_setup.ring_prover_with_context(prover_key.clone(), prover_idx, b"app");
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar", &prover).unwrap();
+
+        let verifier = ring_setup.ring_verifier_with_context(verifier_key.clone(), b"app");
+        assert!(verifier.verify(io, b"bar", &proof).is_ok());
+
+        // Other or no context.
+        let other = ring_setup.ring_verifier_with_context(verifier_key.clone(), b"other");
+        assert!(other.verify(io, b"bar", &proof).is_err());
+        let empty = ring_setup.ring_verifier_with_context(verifier_key.clone(), b"");
+        assert!(empty.verify(io, b"bar", &proof).is_err());
+        let plain = ring_setup.ring_verifier(verifier_key.clone());
+        assert!(Public::verify(io, b"bar", &proof, &plain).is_err());
+
+        // Ring proof detached from its Pedersen proof and checked against
+        // the same key commitment for another I/O or ad.
+        let key_commitment = proof.pedersen_proof.key_commitment().into_te().unwrap();
+        let ring_verify = |ios: &[VrfIo<S>], ad: &[u8]| {
+            verifier
+                .ring_verifier(ios, ad, &proof.pedersen_proof)
+                .verify(proof.ring_proof.clone(), key_commitment)
+        };
+        let other_io = secret.vrf_io(Input::new(b"baz").unwrap());
+        assert!(ring_verify(&[io], b"bar"));
+        assert!(!ring_verify(&[other_io], b"bar"));
+        assert!(!ring_verify(&[io, other_io], b"bar"));
+        assert!(!ring_verify(&[io], b"qux"));
+
+        let mut batch = BatchVerifier::new_bound(verifier.clone());
+        batch.push(io, b"bar", &proof).unwrap();
+        assert!(batch.verify().is_ok());
+        batch.push(io, b"qux", &proof).unwrap();
+        assert!(batch.verify().is_err());
+    }
+
     #[macro_export]
     macro_rules! ring_suite_tests {
         ($suite:ty) => {
@@ -1167,6 +2408,11 @@ pub(crate) mod testing {
                     $crate::ring::testing::prove_verify_multi::<$suite>()
                 }
 
+                #[test]
+                fn prove_preconditions() {
+                    $crate::ring::testing::prove_preconditions::<$suite>()
+                }
+
                 #[test]
                 fn prove_verify_batch() {
                     $crate::ring::testing::prove_verify_batch::<$suite>()
@@ -1192,176 +2438,43 @@ pub(crate) mod testing {
                     $crate::ring::testing::domain_size_conversions::<$suite>()
                 }
 
-                $crate::test_vectors!($crate::ring::testing::TestVector<$suite>);
-            }
-        };
-    }
-
-    pub trait RingSuiteExt: RingSuite + crate::testing::SuiteExt {
-        const SRS_FILE: &str;
-
-        fn ring_setup() -> &'static RingSetup<Self>;
-
-        #[allow(unused)]
-        fn load_ring_setup() -> RingSetup<Self> {
-            use ark_serialize::CanonicalDeserialize;
-            use std::{fs::File, io::Read};
-            let mut file = File::open(Self::SRS_FILE).unwrap();
-            let mut buf = Vec::new();
-            file.read_to_end(&mut buf).unwrap();
-            let pcs_params =
-                PcsParams::<Self>::deserialize_uncompressed_unchecked(&mut &buf[..]).unwrap();
-            RingSetup::from_pcs_params(crate::ring::testing::TEST_RING_SIZE, pcs_params).unwrap()
-        }
-
-        #[allow(unused)]
-        fn write_ring_setup(ring_setup: &RingSetup<Self>) {
-            use ark_serialize::CanonicalSerialize;
-            use std::{fs::File, io::Write};
-            let mut file = File::create(Self::SRS_FILE).unwrap();
-            let mut buf = Vec::new();
-            ring_setup
-                .pcs_params
-                .serialize_uncompressed(&mut buf)
-                .unwrap();
-            file.write_all(&buf).unwrap();
-        }
-    }
-
-    pub struct TestVector<S: RingSuite> {
-        pub pedersen: pedersen::testing::TestVector<S>,
-        pub ring_pks: [AffinePoint<S>; TEST_RING_SIZE],
-        pub ring_pks_com: RingCommitment<S>,
-        pub ring_proof: RingBareProof<S>,
-    }
-
-    impl<S: RingSuite> core::fmt::Debug for TestVector<S> {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            f.debug_struct("TestVector")
-                .field("pedersen", &self.pedersen)
-                .field("ring_proof", &"...")
-                .finish()
-        }
-    }
-
-    impl<S> common::TestVectorTrait for TestVector<S>
-    where
-        S: RingSuiteExt + std::fmt::Debug + 'static,
-    {
-        fn name() -> String {
-            S::SUITE_NAME.to_string() + "_ring"
-        }
-
-        fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
-            use super::Prover;
-            let pedersen = pedersen::testing::TestVector::new(comment, seed, alpha, ad);
-
-            let secret = Secret::<S>::from_scalar(pedersen.base.sk);
-            let public = secret.public();
-
-            let io = VrfIo {
-                input: Input::<S>::from_affine_unchecked(pedersen.base.h),
-                output: Output::from_affine_unchecked(pedersen.base.gamma),
-            };
-
-            let ring_setup = <S as RingSuiteExt>::ring_setup();
-
-            use ark_std::rand::SeedableRng;
-            let rng = &mut ark_std::rand::rngs::StdRng::from_seed([42; 32]);
-            let prover_idx = 3;
-            let mut ring_pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
-            ring_pks[prover_idx] = public.0;
-
-            let ring_ctx = ring_setup.ring_context();
-            let prover_key = ring_setup.prover_key(&ring_pks).unwrap();
-            let prover = ring_ctx.ring_prover(prover_key, prover_idx);
-            let proof = secret.prove(io, ad, &prover);
-
-            let verifier_key = ring_setup.verifier_key(&ring_pks).unwrap();
-            let ring_pks_com = verifier_key.commitment();
-
-            {
-                // Just in case...
-                let mut p = (Vec::new(), Vec::new());
-                pedersen.proof.serialize_compressed(&mut p.0).unwrap();
-                proof.pedersen_proof.serialize_compressed(&mut p.1).unwrap();
-                assert_eq!(p.0, p.1);
-            }
-
-            Self {
-                pedersen,
-                ring_pks: ring_pks.try_into().unwrap(),
-                ring_pks_com,
-                ring_proof: proof.ring_proof,
-            }
-        }
-
-        fn from_map(map: &common::TestVectorMap) -> Self {
-            let pedersen = pedersen::testing::TestVector::from_map(map);
-
-            let ring_pks = map.get::<[AffinePoint<S>; TEST_RING_SIZE]>("ring_pks");
-            let ring_pks_com = map.get::<RingCommitment<S>>("ring_pks_com");
-            let ring_proof = map.get::<RingBareProof<S>>("ring_proof");
-
-            Self {
-                pedersen,
-                ring_pks,
-                ring_pks_com,
-                ring_proof,
-            }
-        }
-
-        fn to_map(&self) -> common::TestVectorMap {
-            let mut map = self.pedersen.to_map();
-            map.set("ring_pks", &self.ring_pks);
-            map.set("ring_pks_com", &self.ring_pks_com);
-            map.set("ring_proof", &self.ring_proof);
-            map
-        }
-
-        fn run(&self) {
-            self.pedersen.run();
-
-            let io = VrfIo {
-                input: Input::<S>::from_affine_unchecked(self.pedersen.base.h),
-                output: Output::from_affine_unchecked(self.pedersen.base.gamma),
-            };
-            let secret = Secret::from_scalar(self.pedersen.base.sk);
-            let public = secret.public();
-            assert_eq!(public.0, self.pedersen.base.pk);
+                #[test]
+                fn prepared_verifier() {
+                    $crate::ring::testing::prepared_verifier::<$suite>()
+                }
 
-            let ring_setup = <S as RingSuiteExt>::ring_setup();
+                #[test]
+                fn budgeted_verify() {
+                    $crate::ring::testing::budgeted_verify::<$suite>()
+                }
 
-            let prover_idx = self.ring_pks.iter().position(|&pk| pk == public.0).unwrap();
+                #[test]
+                fn ring_state() {
+                    $crate::ring::testing::ring_state::<$suite>()
+                }
 
-            let ring_ctx = ring_setup.ring_context();
-            let prover_key = ring_setup.prover_key(&self.ring_pks).unwrap();
-            let prover = ring_ctx.ring_prover(prover_key, prover_idx);
+                #[test]
+                fn key_registry() {
+                    $crate::ring::testing::key_registry::<$suite>()
+                }
 
-            let verifier_key = ring_setup.verifier_key(&self.ring_pks).unwrap();
-            let verifier = ring_ctx.ring_verifier(verifier_key);
+                #[test]
+                fn split_params() {
+                    $crate::ring::testing::split_params::<$suite>()
+                }
 
-            let proof = secret.prove(io, &self.pedersen.base.ad, &prover);
+                #[test]
+                fn context_binding() {
+                    $crate::ring::testing::context_binding::<$suite>()
+                }
 
-            {
-                // Check if Pedersen proof matches
-                let mut p = (Vec::new(), Vec::new());
-                self.pedersen.proof.serialize_compressed(&mut p.0).unwrap();
-                proof.pedersen_proof.serialize_compressed(&mut p.1).unwrap();
-                assert_eq!(p.0, p.1);
-            }
+                #[test]
+                fn tickets() {
+                    $crate::ring::testing::tickets::<$suite>()
+                }
 
-            #[cfg(feature = "test-vectors")]
-            {
-                // Verify if the ring-proof matches. This check is performed only when
-                // deterministic proof generation is required for test vectors.
-                let mut p = (Vec::new(), Vec::new());
-                self.ring_proof.serialize_compressed(&mut p.0).unwrap();
-                proof.ring_proof.serialize_compressed(&mut p.1).unwrap();
-                assert_eq!(p.0, p.1);
+                $crate::test_vectors!($crate::ring::vectors::TestVector<$suite>);
             }
-
-            assert!(Public::verify(io, &self.pedersen.base.ad, &proof, &verifier).is_ok());
-        }
+        };
     }
 }
@@ -0,0 +1,143 @@
+//! # Budgeted batch verification
+//!
+//! Ring proofs verification within a budget, for parties that must stop
+//! verifying at a deadline (e.g. block builders filling a block).
+//!
+//! Proofs are verified by decreasing priority (e.g. fee), in batches of a
+//! fixed size. The budget caps the number of verified proofs, and a caller
+//! supplied check, run before each batch, stops the verification (e.g. when
+//! a deadline is reached). The proofs left out of the budget are deferred,
+//! not rejected.
+//!
+//! A failing batch is verified again proof by proof, to tell the invalid
+//! proofs apart: invalid proofs cost about a batch each, so larger batches
+//! are cheaper per proof but make the batch duration less predictable.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::ring::BudgetedVerifier;
+//!
+//! let verifier = BudgetedVerifier::new(ring_ctx, verifier_key).with_max_proofs(1000);
+//! let report = verifier.verify(&pending, || Instant::now() >= deadline);
+//! for index in report.verified {
+//!     block.push(pending[index]);
+//! }
+//! ```
+
+use super::*;
+
+/// Outcome of a budgeted verification, as indices of the input items.
+///
+/// Indices are in verification order, i.e. by decreasing priority.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BudgetReport {
+    /// Valid proofs.
+    pub verified: Vec<usize>,
+    /// Invalid proofs.
+    pub rejected: Vec<usize>,
+    /// Proofs left out of the budget.
+    pub deferred: Vec<usize>,
+}
+
+/// Ring proofs verifier within a budget.
+pub struct BudgetedVerifier<S: RingSuite> {
+    ring_ctx: RingContext<S>,
+    verifier_key: RingVerifierKey<S>,
+    batch_size: usize,
+    max_proofs: usize,
+}
+
+impl<S: RingSuite> BudgetedVerifier<S> {
+    /// Default number of proofs per batch.
+    pub const DEFAULT_BATCH_SIZE: usize = 16;
+
+    /// Construct a verifier for the ring of `verifier_key`, without a limit
+    /// on the number of proofs.
+    pub fn new(ring_ctx: RingContext<S>, verifier_key: RingVerifierKey<S>) -> Self {
+        Self {
+            ring_ctx,
+            verifier_key,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            max_proofs: usize::MAX,
+        }
+    }
+
+    /// Set the number of proofs per batch (at least one).
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the max number of proofs to verify.
+    pub fn with_max_proofs(mut self, max_proofs: usize) -> Self {
+        self.max_proofs = max_proofs;
+        self
+    }
+
+    /// Verify the `(priority, ios, ad, proof)` items by decreasing priority.
+    ///
+    /// Items with the same priority are verified in input order. `expired`
+    /// is called before each batch: once it returns `true`, the remaining
+    /// items are deferred. A batch started is always completed, so the
+    /// verification may run up to a batch past the deadline.
+    pub fn verify<I, A>(
+        &self,
+        items: &[(u64, I, A, &Proof<S>)],
+        mut expired: impl FnMut() -> bool,
+    ) -> BudgetReport
+    where
+        I: AsRef<[VrfIo<S>]>,
+        A: AsRef<[u8]>,
+    {
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        order.sort_by_key(|&i| core::cmp::Reverse(items[i].0));
+        let over_budget = order.split_off(self.max_proofs.min(order.len()));
+
+        let mut report = BudgetReport::default();
+        let mut batches = order.chunks(self.batch_size);
+        for batch in batches.by_ref() {
+            if expired() {
+                report.deferred.extend_from_slice(batch);
+                break;
+            }
+            self.verify_batch(items, batch, &mut report);
+        }
+        report.deferred.extend(batches.flatten());
+        report.deferred.extend(over_budget);
+        report
+    }
+
+    fn verify_batch<I, A>(
+        &self,
+        items: &[(u64, I, A, &Proof<S>)],
+        batch: &[usize],
+        report: &mut BudgetReport,
+    ) where
+        I: AsRef<[VrfIo<S>]>,
+        A: AsRef<[u8]>,
+    {
+        let verifier = || self.ring_ctx.ring_verifier(self.verifier_key.clone());
+        let mut batch_verifier = BatchVerifier::new(verifier());
+        let mut pushed = Vec::with_capacity(batch.len());
+        for &i in batch {
+            let (_, ios, ad, proof) = &items[i];
+            match batch_verifier.push(ios, ad, proof) {
+                Ok(()) => pushed.push(i),
+                Err(_) => report.rejected.push(i),
+            }
+        }
+        if batch_verifier.verify().is_ok() {
+            report.verified.extend(pushed);
+            return;
+        }
+        let verifier = verifier();
+        for i in pushed {
+            let (_, ios, ad, proof) = &items[i];
+            match Public::verify(ios, ad, proof, &verifier) {
+                Ok(()) => report.verified.push(i),
+                Err(_) => report.rejected.push(i),
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! # Key cache
+//!
+//! Disk cache of the ring prover and verifier keys, to avoid rebuilding them
+//! (which takes seconds for large rings) when a node restarts with an
+//! unchanged ring.
+//!
+//! Entries are keyed by the hash of the SRS and of the ring keys, and hold the
+//! uncompressed encoding of the keys. A missing or undecodable entry is
+//! transparently rebuilt and stored again, while failing to store it is not
+//! an error.
+//!
+//! Entries are decoded without checks: the cache directory must only be
+//! writable by trusted parties.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::ring::cache::KeyCache;
+//! use ark_vrf::suites::bandersnatch::*;
+//!
+//! let cache = KeyCache::new(&ring_setup, "/var/cache/node/ring-keys");
+//! let prover_key = cache.prover_key(&ring)?;
+//! let verifier_key = cache.verifier_key(&ring)?;
+//! ```
+
+use super::*;
+use digest::Digest;
+use sha2::Sha256;
+use std::path::{Path, PathBuf};
+
+/// Ring prover and verifier keys disk cache.
+pub struct KeyCache<'a, S: RingSuite> {
+    setup: &'a RingSetup<S>,
+    dir: PathBuf,
+    srs_id: [u8; 32],
+}
+
+impl<'a, S: RingSuite> KeyCache<'a, S> {
+    /// Construct a cache for the keys of `setup` in `dir`.
+    ///
+    /// Hashes the SRS of the setup. The directory is created on first store.
+    pub fn new(setup: &'a RingSetup<S>, dir: impl Into<PathBuf>) -> Self {
+        let srs = utils::common::encode_compressed(&setup.pcs_params);
+        Self {
+            setup,
+            dir: dir.into(),
+            srs_id: Sha256::digest(srs).into(),
+        }
+    }
+
+    /// Cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// SHA-256 of the compressed SRS.
+    pub fn srs_id(&self) -> [u8; 32] {
+        self.srs_id
+    }
+
+    /// Cache key of the ring `pks`.
+    ///
+    /// SHA-256 of the SRS identifier, the ring length and the compressed ring
+    /// keys.
+    pub fn ring_hash(&self, pks: &[AffinePoint<S>]) -> [u8; 32] {
+        let mut h = Sha256::new();
+        h.update(self.srs_id);
+        h.update((pks.len() as u64).to_le_bytes());
+        for pk in pks {
+            h.update(utils::common::encode_compressed(pk));
+        }
+        h.finalize().into()
+    }
+
+    /// Prover key for the ring `pks`.
+    ///
+    /// Returns `Error::RingTooLarge` if `pks` exceeds the max ring size.
+    pub fn prover_key(&self, pks: &[AffinePoint<S>]) -> Result<RingProverKey<S>, Error> {
+        let path = self.path(pks, "pk");
+        match Self::load(&path) {
+            Some(prover_key) => Ok(prover_key),
+            None => self.build(pks).map(|keys| keys.0),
+        }
+    }
+
+    /// Verifier key for the ring `pks`.
+    ///
+    /// Returns `Error::RingTooLarge` if `pks` exceeds the max ring size.
+    pub fn verifier_key(&self, pks: &[AffinePoint<S>]) -> Result<RingVerifierKey<S>, Error> {
+        let path = self.path(pks, "vk");
+        match Self::load(&path) {
+            Some(verifier_key) => Ok(verifier_key),
+            None => self.build(pks).map(|keys| keys.1),
+        }
+    }
+
+    /// Remove the entries of the ring `pks`.
+    pub fn evict(&self, pks: &[AffinePoint<S>]) {
+        for ext in ["pk", "vk"] {
+            let _ = std::fs::remove_file(self.path(pks, ext));
+        }
+    }
+
+    fn path(&self, pks: &[AffinePoint<S>], ext: &str) -> PathBuf {
+        let name: String = self
+            .ring_hash(pks)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        self.dir.join(name).with_extension(ext)
+    }
+
+    fn load<T: CanonicalDeserialize>(path: &Path) -> Option<T> {
+        let bytes = std::fs::read(path).ok()?;
+        let mut reader = &bytes[..];
+        let value = T::deserialize_uncompressed_unchecked(&mut reader).ok()?;
+        reader.is_empty().then_some(value)
+    }
+
+    fn store<T: CanonicalSerialize>(&self, path: &Path, value: &T) -> std::io::Result<()> {
+        let mut bytes = Vec::with_capacity(value.uncompressed_size());
+        value
+            .serialize_uncompressed(&mut bytes)
+            .map_err(std::io::Error::other)?;
+        std::fs::create_dir_all(&self.dir)?;
+        // Write then rename, so that readers never see partial entries.
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        std::fs::write(&tmp, bytes)?;
+        std::fs::rename(tmp, path)
+    }
+
+    fn build(
+        &self,
+        pks: &[AffinePoint<S>],
+    ) -> Result<(RingProverKey<S>, RingVerifierKey<S>), Error> {
+        let keys = ring_index::<S>(&self.setup.pcs_params, &self.setup.piop_params, pks)?;
+        let _ = self.store(&self.path(pks, "pk"), &keys.0);
+        let _ = self.store(&self.path(pks, "vk"), &keys.1);
+        Ok(keys)
+    }
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+    use crate::testing::random_vec;
+
+    #[test]
+    fn key_cache() {
+        let rng = &mut ark_std::test_rng();
+        let ring_setup = RingSetup::<S>::from_rand(8, rng);
+        let pks = random_vec::<AffinePoint<S>>(8, Some(rng));
+        let dir = std::env::temp_dir().join(format!("ark-vrf-key-cache-{}", std::process::id()));
+        let cache = KeyCache::new(&ring_setup, &dir);
+
+        let commitment = ring_setup.verifier_key(&pks).unwrap().commitment();
+        let ring_id = RingId::new::<S>(&commitment);
+
+        // Miss, then hit.
+        for _ in 0..2 {
+            let vk = cache.verifier_key(&pks).unwrap();
+            assert_eq!(RingId::new::<S>(&vk.commitment()), ring_id);
+            assert!(cache.prover_key(&pks).is_ok());
+            assert!(cache.path(&pks, "vk").exists());
+        }
+
+        // Corrupted entry is rebuilt.
+        std::fs::write(cache.path(&pks, "vk"), b"garbage").unwrap();
+        let vk = cache.verifier_key(&pks).unwrap();
+        assert_eq!(RingId::new::<S>(&vk.commitment()), ring_id);
+
+        assert_ne!(cache.ring_hash(&pks), cache.ring_hash(&pks[1..]));
+        cache.evict(&pks);
+        assert!(!cache.path(&pks, "pk").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
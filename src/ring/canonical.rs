@@ -0,0 +1,156 @@
+//! # Canonical rings
+//!
+//! Canonical ordering of ring key lists, so that nodes independently
+//! collecting the same keys (in any order, possibly with repetitions) build
+//! the same ring and thus commit to the same polynomials.
+//!
+//! The canonical ring is the set of the distinct input keys sorted by their
+//! compressed encoding. Occurrences of the [padding point](RingSuite::PADDING)
+//! are dropped, as the ring proof backend already pads the rings with it: a
+//! padding point in the middle of a ring would otherwise change the
+//! commitment. Index maps relate the input positions to the canonical ones.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::ring::CanonicalRing;
+//!
+//! let ring = CanonicalRing::<S>::new(&collected_keys);
+//! let verifier_key = ring_setup.verifier_key(ring.keys())?;
+//! let prover_index = ring.index_of(&public).unwrap();
+//! ```
+
+use super::*;
+use ark_std::collections::BTreeMap;
+
+/// Canonical ring key list.
+#[derive(Clone)]
+pub struct CanonicalRing<S: RingSuite> {
+    keys: Vec<AffinePoint<S>>,
+    indices: BTreeMap<Public<S>, usize>,
+    positions: Vec<Option<usize>>,
+    padding: Vec<usize>,
+    duplicates: Vec<usize>,
+}
+
+impl<S: RingSuite> CanonicalRing<S> {
+    /// Canonicalize `pks`: sort by compressed encoding, remove duplicates and
+    /// padding points.
+    pub fn new(pks: &[AffinePoint<S>]) -> Self {
+        let mut sorted: Vec<Public<S>> = pks
+            .iter()
+            .filter(|pk| **pk != S::PADDING)
+            .map(|pk| Public(*pk))
+            .collect();
+        sorted.sort();
+        sorted.dedup();
+        let keys: Vec<_> = sorted.iter().map(|pk| pk.0).collect();
+        let indices: BTreeMap<_, _> = sorted.into_iter().zip(0..).collect();
+
+        let mut seen = ark_std::vec![false; keys.len()];
+        let (mut padding, mut duplicates) = (Vec::new(), Vec::new());
+        let positions = pks
+            .iter()
+            .enumerate()
+            .map(|(pos, pk)| {
+                if *pk == S::PADDING {
+                    padding.push(pos);
+                    return None;
+                }
+                let index = indices[&Public(*pk)];
+                if core::mem::replace(&mut seen[index], true) {
+                    duplicates.push(pos);
+                }
+                Some(index)
+            })
+            .collect();
+        Self {
+            keys,
+            indices,
+            positions,
+            padding,
+            duplicates,
+        }
+    }
+
+    /// Returns `true` if `pks` is already canonical: strictly increasing
+    /// encodings, without padding points.
+    pub fn is_canonical(pks: &[AffinePoint<S>]) -> bool {
+        pks.iter().all(|pk| *pk != S::PADDING)
+            && pks.windows(2).all(|w| Public::<S>(w[0]) < Public(w[1]))
+    }
+
+    /// Canonical ring keys.
+    pub fn keys(&self) -> &[AffinePoint<S>] {
+        &self.keys
+    }
+
+    /// Consume the ring, returning its keys.
+    pub fn into_keys(self) -> Vec<AffinePoint<S>> {
+        self.keys
+    }
+
+    /// Canonical index of `public`, if in the ring.
+    pub fn index_of(&self, public: &Public<S>) -> Option<usize> {
+        self.indices.get(public).copied()
+    }
+
+    /// Canonical index of the key at `pos` in the input list.
+    ///
+    /// Duplicated keys map to the same index. Returns `None` if `pos` is out
+    /// of range or holds the padding point.
+    pub fn canonical_index(&self, pos: usize) -> Option<usize> {
+        self.positions.get(pos).copied().flatten()
+    }
+
+    /// Canonical index of each key of the input list, in input order.
+    pub fn positions(&self) -> &[Option<usize>] {
+        &self.positions
+    }
+
+    /// Input positions holding the padding point.
+    pub fn padding_positions(&self) -> &[usize] {
+        &self.padding
+    }
+
+    /// Input positions holding a repetition of a previous key.
+    pub fn duplicate_positions(&self) -> &[usize] {
+        &self.duplicates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+    use crate::testing::random_vec;
+
+    #[test]
+    fn canonical_ring() {
+        let pks = random_vec::<AffinePoint<S>>(5, None);
+        let padding = RingSetup::<S>::padding_point();
+        let input = [pks[3], pks[0], padding, pks[4], pks[1], pks[3], pks[2]];
+
+        let ring = CanonicalRing::<S>::new(&input);
+        assert_eq!(ring.keys().len(), 5);
+        assert!(CanonicalRing::<S>::is_canonical(ring.keys()));
+        assert!(!CanonicalRing::<S>::is_canonical(&input));
+        assert_eq!(ring.padding_positions(), [2]);
+        assert_eq!(ring.duplicate_positions(), [5]);
+        assert_eq!(ring.canonical_index(2), None);
+        assert_eq!(ring.canonical_index(7), None);
+        assert_eq!(ring.canonical_index(0), ring.canonical_index(5));
+        for (pos, index) in ring.positions().iter().enumerate() {
+            if let Some(index) = index {
+                assert_eq!(ring.keys()[*index], input[pos]);
+                assert_eq!(ring.index_of(&Public(input[pos])), Some(*index));
+            }
+        }
+
+        // Same keys, different order and repetitions.
+        let other = CanonicalRing::<S>::new(&[pks[2], pks[1], pks[4], pks[0], pks[3], pks[1]]);
+        assert_eq!(other.keys(), ring.keys());
+        assert!(other.padding_positions().is_empty());
+        assert_eq!(other.into_keys(), ring.into_keys());
+    }
+}
@@ -0,0 +1,282 @@
+//! # SRS ceremony
+//!
+//! Sequential powers of tau ceremony producing the [`PcsParams`], for
+//! deployments generating their own SRS instead of using an existing one (see
+//! `srs`) or [`RingSetup::from_seed`], whose secret is known to anyone
+//! knowing the seed.
+//!
+//! Participants contribute in turn: each one receives the current
+//! [`Ceremony`] state, checks it with [`Ceremony::verify`], mixes its own
+//! secret into the parameters with [`Ceremony::contribute`] and passes the
+//! new state on. The resulting SRS is secure as long as at least one
+//! participant contributed unpredictable entropy and discarded it.
+//!
+//! Every contribution is recorded in the ceremony transcript together with a
+//! hash chaining it to the previous ones. Participants publish their
+//! contribution hash, so that anyone can check that it is part of the
+//! transcript of the final state and audit the whole ceremony.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::ring::Ceremony;
+//! use ark_vrf::suites::bandersnatch::*;
+//!
+//! // Coordinator
+//! let mut ceremony = Ceremony::<BandersnatchSha512Ell2>::new(RING_SIZE);
+//!
+//! // Each participant, in turn
+//! ceremony.verify()?;
+//! let hash = ceremony.contribute(&entropy).hash;
+//!
+//! // Coordinator
+//! ceremony.verify_contribution(&previous)?;
+//! let setup: RingSetup = ceremony.finalize()?;
+//! ```
+
+use super::*;
+use ark_ec::VariableBaseMSM;
+use ark_std::{One, UniformRand};
+use utils::common::DomSep;
+
+type G1<S> = <<S as RingSuite>::Pairing as Pairing>::G1;
+
+/// Ceremony contribution record.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Contribution<S: RingSuite> {
+    /// First G1 power of tau after the contribution.
+    pub tau_g1: G1Affine<S>,
+    /// Contributor secret in G2.
+    pub tau_g2: G2Affine<S>,
+    /// Contribution hash, chained to the previous contribution one.
+    pub hash: [u8; 32],
+}
+
+/// Powers of tau contribution ceremony state.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Ceremony<S: RingSuite> {
+    /// Current parameters.
+    pub params: PcsParams<S>,
+    /// Contributions transcript, in order.
+    pub contributions: Vec<Contribution<S>>,
+}
+
+/// Hash of a contribution following the one with `prev` hash.
+fn contribution_hash<S: RingSuite>(
+    prev: &[u8; 32],
+    powers: usize,
+    tau_g1: &G1Affine<S>,
+    tau_g2: &G2Affine<S>,
+) -> [u8; 32] {
+    let mut t = S::Transcript::new(S::SUITE_ID);
+    t.absorb_raw(&[DomSep::SrsContribution as u8]);
+    t.absorb_raw(prev);
+    t.absorb_raw(&(powers as u64).to_le_bytes());
+    t.absorb_serialize(tau_g1);
+    t.absorb_serialize(tau_g2);
+    let mut hash = [0; 32];
+    t.squeeze_raw(&mut hash);
+    hash
+}
+
+impl<S: RingSuite> Ceremony<S> {
+    /// Start a ceremony for the given ring size.
+    ///
+    /// The initial parameters have `tau = 1` (all the powers are the groups
+    /// generators), and are thus insecure until the first contribution.
+    pub fn new(ring_size: usize) -> Self {
+        let powers = pcs_domain_size::<S>(ring_size);
+        let params = PcsParams::<S> {
+            powers_in_g1: ark_std::vec![G1Affine::<S>::generator(); powers],
+            powers_in_g2: ark_std::vec![G2Affine::<S>::generator(); 2],
+        };
+        Self {
+            params,
+            contributions: Vec::new(),
+        }
+    }
+
+    /// Hash of the last contribution, zero before the first one.
+    fn last_hash(&self) -> [u8; 32] {
+        self.contributions
+            .last()
+            .map(|c| c.hash)
+            .unwrap_or_default()
+    }
+
+    /// Mix a secret derived from `entropy` into the parameters.
+    ///
+    /// The contributor secret is hashed from `entropy` and the previous
+    /// contribution hash, and is erased once the parameters are updated.
+    /// `entropy` should be at least 32 bytes from a secure RNG, to be
+    /// discarded after the call.
+    ///
+    /// Returns the recorded contribution, whose hash identifies it in the
+    /// transcript.
+    pub fn contribute(&mut self, entropy: &[u8]) -> &Contribution<S> {
+        let mut t = S::Transcript::new(S::SUITE_ID);
+        t.absorb_raw(&[DomSep::SrsTau as u8]);
+        t.absorb_raw(&self.last_hash());
+        t.absorb_raw(entropy);
+        let mut rng = t.to_rng();
+        let mut tau = loop {
+            let tau = BaseField::<S>::rand(&mut rng);
+            if !tau.is_zero() {
+                break tau;
+            }
+        };
+
+        let mut power = BaseField::<S>::one();
+        let powers: Vec<G1<S>> = self
+            .params
+            .powers_in_g1
+            .iter()
+            .map(|p| {
+                let p = *p * power;
+                power *= tau;
+                p
+            })
+            .collect();
+        self.params.powers_in_g1 = G1::<S>::normalize_batch(&powers);
+        self.params.powers_in_g2[1] = (self.params.powers_in_g2[1] * tau).into_affine();
+        let tau_g2 = (G2Affine::<S>::generator() * tau).into_affine();
+        tau.zeroize();
+        power.zeroize();
+
+        let tau_g1 = self.params.powers_in_g1[1];
+        let powers = self.params.powers_in_g1.len();
+        let hash = contribution_hash::<S>(&self.last_hash(), powers, &tau_g1, &tau_g2);
+        self.contributions.push(Contribution {
+            tau_g1,
+            tau_g2,
+            hash,
+        });
+        &self.contributions[self.contributions.len() - 1]
+    }
+
+    /// Check the parameters against the whole contributions transcript.
+    ///
+    /// Checks that:
+    /// - each contribution multiplies the previous tau by the contributor
+    ///   secret, and its hash chains it to the previous contribution;
+    /// - the parameters are the powers of the tau of the last contribution,
+    ///   starting from the groups generators.
+    ///
+    /// Returns `Error::VerificationFailure` otherwise.
+    pub fn verify(&self) -> Result<(), Error> {
+        let (g1, g2) = (G1Affine::<S>::generator(), G2Affine::<S>::generator());
+        let (g1s, g2s) = (&self.params.powers_in_g1, &self.params.powers_in_g2);
+        if g1s.len() < 2 || g2s.len() != 2 || g1s[0] != g1 || g2s[0] != g2 {
+            return Err(Error::VerificationFailure);
+        }
+
+        let (mut prev_hash, mut prev_g1) = ([0; 32], g1);
+        for c in &self.contributions {
+            let ok = !c.tau_g2.is_zero()
+                && S::Pairing::pairing(c.tau_g1, g2) == S::Pairing::pairing(prev_g1, c.tau_g2)
+                && c.hash == contribution_hash::<S>(&prev_hash, g1s.len(), &c.tau_g1, &c.tau_g2);
+            if !ok {
+                return Err(Error::VerificationFailure);
+            }
+            (prev_hash, prev_g1) = (c.hash, c.tau_g1);
+        }
+        if g1s[1] != prev_g1 {
+            return Err(Error::VerificationFailure);
+        }
+
+        // Random linear combination of the `e(g1s[i + 1], g2) == e(g1s[i], g2s[1])`
+        // checks, with weights bound to the parameters.
+        let mut t = S::Transcript::new(S::SUITE_ID);
+        t.absorb_raw(&[DomSep::SrsCheck as u8]);
+        t.absorb_serialize(&self.params);
+        let mut rng = t.to_rng();
+        let n = g1s.len() - 1;
+        let weights: Vec<BaseField<S>> = (0..n).map(|_| BaseField::<S>::rand(&mut rng)).collect();
+        let lhs = G1::<S>::msm_unchecked(&g1s[1..], &weights);
+        let rhs = G1::<S>::msm_unchecked(&g1s[..n], &weights);
+        (S::Pairing::pairing(lhs, g2) == S::Pairing::pairing(rhs, g2s[1]))
+            .then_some(())
+            .ok_or(Error::VerificationFailure)
+    }
+
+    /// Check that this state is `prev` plus one valid contribution.
+    ///
+    /// Same as [`Self::verify`], additionally checking that the transcript
+    /// extends the `prev` one by exactly one contribution.
+    pub fn verify_contribution(&self, prev: &Self) -> Result<(), Error> {
+        let n = prev.contributions.len();
+        let extends = self.contributions.len() == n + 1
+            && self.params.powers_in_g1.len() == prev.params.powers_in_g1.len()
+            && (n == 0 || self.contributions[n - 1].hash == prev.last_hash());
+        if !extends {
+            return Err(Error::VerificationFailure);
+        }
+        self.verify()
+    }
+
+    /// Contribution hashes transcript, in order.
+    pub fn transcript(&self) -> Vec<[u8; 32]> {
+        self.contributions.iter().map(|c| c.hash).collect()
+    }
+
+    /// Close the ceremony, returning the ring setup for the max ring size
+    /// supported by the parameters.
+    ///
+    /// Returns `Error::VerificationFailure` if [`Self::verify`] fails or if
+    /// there is no contribution.
+    pub fn finalize(self) -> Result<RingSetup<S>, Error> {
+        if self.contributions.is_empty() {
+            return Err(Error::VerificationFailure);
+        }
+        self.verify()?;
+        let ring_size = max_ring_size_from_pcs_domain_size::<S>(self.params.powers_in_g1.len());
+        RingSetup::from_pcs_params(ring_size, self.params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+    use crate::testing::random_vec;
+
+    #[test]
+    fn ceremony() {
+        let mut ceremony = Ceremony::<S>::new(8);
+        assert!(ceremony.verify().is_ok());
+        assert!(ceremony.clone().finalize().is_err());
+
+        let mut hashes = Vec::new();
+        for entropy in [b"alice", b"bobby"] {
+            let prev = ceremony.clone();
+            hashes.push(ceremony.contribute(entropy).hash);
+            assert!(ceremony.verify_contribution(&prev).is_ok());
+            assert!(prev.verify_contribution(&ceremony).is_err());
+        }
+        assert_eq!(ceremony.transcript(), hashes);
+        assert_ne!(hashes[0], hashes[1]);
+
+        // Serialized state round trip.
+        let mut buf = Vec::new();
+        ceremony.serialize_compressed(&mut buf).unwrap();
+        let decoded = Ceremony::<S>::deserialize_compressed(&mut &buf[..]).unwrap();
+        assert_eq!(decoded.transcript(), hashes);
+
+        // Tampered parameters or transcript.
+        let mut bad = ceremony.clone();
+        bad.params.powers_in_g1.swap(2, 3);
+        assert!(bad.verify().is_err());
+        let mut bad = ceremony.clone();
+        bad.contributions[0].hash[0] ^= 1;
+        assert!(bad.verify().is_err());
+        let mut bad = ceremony.clone();
+        bad.contributions.remove(0);
+        assert!(bad.verify().is_err());
+
+        // The final SRS works with the ring proof.
+        let setup = ceremony.finalize().unwrap();
+        let pks = random_vec::<AffinePoint<S>>(8, None);
+        assert!(setup.prover_key(&pks).is_ok());
+        assert!(setup.verifier_key(&pks).is_ok());
+    }
+}
@@ -0,0 +1,233 @@
+//! # Ring membership proofs
+//!
+//! Succinct proofs that a public key is at a given index of a ring, checked
+//! against the [`RingCommitment`] alone. Light clients holding the ring
+//! commitment can check their own membership without downloading the whole
+//! key set.
+//!
+//! The ring commitment commits to the coordinates of the ring keys as two
+//! columns of the PIOP domain, the key at index `i` being at the `ω^i` row.
+//! A membership proof is a single KZG opening at `ω^i` of the two columns
+//! batched with a challenge bound to the columns commitments, the index and
+//! the key.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::suites::bandersnatch::*;
+//!
+//! // Full node
+//! let proof = ring_setup.membership_proof(&ring, index)?;
+//!
+//! // Light client
+//! let params: VerifierParams = ring_setup.verifier_params();
+//! params.verify_membership(&commitment, &public, &proof)?;
+//! ```
+
+use super::*;
+use ark_ec::VariableBaseMSM;
+use ark_ff::{FftField, Field};
+use ark_std::UniformRand;
+use utils::common::DomSep;
+
+type G1<S> = <<S as RingSuite>::Pairing as Pairing>::G1;
+
+/// Proof that a public key is at a given index of a ring.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MembershipProof<S: RingSuite> {
+    /// Key index in the ring.
+    pub index: usize,
+    /// KZG opening of the batched key coordinates columns.
+    pub opening: G1Affine<S>,
+}
+
+/// Challenge batching the key coordinates columns, given their commitments.
+fn batching_challenge<S: RingSuite>(
+    columns: &[G1Affine<S>; 2],
+    index: usize,
+    key: &TEAffine<CurveConfig<S>>,
+) -> BaseField<S> {
+    let mut t = S::Transcript::new(S::SUITE_ID);
+    t.absorb_raw(&[DomSep::RingMembership as u8]);
+    t.absorb_serialize(columns);
+    t.absorb_raw(&(index as u64).to_le_bytes());
+    t.absorb_serialize(key);
+    BaseField::<S>::rand(&mut t.to_rng())
+}
+
+/// Row of the key at `index` in a PIOP domain of the given size.
+fn index_row<S: RingSuite>(piop_domain_size: usize, index: usize) -> BaseField<S> {
+    BaseField::<S>::get_root_of_unity(piop_domain_size as u64)
+        .expect("PIOP domain size is a supported power of two")
+        .pow([index as u64])
+}
+
+/// Membership proof of the key at `index` of the ring `pks`.
+fn prove_membership<S: RingSuite>(
+    pcs_params: &PcsParams<S>,
+    ring_ctx: &RingContext<S>,
+    pks: &[AffinePoint<S>],
+    index: usize,
+) -> Result<MembershipProof<S>, Error> {
+    let max = ring_ctx.max_ring_size();
+    if pks.len() > max {
+        return Err(Error::RingTooLarge {
+            size: pks.len(),
+            max,
+        });
+    }
+    if index >= pks.len() {
+        return Err(Error::KeyIndexOutOfRange {
+            index,
+            ring_size: pks.len(),
+        });
+    }
+    let te_pks = TEMapping::to_te_slice(pks).ok_or(Error::InvalidData)?;
+    let key = te_pks[index];
+
+    // Same commitments as the ring commitment ones.
+    let columns = ring_ctx.piop_params.points_column(&te_pks);
+    let (xs, ys) = (&columns.xs.poly.coeffs, &columns.ys.poly.coeffs);
+    let powers = &pcs_params.powers_in_g1;
+    let commit = |p: &[BaseField<S>]| G1::<S>::msm_unchecked(&powers[..p.len()], p).into_affine();
+    let gamma = batching_challenge::<S>(&[commit(xs), commit(ys)], index, &key);
+
+    // Quotient of `p(X) - p(z)` by `X - z`, for `p = xs + γ·ys`, by synthetic
+    // division. The remainder `p(z)` is the batched key coordinates.
+    let z = index_row::<S>(ring_ctx.piop_domain_size(), index);
+    let degree = xs.len().max(ys.len()).saturating_sub(1);
+    let coeff = |k: usize| {
+        let zero = BaseField::<S>::zero();
+        *xs.get(k).unwrap_or(&zero) + gamma * ys.get(k).unwrap_or(&zero)
+    };
+    let mut quotient = ark_std::vec![BaseField::<S>::zero(); degree];
+    let mut acc = BaseField::<S>::zero();
+    for k in (1..=degree).rev() {
+        acc = coeff(k) + z * acc;
+        quotient[k - 1] = acc;
+    }
+    debug_assert_eq!(coeff(0) + z * acc, key.x + gamma * key.y);
+
+    Ok(MembershipProof {
+        index,
+        opening: commit(&quotient),
+    })
+}
+
+/// Check `proof` against the ring `commitment`.
+fn verify_membership<S: RingSuite>(
+    kzg_vk: &KzgVerifierKey<S>,
+    ring_ctx: &RingContext<S>,
+    commitment: &RingCommitment<S>,
+    public: &Public<S>,
+    proof: &MembershipProof<S>,
+) -> Result<(), Error> {
+    let index = proof.index;
+    if index >= ring_ctx.max_ring_size() {
+        return Err(Error::KeyIndexOutOfRange {
+            index,
+            ring_size: ring_ctx.max_ring_size(),
+        });
+    }
+    let key = public.0.into_te().ok_or(Error::InvalidData)?;
+    let [cx, cy] = &commitment.points;
+    let gamma = batching_challenge::<S>(&[cx.0, cy.0], index, &key);
+    let z = index_row::<S>(ring_ctx.piop_domain_size(), index);
+
+    // e(C - v·G1 + z·π, G2) == e(π, τ·G2), with `C` and `v` the batched
+    // columns commitment and evaluation.
+    let lhs =
+        cx.0.into_group() + cy.0 * gamma - kzg_vk.g1 * (key.x + gamma * key.y) + proof.opening * z;
+    let ok =
+        S::Pairing::pairing(lhs, kzg_vk.g2) == S::Pairing::pairing(proof.opening, kzg_vk.tau_in_g2);
+    ok.then_some(()).ok_or(Error::VerificationFailure)
+}
+
+impl<S: RingSuite> RingSetup<S> {
+    /// Prove that the key at `index` of the ring `pks` is in the ring
+    /// commitment of `pks`.
+    ///
+    /// Returns `Error::RingTooLarge` if `pks` exceeds the max ring size, or
+    /// `Error::KeyIndexOutOfRange` if `index` is not an index of `pks`.
+    pub fn membership_proof(
+        &self,
+        pks: &[AffinePoint<S>],
+        index: usize,
+    ) -> Result<MembershipProof<S>, Error> {
+        prove_membership(&self.pcs_params, &self.ring_ctx, pks, index)
+    }
+}
+
+impl<S: RingSuite> ProverParams<'_, S> {
+    /// Prove that the key at `index` of the ring `pks` is in the ring
+    /// commitment of `pks`.
+    ///
+    /// Same as [`RingSetup::membership_proof`].
+    pub fn membership_proof(
+        &self,
+        pks: &[AffinePoint<S>],
+        index: usize,
+    ) -> Result<MembershipProof<S>, Error> {
+        prove_membership(self.pcs_params(), self.ring_context(), pks, index)
+    }
+}
+
+impl<S: RingSuite> VerifierParams<S> {
+    /// Check that `public` is in the ring `commitment`, at the proof index.
+    ///
+    /// Returns `Error::KeyIndexOutOfRange` if the proof index exceeds the max
+    /// ring size, or `Error::VerificationFailure` if the proof is invalid.
+    pub fn verify_membership(
+        &self,
+        commitment: &RingCommitment<S>,
+        public: &Public<S>,
+        proof: &MembershipProof<S>,
+    ) -> Result<(), Error> {
+        let (kzg_vk, ring_ctx) = (self.kzg_verifier_key(), self.ring_context());
+        verify_membership(kzg_vk, ring_ctx, commitment, public, proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+    use crate::testing::random_vec;
+
+    #[test]
+    fn membership() {
+        let rng = &mut ark_std::test_rng();
+        let setup = RingSetup::<S>::from_rand(8, rng);
+        let params = setup.verifier_params();
+        let pks = random_vec::<AffinePoint<S>>(6, Some(rng));
+        let commitment = setup.verifier_key(&pks).unwrap().commitment();
+        let verify = |commitment: &RingCommitment<S>, pk, proof: &MembershipProof<S>| {
+            params.verify_membership(commitment, &Public(pk), proof)
+        };
+
+        for (index, pk) in pks.iter().enumerate() {
+            let proof = setup.membership_proof(&pks, index).unwrap();
+            assert!(verify(&commitment, *pk, &proof).is_ok());
+
+            // Wrong key or index.
+            let next = (index + 1) % pks.len();
+            assert!(verify(&commitment, pks[next], &proof).is_err());
+            let mut moved = proof.clone();
+            moved.index = next;
+            assert!(verify(&commitment, *pk, &moved).is_err());
+        }
+
+        // Key not in the ring.
+        let other = random_vec::<AffinePoint<S>>(6, Some(rng));
+        let other_commitment = setup.verifier_key(&other).unwrap().commitment();
+        let proof = setup.prover_params().membership_proof(&pks, 0).unwrap();
+        assert!(verify(&commitment, pks[0], &proof).is_ok());
+        assert!(verify(&other_commitment, pks[0], &proof).is_err());
+
+        let err = Error::KeyIndexOutOfRange {
+            index: 6,
+            ring_size: 6,
+        };
+        assert_eq!(setup.membership_proof(&pks, 6).err(), Some(err));
+    }
+}
@@ -0,0 +1,241 @@
+//! # Prover and verifier parameters
+//!
+//! Split views of a [`RingSetup`] for processes running only one side of the
+//! ring protocol.
+//!
+//! - [`ProverParams`] borrows the PCS parameters, so that several contexts
+//!   (e.g. different ring sizes, or threads holding an `Arc<PcsParams>`) can
+//!   share a single SRS without copying its powers.
+//! - [`VerifierParams`] only holds the KZG verifier key (two `G2` and one `G1`
+//!   points) and the ring context. Verifiers build their keys from ring
+//!   commitments and never need the `pcs_domain_size` powers in `G1`, which
+//!   [`VerifierParams::from_pcs_params_reader`] skips while reading the SRS.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::suites::bandersnatch::*;
+//!
+//! // Prover process
+//! let pcs_params: PcsParams = ZCASH_BLS12_381.load("bls12-381-srs.bin")?;
+//! let params = ProverParams::new(RING_SIZE, &pcs_params)?;
+//! let prover_key = params.prover_key(&ring)?;
+//!
+//! // Verifier process
+//! let file = std::fs::File::open("bls12-381-srs.bin")?;
+//! let params = VerifierParams::from_pcs_params_reader(RING_SIZE, file, Compress::No, Validate::No)?;
+//! let verifier = params.ring_verifier(commitment);
+//! ```
+
+use super::*;
+use ark_serialize::{Compress, Read, SerializationError, Validate};
+
+/// KZG verifier key.
+pub type KzgVerifierKey<S> =
+    ring_proof::pcs::kzg::params::RawKzgVerifierKey<<S as RingSuite>::Pairing>;
+
+/// Prover side ring parameters.
+///
+/// Borrows the PCS parameters instead of owning (a truncated copy of) them as
+/// [`RingSetup`] does. Longer SRS are fine, only the first `pcs_domain_size`
+/// powers are used.
+#[derive(Clone)]
+pub struct ProverParams<'a, S: RingSuite> {
+    pcs_params: &'a PcsParams<S>,
+    ring_ctx: RingContext<S>,
+}
+
+impl<'a, S: RingSuite> ProverParams<'a, S> {
+    /// Construct prover parameters for the given ring size.
+    ///
+    /// Returns `Error::SrsTooShort` if `pcs_params` is insufficient for the
+    /// specified ring size.
+    pub fn new(ring_size: usize, pcs_params: &'a PcsParams<S>) -> Result<Self, Error> {
+        check_pcs_params::<S>(ring_size, pcs_params)?;
+        Ok(Self {
+            pcs_params,
+            ring_ctx: RingContext::new(ring_size),
+        })
+    }
+
+    /// Borrowed PCS parameters.
+    pub fn pcs_params(&self) -> &'a PcsParams<S> {
+        self.pcs_params
+    }
+
+    /// Get a reference to the lightweight [`RingContext`].
+    pub fn ring_context(&self) -> &RingContext<S> {
+        &self.ring_ctx
+    }
+
+    /// Create a prover key for the given ring of public keys.
+    ///
+    /// Returns `Error::RingTooLarge` if `pks` exceeds the max ring size.
+    pub fn prover_key(&self, pks: &[AffinePoint<S>]) -> Result<RingProverKey<S>, Error> {
+        ring_index::<S>(self.pcs_params, &self.ring_ctx.piop_params, pks).map(|keys| keys.0)
+    }
+
+    /// Create a verifier key for the given ring of public keys.
+    ///
+    /// Returns `Error::RingTooLarge` if `pks` exceeds the max ring size.
+    pub fn verifier_key(&self, pks: &[AffinePoint<S>]) -> Result<RingVerifierKey<S>, Error> {
+        ring_index::<S>(self.pcs_params, &self.ring_ctx.piop_params, pks).map(|keys| keys.1)
+    }
+
+    /// Extract the verifier parameters.
+    pub fn verifier_params(&self) -> VerifierParams<S> {
+        use ring_proof::pcs::PcsParams;
+        VerifierParams {
+            kzg_vk: self.pcs_params.raw_vk(),
+            ring_ctx: self.ring_ctx.clone(),
+        }
+    }
+}
+
+/// Verifier side ring parameters.
+///
+/// Serialized as the ring size followed by the KZG verifier key.
+#[derive(Clone)]
+pub struct VerifierParams<S: RingSuite> {
+    kzg_vk: KzgVerifierKey<S>,
+    ring_ctx: RingContext<S>,
+}
+
+impl<S: RingSuite> VerifierParams<S> {
+    /// Construct verifier parameters from a KZG verifier key.
+    pub fn new(ring_size: usize, kzg_vk: KzgVerifierKey<S>) -> Self {
+        Self {
+            kzg_vk,
+            ring_ctx: RingContext::new(ring_size),
+        }
+    }
+
+    /// Read the verifier parameters from an encoded [`PcsParams`].
+    ///
+    /// Only the first power in `G1` and the two first powers in `G2` are
+    /// decoded, the other powers are skipped without being retained.
+    ///
+    /// Returns `Error::SrsTooShort` if the encoded parameters are insufficient
+    /// for the specified ring size, or `Error::InvalidData` on read failure.
+    pub fn from_pcs_params_reader<R: Read>(
+        ring_size: usize,
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        trace_span!("srs_load_verifier_params", ring_size);
+        let pcs_domain_size = pcs_domain_size::<S>(ring_size);
+        let g1_len = G1Affine::<S>::generator().serialized_size(compress);
+        let g2_len = G2Affine::<S>::generator().serialized_size(compress);
+
+        let g1_count = u64::deserialize_with_mode(&mut reader, compress, validate)? as usize;
+        if g1_count < pcs_domain_size {
+            return Err(Error::SrsTooShort {
+                needed: pcs_domain_size,
+                got: g1_count,
+            });
+        }
+        let g1 = G1Affine::<S>::deserialize_with_mode(&mut reader, compress, validate)?;
+        skip(&mut reader, (g1_count - 1).saturating_mul(g1_len))?;
+
+        let g2_count = u64::deserialize_with_mode(&mut reader, compress, validate)? as usize;
+        if g2_count < 2 {
+            return Err(Error::SrsTooShort {
+                needed: 2,
+                got: g2_count,
+            });
+        }
+        let g2 = G2Affine::<S>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let tau_in_g2 = G2Affine::<S>::deserialize_with_mode(&mut reader, compress, validate)?;
+        skip(&mut reader, (g2_count - 2).saturating_mul(g2_len))?;
+
+        let kzg_vk = KzgVerifierKey::<S> { g1, g2, tau_in_g2 };
+        Ok(Self::new(ring_size, kzg_vk))
+    }
+
+    /// KZG verifier key.
+    pub fn kzg_verifier_key(&self) -> &KzgVerifierKey<S> {
+        &self.kzg_vk
+    }
+
+    /// Get a reference to the lightweight [`RingContext`].
+    pub fn ring_context(&self) -> &RingContext<S> {
+        &self.ring_ctx
+    }
+
+    /// Create a verifier key from a precomputed ring commitment.
+    pub fn verifier_key_from_commitment(
+        &self,
+        commitment: RingCommitment<S>,
+    ) -> RingVerifierKey<S> {
+        RingVerifierKey::<S>::from_commitment_and_kzg_vk(commitment, self.kzg_vk.clone())
+    }
+
+    /// Create a verifier instance from a precomputed ring commitment.
+    pub fn ring_verifier(&self, commitment: RingCommitment<S>) -> RingVerifier<S> {
+        let verifier_key = self.verifier_key_from_commitment(commitment);
+        self.ring_ctx.ring_verifier(verifier_key)
+    }
+}
+
+/// Discard `len` bytes from `reader`.
+fn skip<R: Read>(reader: &mut R, mut len: usize) -> Result<(), SerializationError> {
+    let mut buf = [0_u8; 256];
+    while len != 0 {
+        let n = len.min(buf.len());
+        reader.read_exact(&mut buf[..n])?;
+        len -= n;
+    }
+    Ok(())
+}
+
+impl<S: RingSuite> CanonicalSerialize for VerifierParams<S> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.ring_ctx
+            .max_ring_size()
+            .serialize_with_mode(&mut writer, compress)?;
+        self.kzg_vk.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.ring_ctx.max_ring_size().serialized_size(compress)
+            + self.kzg_vk.serialized_size(compress)
+    }
+}
+
+impl<S: RingSuite> CanonicalDeserialize for VerifierParams<S> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let ring_size = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let kzg_vk = KzgVerifierKey::<S>::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self::new(ring_size, kzg_vk))
+    }
+}
+
+impl<S: RingSuite> ark_serialize::Valid for VerifierParams<S> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.kzg_vk.check()
+    }
+}
+
+impl<S: RingSuite> RingSetup<S> {
+    /// Borrow the setup as [`ProverParams`].
+    pub fn prover_params(&self) -> ProverParams<'_, S> {
+        ProverParams {
+            pcs_params: &self.pcs_params,
+            ring_ctx: self.ring_ctx.clone(),
+        }
+    }
+
+    /// Extract the [`VerifierParams`].
+    pub fn verifier_params(&self) -> VerifierParams<S> {
+        self.prover_params().verifier_params()
+    }
+}
@@ -0,0 +1,207 @@
+//! # Key registry
+//!
+//! Registry of the ring keys over time: keys are inserted and revoked at any
+//! moment, and the current key set is committed at each epoch transition.
+//! The committed snapshots are kept, so that proofs produced against a past
+//! ring can still be verified and the key indices of past rings looked up.
+//!
+//! Keys keep their relative order as in [`RingState`]: revoked keys are
+//! dropped and inserted keys appended.
+//!
+//! Every snapshot holds its whole key set: long-running registries should
+//! [`prune`](KeyRegistry::prune) the snapshots no longer needed.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::ring::KeyRegistry;
+//! use ark_vrf::suites::bandersnatch::*;
+//!
+//! let mut registry = KeyRegistry::new(ring_setup);
+//! registry.insert(public)?;
+//! registry.commit(epoch)?;
+//!
+//! // Later, verify a proof produced at `epoch`.
+//! let verifier = registry.verifier_at(epoch).unwrap();
+//! Public::verify(io, ad, &proof, &verifier)?;
+//! ```
+
+use super::*;
+use ark_std::collections::BTreeMap;
+
+/// Key set committed at a given epoch.
+pub struct RingSnapshot<S: RingSuite> {
+    epoch: u64,
+    keys: Vec<AffinePoint<S>>,
+    indices: BTreeMap<Public<S>, usize>,
+    verifier_key: RingVerifierKey<S>,
+}
+
+impl<S: RingSuite> RingSnapshot<S> {
+    /// Epoch from which the snapshot is in force.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Ring keys, in ring order.
+    pub fn keys(&self) -> &[AffinePoint<S>] {
+        &self.keys
+    }
+
+    /// Ring index of `public`, if in the ring.
+    pub fn index_of(&self, public: &Public<S>) -> Option<usize> {
+        self.indices.get(public).copied()
+    }
+
+    /// Verifier key of the key set.
+    pub fn verifier_key(&self) -> &RingVerifierKey<S> {
+        &self.verifier_key
+    }
+
+    /// Commitment to the key set.
+    pub fn commitment(&self) -> RingCommitment<S> {
+        self.verifier_key.commitment()
+    }
+
+    /// Identifier of the ring commitment.
+    pub fn ring_id(&self) -> RingId {
+        RingId::new::<S>(&self.commitment())
+    }
+}
+
+/// Ring keys registry with per-epoch commitment snapshots.
+pub struct KeyRegistry<S: RingSuite> {
+    setup: RingSetup<S>,
+    keys: Vec<AffinePoint<S>>,
+    indices: BTreeMap<Public<S>, usize>,
+    snapshots: Vec<RingSnapshot<S>>,
+}
+
+impl<S: RingSuite> KeyRegistry<S> {
+    /// Construct an empty registry.
+    pub fn new(setup: RingSetup<S>) -> Self {
+        Self {
+            setup,
+            keys: Vec::new(),
+            indices: BTreeMap::new(),
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// Insert `public` in the current key set, returning its index.
+    ///
+    /// Returns `Error::InvalidData` if `public` is already in the set, or
+    /// `Error::RingTooLarge` if the set is full.
+    pub fn insert(&mut self, public: Public<S>) -> Result<usize, Error> {
+        if self.indices.contains_key(&public) {
+            return Err(Error::InvalidData);
+        }
+        let max = self.setup.max_ring_size();
+        if self.keys.len() == max {
+            return Err(Error::RingTooLarge { size: max + 1, max });
+        }
+        let index = self.keys.len();
+        self.keys.push(public.0);
+        self.indices.insert(public, index);
+        Ok(index)
+    }
+
+    /// Revoke `public` from the current key set.
+    ///
+    /// The keys following `public` move one index back. Returns
+    /// `Error::InvalidData` if `public` is not in the set.
+    pub fn revoke(&mut self, public: &Public<S>) -> Result<(), Error> {
+        let index = self.indices.remove(public).ok_or(Error::InvalidData)?;
+        self.keys.remove(index);
+        for i in self.indices.values_mut() {
+            if *i > index {
+                *i -= 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Current key set, in ring order.
+    pub fn keys(&self) -> &[AffinePoint<S>] {
+        &self.keys
+    }
+
+    /// Index of `public` in the current key set.
+    pub fn index_of(&self, public: &Public<S>) -> Option<usize> {
+        self.indices.get(public).copied()
+    }
+
+    /// Ring setup.
+    pub fn setup(&self) -> &RingSetup<S> {
+        &self.setup
+    }
+
+    /// Commit the current key set, in force from `epoch`.
+    ///
+    /// Returns `Error::InvalidData` if `epoch` is not after the last
+    /// committed one.
+    pub fn commit(&mut self, epoch: u64) -> Result<&RingSnapshot<S>, Error> {
+        if self.snapshots.last().is_some_and(|s| s.epoch >= epoch) {
+            return Err(Error::InvalidData);
+        }
+        let verifier_key = self.setup.verifier_key(&self.keys)?;
+        self.snapshots.push(RingSnapshot {
+            epoch,
+            keys: self.keys.clone(),
+            indices: self.indices.clone(),
+            verifier_key,
+        });
+        Ok(&self.snapshots[self.snapshots.len() - 1])
+    }
+
+    /// Committed snapshots, by increasing epoch.
+    pub fn snapshots(&self) -> &[RingSnapshot<S>] {
+        &self.snapshots
+    }
+
+    /// Snapshot in force at `epoch`: the last one committed at or before it.
+    ///
+    /// Returns `None` if `epoch` precedes the first (retained) snapshot.
+    pub fn snapshot_at(&self, epoch: u64) -> Option<&RingSnapshot<S>> {
+        let n = self.snapshots.partition_point(|s| s.epoch <= epoch);
+        n.checked_sub(1).map(|i| &self.snapshots[i])
+    }
+
+    /// Ring index of `public` at `epoch`.
+    pub fn index_at(&self, epoch: u64, public: &Public<S>) -> Option<usize> {
+        self.snapshot_at(epoch)?.index_of(public)
+    }
+
+    /// Ring commitment at `epoch`.
+    pub fn commitment_at(&self, epoch: u64) -> Option<RingCommitment<S>> {
+        self.snapshot_at(epoch).map(RingSnapshot::commitment)
+    }
+
+    /// Ring prover for `public` at `epoch`.
+    ///
+    /// Builds the prover key of the snapshot key set, which is expensive.
+    ///
+    /// Returns `Error::InvalidData` if there is no snapshot at `epoch` or
+    /// `public` is not in its ring.
+    pub fn prover_at(&self, epoch: u64, public: &Public<S>) -> Result<RingProver<S>, Error> {
+        let snapshot = self.snapshot_at(epoch).ok_or(Error::InvalidData)?;
+        let index = snapshot.index_of(public).ok_or(Error::InvalidData)?;
+        let prover_key = self.setup.prover_key(&snapshot.keys)?;
+        Ok(self.setup.ring_context().ring_prover(prover_key, index))
+    }
+
+    /// Ring verifier at `epoch`.
+    pub fn verifier_at(&self, epoch: u64) -> Option<RingVerifier<S>> {
+        let verifier_key = self.snapshot_at(epoch)?.verifier_key.clone();
+        Some(self.setup.ring_context().ring_verifier(verifier_key))
+    }
+
+    /// Drop the snapshots superseded before `epoch`.
+    ///
+    /// The snapshot in force at `epoch` is retained, so lookups at `epoch`
+    /// and after are unaffected.
+    pub fn prune(&mut self, epoch: u64) {
+        let n = self.snapshots.partition_point(|s| s.epoch <= epoch);
+        self.snapshots.drain(..n.saturating_sub(1));
+    }
+}
@@ -0,0 +1,195 @@
+//! # SRS files
+//!
+//! Loading of the canonical SRS files used by the built-in ring suites, with
+//! the file contents checked against pinned SHA-256 digests before being
+//! converted to [`PcsParams`].
+//!
+//! Files hold the uncompressed encoding of [`PcsParams`]. Once the digest
+//! matches, the file is trusted and decoded without the (slow) point checks.
+//!
+//! The files are distributed in the `data/srs` directory of the repository;
+//! fetching them is left to the caller, which can then check the downloaded
+//! bytes with [`SrsFile::verify`].
+//!
+//! With the `test-utils` feature, a small testing SRS with a known secret is
+//! embedded in the crate (see [`insecure_testing_ring_setup`]), so that tests
+//! can exercise ring proofs without fetching any file. Never use it outside
+//! of tests: anyone can forge proofs against it.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::ring::srs::ZCASH_BLS12_381;
+//! use ark_vrf::suites::bandersnatch::*;
+//!
+//! let setup: RingSetup = ZCASH_BLS12_381.ring_setup("bls12-381-srs.bin", 1023)?;
+//! ```
+
+use super::*;
+use digest::Digest;
+use std::path::Path;
+
+/// SRS file with pinned digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SrsFile {
+    /// File name in `data/srs`.
+    pub name: &'static str,
+    /// SHA-256 digest of the file contents.
+    pub sha256: [u8; 32],
+}
+
+/// Zcash BLS12-381 SRS, 2^11 powers.
+///
+/// Derived from <https://zfnd.org/conclusion-of-the-powers-of-tau-ceremony>.
+/// Used by the Bandersnatch and JubJub suites.
+pub const ZCASH_BLS12_381: SrsFile = SrsFile {
+    name: "bls12-381-srs-2-11-uncompressed-zcash.bin",
+    sha256: [
+        0x1d, 0x7d, 0x27, 0xe4, 0xf5, 0xf3, 0xc6, 0x19, 0x09, 0x89, 0xbe, 0xa5, 0x88, 0x03, 0x18,
+        0x0d, 0x3e, 0x19, 0xf7, 0x25, 0xa5, 0x70, 0x69, 0x39, 0x2a, 0x40, 0x5a, 0xc7, 0x8b, 0x23,
+        0x3c, 0x7d,
+    ],
+};
+
+/// BN254 testing SRS, 2^9 powers.
+///
+/// Derived from seed `[0_u8; 32]`, so its secret is known: for testing only.
+/// Used by the Baby-JubJub suite.
+pub const TESTING_BN254: SrsFile = SrsFile {
+    name: "bn254-testing-2-9-uncompressed.bin",
+    sha256: [
+        0x8e, 0x36, 0x7f, 0x73, 0x90, 0xfd, 0xd5, 0x14, 0xeb, 0x42, 0x66, 0x3a, 0x43, 0xc1, 0x97,
+        0x24, 0xbd, 0x23, 0xc8, 0x6e, 0x7f, 0xde, 0x8f, 0x8d, 0x86, 0xa8, 0x64, 0x62, 0x55, 0x4f,
+        0xdc, 0x02,
+    ],
+};
+
+/// BLS12-381 INSECURE testing SRS, 2^9 powers.
+///
+/// Derived from a ChaCha20 RNG with seed `[0_u8; 32]`, so its secret is known:
+/// for testing only. Supports rings of up to 255 keys for the Bandersnatch and
+/// JubJub suites.
+pub const INSECURE_TESTING_BLS12_381: SrsFile = SrsFile {
+    name: "bls12-381-INSECURE-testing-2-9-uncompressed.bin",
+    sha256: [
+        0xaf, 0xd0, 0x24, 0x64, 0x38, 0xfd, 0x47, 0x94, 0x70, 0x4c, 0x04, 0x7d, 0xab, 0x44, 0xf0,
+        0xe4, 0x66, 0x36, 0x43, 0xe7, 0x15, 0xab, 0x64, 0x7d, 0x8f, 0xd7, 0xcf, 0xa8, 0x54, 0x4c,
+        0xa3, 0x41,
+    ],
+};
+
+/// Contents of the [`INSECURE_TESTING_BLS12_381`] file, embedded in the crate.
+#[cfg(feature = "test-utils")]
+pub const INSECURE_TESTING_BLS12_381_BYTES: &[u8] =
+    include_bytes!("../../data/srs/bls12-381-INSECURE-testing-2-9-uncompressed.bin");
+
+/// INSECURE ring setup for `ring_size` from the embedded testing SRS.
+///
+/// The SRS secret is known, so anyone can forge ring proofs against the
+/// setup: for tests only.
+///
+/// Returns `Error::InvalidData` if `S` is not over BLS12-381, or
+/// `Error::SrsTooShort` if `ring_size` is larger than the SRS supports.
+#[cfg(feature = "test-utils")]
+pub fn insecure_testing_ring_setup<S: RingSuite>(ring_size: usize) -> Result<RingSetup<S>, Error> {
+    let params = INSECURE_TESTING_BLS12_381.decode::<S>(INSECURE_TESTING_BLS12_381_BYTES)?;
+    RingSetup::from_pcs_params(ring_size, params)
+}
+
+impl SrsFile {
+    /// Check `bytes` against the pinned digest.
+    ///
+    /// Returns `Error::VerificationFailure` on mismatch.
+    pub fn verify(&self, bytes: &[u8]) -> Result<(), Error> {
+        (sha2::Sha256::digest(bytes)[..] == self.sha256)
+            .then_some(())
+            .ok_or(Error::VerificationFailure)
+    }
+
+    /// Decode the file contents, after checking them with [`Self::verify`].
+    ///
+    /// Returns `Error::InvalidData` if the contents are not PCS parameters
+    /// for the pairing of `S`.
+    pub fn decode<S: RingSuite>(&self, bytes: &[u8]) -> Result<PcsParams<S>, Error> {
+        trace_span!("srs_decode", bytes = bytes.len());
+        self.verify(bytes)?;
+        let mut reader = bytes;
+        let params = PcsParams::<S>::deserialize_uncompressed_unchecked(&mut reader)?;
+        if !reader.is_empty() {
+            return Err(Error::InvalidData);
+        }
+        Ok(params)
+    }
+
+    /// Read and decode the file at `path` (see [`Self::decode`]).
+    ///
+    /// Returns `Error::InvalidData` if the file can't be read.
+    pub fn load<S: RingSuite>(&self, path: impl AsRef<Path>) -> Result<PcsParams<S>, Error> {
+        trace_span!("srs_load");
+        let bytes = std::fs::read(path).map_err(|_| Error::InvalidData)?;
+        self.decode::<S>(&bytes)
+    }
+
+    /// Ring setup for `ring_size` from the file at `path`.
+    pub fn ring_setup<S: RingSuite>(
+        &self,
+        path: impl AsRef<Path>,
+        ring_size: usize,
+    ) -> Result<RingSetup<S>, Error> {
+        RingSetup::from_pcs_params(ring_size, self.load::<S>(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(file: &SrsFile) -> String {
+        [env!("CARGO_MANIFEST_DIR"), "/data/srs/", file.name].concat()
+    }
+
+    #[test]
+    fn pinned_digests() {
+        for file in [ZCASH_BLS12_381, TESTING_BN254, INSECURE_TESTING_BLS12_381] {
+            let mut bytes = std::fs::read(path(&file)).unwrap();
+            assert_eq!(file.verify(&bytes), Ok(()));
+            bytes[0] ^= 1;
+            assert_eq!(file.verify(&bytes), Err(Error::VerificationFailure));
+        }
+    }
+
+    #[cfg(feature = "bandersnatch")]
+    #[test]
+    fn load_ring_setup() {
+        use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+
+        let setup = ZCASH_BLS12_381.ring_setup::<S>(path(&ZCASH_BLS12_381), 8);
+        assert!(setup.is_ok());
+        // Digest matches, but not the pairing.
+        let params = TESTING_BN254.load::<S>(path(&TESTING_BN254));
+        assert_eq!(params.err(), Some(Error::InvalidData));
+        let params = ZCASH_BLS12_381.load::<S>(path(&TESTING_BN254));
+        assert_eq!(params.err(), Some(Error::VerificationFailure));
+    }
+
+    #[cfg(all(feature = "bandersnatch", feature = "test-utils"))]
+    #[test]
+    fn insecure_testing_setup() {
+        use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+
+        let setup = insecure_testing_ring_setup::<S>(8).unwrap();
+        let secret = Secret::<S>::from_seed([0; 32]);
+        let mut pks = crate::testing::random_vec::<AffinePoint<S>>(8, None);
+        pks[3] = secret.public().0;
+        let ring_ctx = setup.ring_context();
+        let prover = ring_ctx.ring_prover(setup.prover_key(&pks).unwrap(), 3);
+        let verifier = ring_ctx.ring_verifier(setup.verifier_key(&pks).unwrap());
+
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = Prover::prove(&secret, io, b"bar", &prover).unwrap();
+        assert!(Public::verify(io, b"bar", &proof, &verifier).is_ok());
+
+        let setup = insecure_testing_ring_setup::<S>(max_ring_size::<S>(8) + 1);
+        assert!(matches!(setup, Err(Error::SrsTooShort { .. })));
+    }
+}
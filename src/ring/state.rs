@@ -0,0 +1,156 @@
+//! # Ring state
+//!
+//! Epoch-based management of a ring: the current key set with per-key
+//! indices, its commitment and verifier key, updated by applying key set
+//! diffs at each epoch transition.
+//!
+//! Keys keep their relative order across updates: removed keys are dropped
+//! and added keys appended, so that all the parties applying the same diffs
+//! agree on the ring commitment.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::suites::bandersnatch::*;
+//!
+//! let mut state = RingState::new(ring_setup, 0, &keys)?;
+//!
+//! // Epoch transition
+//! state.apply(1, &removed, &added)?;
+//!
+//! let prover = state.prover(&secret.public())?;
+//! let verifier = state.verifier();
+//! ```
+
+use super::*;
+use ark_std::collections::{BTreeMap, BTreeSet};
+
+/// Ring key set and verifier key at a given epoch.
+pub struct RingState<S: RingSuite> {
+    setup: RingSetup<S>,
+    epoch: u64,
+    keys: Vec<AffinePoint<S>>,
+    indices: BTreeMap<Public<S>, usize>,
+    verifier_key: RingVerifierKey<S>,
+}
+
+impl<S: RingSuite> RingState<S> {
+    /// Construct the state for `keys` at `epoch`.
+    ///
+    /// Returns `Error::InvalidData` if `keys` has duplicates, or
+    /// `Error::RingTooLarge` if it exceeds the setup capacity.
+    pub fn new(setup: RingSetup<S>, epoch: u64, keys: &[Public<S>]) -> Result<Self, Error> {
+        let keys: Vec<_> = keys.iter().map(|pk| pk.0).collect();
+        let indices = Self::indices(&keys)?;
+        let verifier_key = setup.verifier_key(&keys)?;
+        Ok(Self {
+            setup,
+            epoch,
+            keys,
+            indices,
+            verifier_key,
+        })
+    }
+
+    fn indices(keys: &[AffinePoint<S>]) -> Result<BTreeMap<Public<S>, usize>, Error> {
+        let mut indices = BTreeMap::new();
+        for (i, pk) in keys.iter().enumerate() {
+            if indices.insert(Public(*pk), i).is_some() {
+                return Err(Error::InvalidData);
+            }
+        }
+        Ok(indices)
+    }
+
+    /// Move to `epoch`, removing and then adding the given keys.
+    ///
+    /// Returns `Error::InvalidData`, leaving the state unchanged, if `epoch`
+    /// is not after the current one, a removed key is not in the ring, or an
+    /// added key already is (or is added twice). Returns
+    /// `Error::RingTooLarge` if the new key set exceeds the setup capacity.
+    pub fn apply(
+        &mut self,
+        epoch: u64,
+        removed: &[Public<S>],
+        added: &[Public<S>],
+    ) -> Result<(), Error> {
+        if epoch <= self.epoch {
+            return Err(Error::InvalidData);
+        }
+        let mut dropped = BTreeSet::new();
+        for pk in removed {
+            let i = self.index_of(pk).ok_or(Error::InvalidData)?;
+            if !dropped.insert(i) {
+                return Err(Error::InvalidData);
+            }
+        }
+        let mut keys: Vec<_> = self
+            .keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !dropped.contains(i))
+            .map(|(_, pk)| *pk)
+            .collect();
+        keys.extend(added.iter().map(|pk| pk.0));
+        let indices = Self::indices(&keys)?;
+        let verifier_key = self.setup.verifier_key(&keys)?;
+        self.epoch = epoch;
+        self.keys = keys;
+        self.indices = indices;
+        self.verifier_key = verifier_key;
+        Ok(())
+    }
+
+    /// Current epoch.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Ring keys, in ring order.
+    pub fn keys(&self) -> &[AffinePoint<S>] {
+        &self.keys
+    }
+
+    /// Ring index of `public`, if in the ring.
+    pub fn index_of(&self, public: &Public<S>) -> Option<usize> {
+        self.indices.get(public).copied()
+    }
+
+    /// Ring setup.
+    pub fn setup(&self) -> &RingSetup<S> {
+        &self.setup
+    }
+
+    /// Verifier key of the current key set.
+    pub fn verifier_key(&self) -> &RingVerifierKey<S> {
+        &self.verifier_key
+    }
+
+    /// Commitment to the current key set.
+    pub fn commitment(&self) -> RingCommitment<S> {
+        self.verifier_key.commitment()
+    }
+
+    /// Identifier of the current ring commitment.
+    pub fn ring_id(&self) -> RingId {
+        RingId::new::<S>(&self.commitment())
+    }
+
+    /// Ring prover for `public`.
+    ///
+    /// Builds the prover key for the current key set, which is expensive:
+    /// provers are expected to build one per epoch.
+    ///
+    /// Returns `Error::InvalidData` if `public` is not in the ring.
+    pub fn prover(&self, public: &Public<S>) -> Result<RingProver<S>, Error> {
+        let index = self.index_of(public).ok_or(Error::InvalidData)?;
+        let prover_key = self.setup.prover_key(&self.keys)?;
+        Ok(self.setup.ring_context().ring_prover(prover_key, index))
+    }
+
+    /// Ring verifier for the current key set.
+    pub fn verifier(&self) -> RingVerifier<S> {
+        let verifier_key = self.verifier_key.clone();
+        self.setup.ring_context().ring_verifier(verifier_key)
+    }
+}
@@ -0,0 +1,210 @@
+//! # Tickets
+//!
+//! Ring VRF tickets, as used by Sassafras and JAM for anonymous block
+//! production slot assignment.
+//!
+//! Each validator may submit up to a fixed number of tickets per epoch. The
+//! VRF input of a ticket is derived from the epoch randomness and the attempt
+//! index, framed as `domain || randomness || attempt` (as in the JAM
+//! `jam_ticket_seal` context). The ticket identifier is the hash of the VRF
+//! output, and only tickets with identifier below a threshold are accepted.
+//!
+//! The identifier only depends on the secret key and the input, so provers
+//! should check it with [`Ticket::id_for`] before paying for the ring proof.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::ring::{Ticket, TicketContext, ticket_threshold};
+//! use ark_vrf::suites::bandersnatch::*;
+//!
+//! let ctx = TicketContext::jam(&entropy);
+//! let threshold = ticket_threshold(1, 600, 2, 1023);
+//!
+//! // Proving
+//! if Ticket::id_for(&secret, &ctx, attempt) < threshold {
+//!     let (id, ticket) = Ticket::prove(&secret, &ctx, attempt, &prover)?;
+//! }
+//!
+//! // Verification
+//! let id = ticket.verify(&ctx, &threshold, &verifier)?;
+//! ```
+
+use super::*;
+
+/// JAM ticket seal domain.
+pub const JAM_TICKET_SEAL: &[u8] = b"jam_ticket_seal";
+
+/// Ticket identifier: the first 32 bytes of the VRF output hash.
+///
+/// Identifiers compare lexicographically, i.e. as big-endian integers.
+pub type TicketId = [u8; 32];
+
+/// Ticket identifier threshold.
+///
+/// Returns `2^256 * (redundancy * slots) / (attempts * validators)`, the
+/// Sassafras threshold for which each slot gets `redundancy` tickets on
+/// average when all the validators submit all their attempts.
+///
+/// Saturates to all ones (accepting any ticket but the negligible all ones
+/// identifier) if the ratio is not below one, or if `attempts * validators`
+/// is zero.
+pub fn ticket_threshold(redundancy: u32, slots: u32, attempts: u32, validators: u32) -> TicketId {
+    let num = redundancy as u128 * slots as u128;
+    let den = attempts as u128 * validators as u128;
+    let mut threshold = [0xff; 32];
+    if num >= den {
+        return threshold;
+    }
+    // Long division of `num / den` in base 256.
+    let mut rem = num;
+    for byte in threshold.iter_mut() {
+        rem <<= 8;
+        *byte = (rem / den) as u8;
+        rem %= den;
+    }
+    threshold
+}
+
+/// Ticket input framing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TicketContext<'a> {
+    /// Ticket domain (e.g. [`JAM_TICKET_SEAL`]).
+    pub domain: &'a [u8],
+    /// Epoch randomness.
+    pub randomness: &'a [u8],
+}
+
+impl<'a> TicketContext<'a> {
+    /// Construct a new context.
+    pub const fn new(domain: &'a [u8], randomness: &'a [u8]) -> Self {
+        Self { domain, randomness }
+    }
+
+    /// Context with the [`JAM_TICKET_SEAL`] domain.
+    pub const fn jam(randomness: &'a [u8]) -> Self {
+        Self::new(JAM_TICKET_SEAL, randomness)
+    }
+
+    /// VRF input of the ticket with the given attempt index.
+    ///
+    /// Hashes `domain || randomness || attempt` via [`Input::new`].
+    pub fn input<S: Suite>(&self, attempt: u8) -> Option<Input<S>> {
+        let data = [self.domain, self.randomness, &[attempt]].concat();
+        Input::new(&data)
+    }
+}
+
+/// Ring VRF ticket.
+///
+/// Attempt index and VRF output, with the ring proof for the matching input.
+/// The proof has no additional data, the ticket being fully determined by
+/// its input.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Ticket<S: RingSuite> {
+    /// Attempt index.
+    pub attempt: u8,
+    /// VRF output.
+    pub output: Output<S>,
+    /// Ring VRF proof.
+    pub proof: Proof<S>,
+}
+
+impl<S: RingSuite> core::fmt::Debug for Ticket<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ticket")
+            .field("attempt", &self.attempt)
+            .field("output", &self.output.0)
+            .field("proof", &self.proof)
+            .finish()
+    }
+}
+
+impl<S: RingSuite> Ticket<S> {
+    /// Identifier of the ticket with the given attempt index.
+    ///
+    /// Cheap to compute, as it requires no proof.
+    ///
+    /// Panics if the ticket input can't be constructed, which for the
+    /// built-in suites happens with negligible probability.
+    #[cfg(feature = "prove")]
+    pub fn id_for(secret: &Secret<S>, ctx: &TicketContext, attempt: u8) -> TicketId {
+        let input = ctx.input::<S>(attempt).expect("ticket input");
+        secret.output(input).hash()
+    }
+
+    /// Build the ticket with the given attempt index.
+    ///
+    /// Returns the ticket identifier with the ticket. Threshold filtering is
+    /// left to the caller, preferably before proving (see [`Self::id_for`]).
+    ///
+    /// Fails under the same conditions as [`Prover::prove`](super::Prover::prove).
+    /// Panics under the same conditions as [`Self::id_for`].
+    #[cfg(feature = "prove")]
+    pub fn prove(
+        secret: &Secret<S>,
+        ctx: &TicketContext,
+        attempt: u8,
+        prover: &RingProver<S>,
+    ) -> Result<(TicketId, Self), Error> {
+        use super::Prover;
+        let input = ctx.input::<S>(attempt).expect("ticket input");
+        let io = secret.vrf_io(input);
+        let proof = secret.prove(io, b"", prover)?;
+        let ticket = Self {
+            attempt,
+            output: io.output,
+            proof,
+        };
+        Ok((io.output.hash(), ticket))
+    }
+
+    /// Ticket identifier.
+    ///
+    /// Not authenticated until the ticket is verified.
+    pub fn id(&self) -> TicketId {
+        self.output.hash()
+    }
+
+    /// Verify the ticket and check its identifier against `threshold`.
+    ///
+    /// The identifier is checked first, so tickets above the threshold are
+    /// rejected without verifying the proof.
+    ///
+    /// Returns the ticket identifier on success, `Error::InvalidData` if the
+    /// ticket input can't be constructed, or `Error::VerificationFailure`
+    /// if the identifier is not below `threshold` or the proof is invalid.
+    pub fn verify(
+        &self,
+        ctx: &TicketContext,
+        threshold: &TicketId,
+        verifier: &RingVerifier<S>,
+    ) -> Result<TicketId, Error> {
+        use super::Verifier;
+        let id = self.id();
+        if id >= *threshold {
+            return Err(Error::VerificationFailure);
+        }
+        let input = ctx.input::<S>(self.attempt).ok_or(Error::InvalidData)?;
+        let io = VrfIo {
+            input,
+            output: self.output,
+        };
+        Public::verify(io, b"", &self.proof, verifier)?;
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn threshold() {
+        assert_eq!(ticket_threshold(1, 1, 2, 1)[..2], [0x80, 0x00]);
+        assert_eq!(ticket_threshold(1, 1, 3, 1)[..2], [0x55, 0x55]);
+        assert_eq!(ticket_threshold(1, 600, 2, 1023)[..2], [0x4b, 0x12]);
+        assert_eq!(ticket_threshold(2, 1, 2, 1), [0xff; 32]);
+        assert_eq!(ticket_threshold(1, 1, 0, 1), [0xff; 32]);
+    }
+}
@@ -0,0 +1,179 @@
+//! # RustCrypto signature adapters
+//!
+//! Use the Tiny VRF as a signature scheme through the [`signature`] crate
+//! traits, so that VRF keys plug into key-management code generic over
+//! [`Signer`] and [`Verifier`].
+//!
+//! The signed message is hashed to the VRF input and the signature carries the
+//! VRF output together with the proof. The additional data bound to the proof
+//! is not part of the message: it is carried by the signing and verifying
+//! contexts ([`VrfSigner`] and [`VrfVerifier`]). [`Secret`] and [`Public`]
+//! implement the traits directly with empty additional data.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::rustcrypto::{VrfSigner, VrfVerifier};
+//! use signature::{Signer, Verifier};
+//!
+//! let sig = VrfSigner::new(&secret, b"ad").sign(b"msg");
+//! VrfVerifier::new(&public, b"ad").verify(b"msg", &sig)?;
+//! let randomness = sig.output.hash::<32>();
+//! ```
+
+#[cfg(feature = "prove")]
+use crate::tiny::Prover;
+use crate::tiny::{self, Proof, TinySuite};
+use crate::utils::decode_canonical;
+use crate::*;
+#[cfg(feature = "prove")]
+use signature::Signer;
+use signature::{Error as SignatureError, SignatureEncoding, Verifier};
+
+/// Tiny VRF signature.
+#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VrfSignature<S: TinySuite> {
+    /// VRF output for the message.
+    pub output: Output<S>,
+    /// Tiny VRF proof.
+    pub proof: Proof<S>,
+}
+
+impl<S: TinySuite> TryFrom<&[u8]> for VrfSignature<S> {
+    type Error = SignatureError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, SignatureError> {
+        decode_canonical(bytes).map_err(|_| SignatureError::new())
+    }
+}
+
+impl<S: TinySuite> TryFrom<Vec<u8>> for VrfSignature<S> {
+    type Error = SignatureError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, SignatureError> {
+        Self::try_from(&bytes[..])
+    }
+}
+
+impl<S: TinySuite> From<VrfSignature<S>> for Vec<u8> {
+    fn from(sig: VrfSignature<S>) -> Self {
+        let mut buf = Vec::with_capacity(sig.compressed_size());
+        sig.serialize_compressed(&mut buf)
+            .expect("serialization into a vector can't fail");
+        buf
+    }
+}
+
+impl<S: TinySuite> SignatureEncoding for VrfSignature<S> {
+    type Repr = Vec<u8>;
+}
+
+/// Signing context binding a secret key to additional data.
+#[cfg(feature = "prove")]
+#[derive(Clone, Copy)]
+pub struct VrfSigner<'a, S: TinySuite> {
+    secret: &'a Secret<S>,
+    ad: &'a [u8],
+}
+
+#[cfg(feature = "prove")]
+impl<'a, S: TinySuite> VrfSigner<'a, S> {
+    /// Sign with `secret`, binding `ad` to every proof.
+    pub fn new(secret: &'a Secret<S>, ad: &'a [u8]) -> Self {
+        Self { secret, ad }
+    }
+}
+
+#[cfg(feature = "prove")]
+impl<S: TinySuite> Signer<VrfSignature<S>> for VrfSigner<'_, S> {
+    fn try_sign(&self, msg: &[u8]) -> Result<VrfSignature<S>, SignatureError> {
+        let input = Input::new(msg).ok_or_else(SignatureError::new)?;
+        let io = self.secret.vrf_io(input);
+        Ok(VrfSignature {
+            output: io.output,
+            proof: self.secret.prove(io, self.ad),
+        })
+    }
+}
+
+#[cfg(feature = "prove")]
+impl<S: TinySuite> Signer<VrfSignature<S>> for Secret<S> {
+    fn try_sign(&self, msg: &[u8]) -> Result<VrfSignature<S>, SignatureError> {
+        VrfSigner::new(self, &[]).try_sign(msg)
+    }
+}
+
+/// Verifying context binding a public key to additional data.
+#[derive(Clone, Copy)]
+pub struct VrfVerifier<'a, S: TinySuite> {
+    public: &'a Public<S>,
+    ad: &'a [u8],
+}
+
+impl<'a, S: TinySuite> VrfVerifier<'a, S> {
+    /// Verify against `public`, expecting `ad` to be bound to the proofs.
+    pub fn new(public: &'a Public<S>, ad: &'a [u8]) -> Self {
+        Self { public, ad }
+    }
+}
+
+impl<S: TinySuite> Verifier<VrfSignature<S>> for VrfVerifier<'_, S> {
+    fn verify(&self, msg: &[u8], sig: &VrfSignature<S>) -> Result<(), SignatureError> {
+        let input = Input::new(msg).ok_or_else(SignatureError::new)?;
+        let io = VrfIo {
+            input,
+            output: sig.output,
+        };
+        tiny::Verifier::verify(self.public, io, self.ad, &sig.proof)
+            .map_err(|_| SignatureError::new())
+    }
+}
+
+impl<S: TinySuite> Verifier<VrfSignature<S>> for Public<S> {
+    fn verify(&self, msg: &[u8], sig: &VrfSignature<S>) -> Result<(), SignatureError> {
+        VrfVerifier::new(self, &[]).verify(msg, sig)
+    }
+}
+
+/// Type aliases of the signature types for the given suite.
+#[macro_export]
+macro_rules! signature_suite_types {
+    ($suite:ident) => {
+        #[allow(dead_code)]
+        pub type VrfSignature = $crate::rustcrypto::VrfSignature<$suite>;
+        $crate::cfg_prove! {
+            #[allow(dead_code)]
+            pub type VrfSigner<'a> = $crate::rustcrypto::VrfSigner<'a, $suite>;
+        }
+        #[allow(dead_code)]
+        pub type VrfVerifier<'a> = $crate::rustcrypto::VrfVerifier<'a, $suite>;
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use suites::testing::TestSuite;
+
+    #[test]
+    fn sign_verify() {
+        let secret = Secret::<TestSuite>::from_seed(crate::testing::TEST_SEED);
+        let public = secret.public();
+
+        let sig: VrfSignature<TestSuite> = VrfSigner::new(&secret, b"ad").sign(b"msg");
+        let expected = secret.output(Input::new(b"msg").unwrap());
+        assert_eq!(sig.output, expected);
+        let verifier = VrfVerifier::new(&public, b"ad");
+        assert!(verifier.verify(b"msg", &sig).is_ok());
+        assert!(verifier.verify(b"other", &sig).is_err());
+        assert!(VrfVerifier::new(&public, b"").verify(b"msg", &sig).is_err());
+
+        let sig: VrfSignature<TestSuite> = secret.sign(b"msg");
+        assert!(public.verify(b"msg", &sig).is_ok());
+
+        let bytes = sig.to_vec();
+        let decoded = VrfSignature::<TestSuite>::try_from(&bytes[..]).unwrap();
+        assert!(public.verify(b"msg", &decoded).is_ok());
+        assert!(VrfSignature::<TestSuite>::try_from(&bytes[1..]).is_err());
+    }
+}
@@ -43,7 +43,7 @@
 //! * The `ECVRF_encode_to_curve` function uses Try-And-Increment, inspired
 //!   by Section 5.4.1.1 of RFC-9381.
 
-use super::{SuiteId, curve, h2c, hash};
+use super::{SuiteId, SuiteInfo, curve, h2c, hash};
 use crate::{pedersen::PedersenSuite, *};
 use ark_ff::MontFp;
 
@@ -53,11 +53,24 @@ pub struct BabyJubJubSha512Ell2;
 type ThisSuite = BabyJubJubSha512Ell2;
 
 suite_types!(ThisSuite);
+#[cfg(feature = "signature")]
+signature_suite_types!(ThisSuite);
 
 impl Suite for ThisSuite {
     const SUITE_ID: SuiteId = SuiteId::new(1, curve::BABY_JUBJUB, hash::SHA512, h2c::TAI);
     type Affine = ark_ed_on_bn254::EdwardsAffine;
     type Transcript = utils::HashTranscript<sha2::Sha512>;
+
+    fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(Self::generator)
+    }
+}
+
+impl SuiteInfo for ThisSuite {
+    const NAME: &'static str = "baby-jubjub";
+    const SUPPORTS_PEDERSEN: bool = true;
+    const SUPPORTS_RING: bool = cfg!(feature = "ring");
 }
 
 impl PedersenSuite for ThisSuite {
@@ -69,6 +82,11 @@ impl PedersenSuite for ThisSuite {
         );
         AffinePoint::new_unchecked(X, Y)
     };
+
+    fn blinding_base_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(|| Self::BLINDING_BASE)
+    }
 }
 
 #[cfg(feature = "ring")]
@@ -98,29 +116,30 @@ impl crate::ring::RingSuite for ThisSuite {
 #[cfg(feature = "ring")]
 ring_suite_types!(ThisSuite);
 
+#[cfg(any(test, feature = "vectors"))]
+impl crate::vectors::SuiteExt for ThisSuite {
+    const SUITE_NAME: &str = "baby-jubjub_sha-512_tai";
+}
+
+#[cfg(all(feature = "ring", any(test, feature = "vectors")))]
+impl crate::ring::vectors::RingSuiteExt for ThisSuite {
+    const SRS_FILE: &str = crate::vectors::BN254_PCS_SRS_FILE;
+
+    fn ring_setup() -> &'static RingSetup {
+        use std::sync::OnceLock;
+        static RING_SETUP: OnceLock<RingSetup> = OnceLock::new();
+        RING_SETUP.get_or_init(Self::load_ring_setup)
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
 
-    impl crate::testing::SuiteExt for ThisSuite {
-        const SUITE_NAME: &str = "baby-jubjub_sha-512_tai";
-    }
-
     tiny_suite_tests!(ThisSuite);
     pedersen_suite_tests!(ThisSuite);
     thin_suite_tests!(ThisSuite);
 
     #[cfg(feature = "ring")]
     ring_suite_tests!(ThisSuite);
-
-    #[cfg(feature = "ring")]
-    impl crate::ring::testing::RingSuiteExt for ThisSuite {
-        const SRS_FILE: &str = crate::testing::BN254_PCS_SRS_FILE;
-
-        fn ring_setup() -> &'static RingSetup {
-            use std::sync::OnceLock;
-            static RING_SETUP: OnceLock<RingSetup> = OnceLock::new();
-            RING_SETUP.get_or_init(Self::load_ring_setup)
-        }
-    }
 }
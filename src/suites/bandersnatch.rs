@@ -47,7 +47,7 @@
 //!   with `h2c_suite_ID_string` = `"Bandersnatch_XMD:SHA-512_ELL2_RO_"`
 //!   and domain separation tag `DST = "ECVRF_" || h2c_suite_ID_string || suite_string`.
 
-use super::{SuiteId, curve, h2c, hash};
+use super::{SuiteId, SuiteInfo, curve, h2c, hash};
 use crate::{pedersen::PedersenSuite, *};
 use ark_ff::MontFp;
 
@@ -57,6 +57,8 @@ pub struct BandersnatchSha512Ell2;
 type ThisSuite = BandersnatchSha512Ell2;
 
 suite_types!(ThisSuite);
+#[cfg(feature = "signature")]
+signature_suite_types!(ThisSuite);
 
 impl Suite for ThisSuite {
     const SUITE_ID: SuiteId = SuiteId::new(1, curve::BANDERSNATCH, hash::SHA512, h2c::ELL2);
@@ -69,6 +71,18 @@ impl Suite for ThisSuite {
         let h2c_suite_id = b"Bandersnatch_XMD:SHA-512_ELL2_RO_";
         utils::hash_to_curve_ell2_xmd::<Self, sha2::Sha512>(data, h2c_suite_id)
     }
+
+    fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(Self::generator)
+    }
+}
+
+impl SuiteInfo for ThisSuite {
+    const NAME: &'static str = "bandersnatch";
+    const DATA_TO_POINT: &'static str = "ell2_rfc_9380";
+    const SUPPORTS_PEDERSEN: bool = true;
+    const SUPPORTS_RING: bool = cfg!(feature = "ring");
 }
 
 impl PedersenSuite for ThisSuite {
@@ -80,6 +94,11 @@ impl PedersenSuite for ThisSuite {
         );
         AffinePoint::new_unchecked(X, Y)
     };
+
+    fn blinding_base_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(|| Self::BLINDING_BASE)
+    }
 }
 
 #[cfg(feature = "ring")]
@@ -110,14 +129,26 @@ impl crate::ring::RingSuite for ThisSuite {
 #[cfg(feature = "ring")]
 ring_suite_types!(ThisSuite);
 
+#[cfg(any(test, feature = "vectors"))]
+impl crate::vectors::SuiteExt for ThisSuite {
+    const SUITE_NAME: &str = "bandersnatch_sha-512_ell2";
+}
+
+#[cfg(all(feature = "ring", any(test, feature = "vectors")))]
+impl crate::ring::vectors::RingSuiteExt for ThisSuite {
+    const SRS_FILE: &str = crate::vectors::BLS12_381_PCS_SRS_FILE;
+
+    fn ring_setup() -> &'static RingSetup {
+        use std::sync::OnceLock;
+        static RING_SETUP: OnceLock<RingSetup> = OnceLock::new();
+        RING_SETUP.get_or_init(Self::load_ring_setup)
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
 
-    impl crate::testing::SuiteExt for ThisSuite {
-        const SUITE_NAME: &str = "bandersnatch_sha-512_ell2";
-    }
-
     tiny_suite_tests!(ThisSuite);
     pedersen_suite_tests!(ThisSuite);
     thin_suite_tests!(ThisSuite);
@@ -125,17 +156,6 @@ pub(crate) mod tests {
     #[cfg(feature = "ring")]
     ring_suite_tests!(ThisSuite);
 
-    #[cfg(feature = "ring")]
-    impl crate::ring::testing::RingSuiteExt for ThisSuite {
-        const SRS_FILE: &str = crate::testing::BLS12_381_PCS_SRS_FILE;
-
-        fn ring_setup() -> &'static RingSetup {
-            use std::sync::OnceLock;
-            static RING_SETUP: OnceLock<RingSetup> = OnceLock::new();
-            RING_SETUP.get_or_init(Self::load_ring_setup)
-        }
-    }
-
     #[test]
     fn elligator2_hash_to_curve() {
         use crate::testing::CheckPoint;
@@ -0,0 +1,233 @@
+//! # Bandersnatch host API
+//!
+//! Non-generic API over [`BandersnatchSha512Ell2`] with fixed-size byte arrays
+//! at the boundary, meant to back runtime host functions (e.g. Substrate's
+//! `sp-io`), where argument sizes must be known statically and every
+//! encoding is passed across the boundary as bytes.
+//!
+//! All values use the suite's compressed encoding and are decoded with the
+//! full validity checks.
+//!
+//! With the `ring` feature, [`RingVerifierHandle`] is built once from a ring
+//! commitment and reused to verify any number of proofs for that ring.
+
+use super::bandersnatch::*;
+use crate::Error;
+#[cfg(feature = "prove")]
+use crate::tiny::Prover as _;
+use crate::tiny::Verifier as _;
+use ark_serialize::CanonicalDeserialize;
+use ark_std::vec::Vec;
+
+/// Encoded secret key length.
+pub const SECRET_LEN: usize = 32;
+/// Encoded public key length.
+pub const PUBLIC_LEN: usize = 32;
+/// Encoded VRF output point length.
+pub const OUTPUT_LEN: usize = 32;
+/// VRF output hash length.
+pub const HASH_LEN: usize = 32;
+/// Encoded Tiny VRF proof length.
+pub const TINY_PROOF_LEN: usize = 48;
+/// Encoded Pedersen VRF proof length.
+pub const PEDERSEN_PROOF_LEN: usize = 160;
+/// Encoded ring commitment length.
+#[cfg(feature = "ring")]
+pub const RING_COMMITMENT_LEN: usize = 144;
+/// Encoded Ring VRF proof length.
+#[cfg(feature = "ring")]
+pub const RING_PROOF_LEN: usize = PEDERSEN_PROOF_LEN + 592;
+
+fn decode<T: CanonicalDeserialize>(bytes: &[u8]) -> Result<T, Error> {
+    Ok(T::deserialize_compressed(bytes)?)
+}
+
+#[cfg(any(feature = "prove", feature = "ring"))]
+fn encode<T: ark_serialize::CanonicalSerialize, const N: usize>(value: &T) -> [u8; N] {
+    let mut buf = [0; N];
+    value
+        .serialize_compressed(&mut buf[..])
+        .expect("buffer size matches the encoding");
+    buf
+}
+
+fn input(data: &[u8]) -> Result<Input, Error> {
+    Input::new(data).ok_or(Error::HashToCurveFailure)
+}
+
+fn vrf_io(data: &[u8], output: &[u8; OUTPUT_LEN]) -> Result<VrfIo, Error> {
+    Ok(VrfIo {
+        input: input(data)?,
+        output: decode(output)?,
+    })
+}
+
+/// Derive a secret key from a seed.
+#[cfg(feature = "prove")]
+pub fn secret_from_seed(seed: &[u8; 32]) -> [u8; SECRET_LEN] {
+    encode(&Secret::from_seed(*seed))
+}
+
+/// Public key of a secret key.
+#[cfg(feature = "prove")]
+pub fn public(secret: &[u8; SECRET_LEN]) -> Result<[u8; PUBLIC_LEN], Error> {
+    Ok(encode(&decode::<Secret>(secret)?.public()))
+}
+
+/// VRF output point for the input derived from `data`.
+#[cfg(feature = "prove")]
+pub fn output(secret: &[u8; SECRET_LEN], data: &[u8]) -> Result<[u8; OUTPUT_LEN], Error> {
+    let secret: Secret = decode(secret)?;
+    Ok(encode(&secret.output(input(data)?)))
+}
+
+/// Hash of a VRF output point.
+pub fn output_hash(output: &[u8; OUTPUT_LEN]) -> Result<[u8; HASH_LEN], Error> {
+    Ok(decode::<Output>(output)?.hash())
+}
+
+/// Tiny VRF proof for the input derived from `data`.
+///
+/// Returns the VRF output point and the proof.
+#[cfg(feature = "prove")]
+pub fn tiny_prove(
+    secret: &[u8; SECRET_LEN],
+    data: &[u8],
+    ad: &[u8],
+) -> Result<([u8; OUTPUT_LEN], [u8; TINY_PROOF_LEN]), Error> {
+    let secret: Secret = decode(secret)?;
+    let io = secret.vrf_io(input(data)?);
+    Ok((encode(&io.output), encode(&secret.prove(io, ad))))
+}
+
+/// Verify a Tiny VRF proof.
+pub fn tiny_verify(
+    public: &[u8; PUBLIC_LEN],
+    data: &[u8],
+    output: &[u8; OUTPUT_LEN],
+    ad: &[u8],
+    proof: &[u8; TINY_PROOF_LEN],
+) -> Result<(), Error> {
+    let public: Public = decode(public)?;
+    let proof: TinyProof = decode(proof)?;
+    public.verify(vrf_io(data, output)?, ad, &proof)
+}
+
+/// Verify a Pedersen VRF proof.
+pub fn pedersen_verify(
+    data: &[u8],
+    output: &[u8; OUTPUT_LEN],
+    ad: &[u8],
+    proof: &[u8; PEDERSEN_PROOF_LEN],
+) -> Result<(), Error> {
+    use crate::pedersen::Verifier;
+    let proof: PedersenProof = decode(proof)?;
+    <Public as Verifier<_>>::verify(vrf_io(data, output)?, ad, &proof)
+}
+
+/// Reusable Ring VRF verifier for a fixed ring.
+#[cfg(feature = "ring")]
+pub struct RingVerifierHandle {
+    verifier: RingVerifier,
+}
+
+#[cfg(feature = "ring")]
+impl RingVerifierHandle {
+    /// Build a verifier for the ring with the given commitment.
+    ///
+    /// Only the verification key part of `setup` is used, so the handle can
+    /// outlive it.
+    pub fn new(setup: &RingSetup, commitment: &[u8; RING_COMMITMENT_LEN]) -> Result<Self, Error> {
+        let commitment: RingCommitment = decode(commitment)?;
+        let verifier_key = setup.verifier_key_from_commitment(commitment);
+        Ok(Self {
+            verifier: setup.ring_context().ring_verifier(verifier_key),
+        })
+    }
+
+    /// Verify a Ring VRF proof.
+    pub fn verify(
+        &self,
+        data: &[u8],
+        output: &[u8; OUTPUT_LEN],
+        ad: &[u8],
+        proof: &[u8; RING_PROOF_LEN],
+    ) -> Result<(), Error> {
+        use crate::ring::Verifier;
+        let proof: RingProof = decode(proof)?;
+        <Public as Verifier<_>>::verify(vrf_io(data, output)?, ad, &proof, &self.verifier)
+    }
+}
+
+/// Ring commitment for the given ring public keys.
+///
+/// Returns `Error::InvalidData` if a key is malformed or the ring doesn't fit
+/// `setup`.
+#[cfg(feature = "ring")]
+pub fn ring_commitment(
+    setup: &RingSetup,
+    ring: &[[u8; PUBLIC_LEN]],
+) -> Result<[u8; RING_COMMITMENT_LEN], Error> {
+    let pks = ring
+        .iter()
+        .map(|pk| decode::<Public>(pk).map(|pk| pk.0))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(encode(&setup.verifier_key(&pks)?.commitment()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_SEED;
+
+    #[test]
+    fn prove_verify() {
+        let secret = secret_from_seed(&TEST_SEED);
+        let public = public(&secret).unwrap();
+        let (out, proof) = tiny_prove(&secret, b"foo", b"bar").unwrap();
+        assert_eq!(out, output(&secret, b"foo").unwrap());
+        assert!(output_hash(&out).is_ok());
+        assert_eq!(tiny_verify(&public, b"foo", &out, b"bar", &proof), Ok(()));
+        assert_eq!(
+            tiny_verify(&public, b"foo", &out, b"baz", &proof),
+            Err(Error::VerificationFailure)
+        );
+
+        let secret: Secret = decode(&secret).unwrap();
+        let io = secret.vrf_io(input(b"foo").unwrap());
+        let (proof, _) = crate::pedersen::Prover::prove(&secret, io, b"bar");
+        let proof = encode(&proof);
+        assert_eq!(pedersen_verify(b"foo", &out, b"bar", &proof), Ok(()));
+    }
+
+    #[cfg(feature = "ring")]
+    #[test]
+    fn ring_verifier_handle() {
+        use crate::ring::vectors::RingSuiteExt;
+        let setup = BandersnatchSha512Ell2::ring_setup();
+        let secret = Secret::from_seed(TEST_SEED);
+        let mut ring: Vec<_> = (0..4u8)
+            .map(|i| encode(&Secret::from_seed([i + 1; 32]).public()))
+            .collect();
+        ring[2] = encode(&secret.public());
+        let commitment = ring_commitment(setup, &ring).unwrap();
+
+        let pks: Vec<_> = ring
+            .iter()
+            .map(|pk| decode::<Public>(pk).unwrap().0)
+            .collect();
+        let prover_key = setup.prover_key(&pks).unwrap();
+        let prover = setup.ring_context().ring_prover(prover_key, 2);
+        let io = secret.vrf_io(input(b"foo").unwrap());
+        let proof: [u8; RING_PROOF_LEN] =
+            encode(&crate::ring::Prover::prove(&secret, io, b"bar", &prover).unwrap());
+
+        let handle = RingVerifierHandle::new(setup, &commitment).unwrap();
+        let out = encode(&io.output);
+        assert_eq!(handle.verify(b"foo", &out, b"bar", &proof), Ok(()));
+        assert_eq!(
+            handle.verify(b"foo", &out, b"baz", &proof),
+            Err(Error::VerificationFailure)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! # Legacy `bandersnatch_vrfs` compatibility
+//!
+//! Conversions between this crate's Bandersnatch suites and the byte formats
+//! of the w3f `bandersnatch_vrfs`/`ring-vrf` crates, to migrate keys of
+//! existing deployments.
+//!
+//! The legacy crates work on the Short Weierstrass form of Bandersnatch and
+//! use arkworks compressed encodings throughout:
+//!
+//! | Item           | Legacy format                       | Compatibility                          |
+//! |----------------|-------------------------------------|----------------------------------------|
+//! | Secret scalar  | 32 bytes, little endian             | Same encoding, see [`secret_from_legacy`] |
+//! | Public key     | SW point, 33 bytes compressed       | Identical to [`BandersnatchSha512Tai`] keys; mapped to the TE form by [`public_from_legacy`] |
+//! | VRF input      | Hashed with the legacy transcript   | Not compatible                         |
+//! | VRF output     | SW point, 33 bytes compressed       | Decodes, but differs for the same data |
+//! | Proofs         | Thin/Pedersen/ring over `merlin`-style transcripts | Not compatible         |
+//! | Ring commitment| KZG commitments, legacy padding and accumulator seed | Not compatible; rebuild from the ring keys |
+//!
+//! Secret keys derived from a seed are not compatible either, as seed
+//! expansion differs: migrate the secret scalar rather than the seed.
+//!
+//! Only key material carries over. A migrated key produces different VRF
+//! outputs for the same input data, so protocols must switch scheme at a
+//! well defined point (e.g. an epoch boundary).
+//!
+//! [`BandersnatchSha512Tai`]: super::bandersnatch_sw::BandersnatchSha512Tai
+//! [`BandersnatchSha512Ell2`]: super::bandersnatch::BandersnatchSha512Ell2
+
+use super::bandersnatch::Public;
+#[cfg(feature = "prove")]
+use super::bandersnatch::Secret;
+use super::bandersnatch_sw::BandersnatchSha512Tai;
+use crate::utils::{decode_canonical, te_sw_map::*};
+use crate::*;
+use ark_ed_on_bls12_381_bandersnatch::{EdwardsAffine, SWAffine};
+
+/// Legacy encoded secret scalar length.
+pub const LEGACY_SECRET_LEN: usize = 32;
+
+/// Legacy encoded public key length.
+pub const LEGACY_PUBLIC_LEN: usize = 33;
+
+/// Import a secret key from a legacy encoded secret scalar.
+///
+/// The resulting secret's public key is the TE form of the legacy one.
+/// Returns `Error::NonCanonicalScalar` for a non-canonical or zero scalar.
+#[cfg(feature = "prove")]
+pub fn secret_from_legacy(bytes: &[u8]) -> Result<Secret, Error> {
+    Secret::try_from_bytes(bytes)
+}
+
+/// Export a secret key as a legacy encoded secret scalar.
+#[cfg(feature = "prove")]
+pub fn secret_to_legacy(secret: &Secret) -> [u8; LEGACY_SECRET_LEN] {
+    let mut buf = [0; LEGACY_SECRET_LEN];
+    secret
+        .scalar()
+        .serialize_compressed(&mut buf[..])
+        .expect("buffer is big enough");
+    buf
+}
+
+/// Import a legacy encoded public key.
+///
+/// Decodes the SW point, checks it is a non-identity point of the prime order
+/// subgroup and maps it to the TE form used by
+/// [`BandersnatchSha512Ell2`](super::bandersnatch::BandersnatchSha512Ell2).
+pub fn public_from_legacy(bytes: &[u8]) -> Result<Public, Error> {
+    let sw: crate::Public<BandersnatchSha512Tai> = decode_canonical(bytes)?;
+    let te: EdwardsAffine = sw_to_te(&sw.0).ok_or(Error::InvalidData)?;
+    Public::try_from_affine(te)
+}
+
+/// Export a public key in the legacy encoding.
+pub fn public_to_legacy(public: &Public) -> Result<[u8; LEGACY_PUBLIC_LEN], Error> {
+    let sw: SWAffine = te_to_sw(&public.0).ok_or(Error::InvalidData)?;
+    let mut buf = [0; LEGACY_PUBLIC_LEN];
+    sw.serialize_compressed(&mut buf[..])?;
+    Ok(buf)
+}
+
+/// Convert a public key of the TE suite to the equivalent SW suite key.
+///
+/// [`BandersnatchSha512Tai`] keys share the legacy encoding, so this is the
+/// in-memory counterpart of [`public_to_legacy`].
+pub fn public_to_sw(public: &Public) -> Option<crate::Public<BandersnatchSha512Tai>> {
+    te_to_sw(&public.0).map(crate::Public)
+}
+
+/// Convert a [`BandersnatchSha512Tai`] public key to the TE suite key.
+pub fn public_from_sw(public: &crate::Public<BandersnatchSha512Tai>) -> Option<Public> {
+    sw_to_te(&public.0).map(crate::Public)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_SEED;
+
+    #[test]
+    fn legacy_key_round_trip() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let legacy_secret = secret_to_legacy(&secret);
+        assert_eq!(secret_from_legacy(&legacy_secret).unwrap(), secret);
+
+        // Legacy keys are SW keys for the same scalar.
+        let public = secret.public();
+        let legacy_public = public_to_legacy(&public).unwrap();
+        let sw_public =
+            crate::Secret::<BandersnatchSha512Tai>::from_scalar(*secret.scalar()).public();
+        let mut sw_bytes = Vec::new();
+        sw_public.serialize_compressed(&mut sw_bytes).unwrap();
+        assert_eq!(legacy_public.as_slice(), sw_bytes);
+        assert_eq!(public_from_legacy(&legacy_public).unwrap(), public);
+        assert_eq!(public_to_sw(&public), Some(sw_public));
+        assert_eq!(public_from_sw(&sw_public), Some(public));
+
+        assert_eq!(
+            secret_from_legacy(&[0; LEGACY_SECRET_LEN]),
+            Err(Error::NonCanonicalScalar)
+        );
+        assert!(public_from_legacy(&legacy_public[1..]).is_err());
+    }
+}
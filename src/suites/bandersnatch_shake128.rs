@@ -15,7 +15,7 @@
 //!
 //! * The Fiat-Shamir transcript uses SHAKE128 in XOF mode.
 
-use super::{SuiteId, curve, h2c, hash};
+use super::{SuiteId, SuiteInfo, curve, h2c, hash};
 use crate::{pedersen::PedersenSuite, *};
 use ark_ff::MontFp;
 
@@ -25,6 +25,8 @@ pub struct BandersnatchShake128Ell2;
 type ThisSuite = BandersnatchShake128Ell2;
 
 suite_types!(ThisSuite);
+#[cfg(feature = "signature")]
+signature_suite_types!(ThisSuite);
 
 impl Suite for ThisSuite {
     const SUITE_ID: SuiteId = SuiteId::new(1, curve::BANDERSNATCH, hash::SHAKE128, h2c::ELL2);
@@ -35,6 +37,18 @@ impl Suite for ThisSuite {
         let h2c_suite_id = b"Bandersnatch_XOF:SHAKE128_ELL2_RO_";
         utils::hash_to_curve_ell2_xof::<Self, sha3::Shake128>(data, h2c_suite_id)
     }
+
+    fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(Self::generator)
+    }
+}
+
+impl SuiteInfo for ThisSuite {
+    const NAME: &'static str = "bandersnatch-shake128";
+    const DATA_TO_POINT: &'static str = "ell2_rfc_9380";
+    const SUPPORTS_PEDERSEN: bool = true;
+    const SUPPORTS_RING: bool = cfg!(feature = "ring");
 }
 
 impl PedersenSuite for ThisSuite {
@@ -47,6 +61,11 @@ impl PedersenSuite for ThisSuite {
         );
         AffinePoint::new_unchecked(X, Y)
     };
+
+    fn blinding_base_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(|| Self::BLINDING_BASE)
+    }
 }
 
 #[cfg(feature = "ring")]
@@ -76,14 +95,50 @@ impl crate::ring::RingSuite for ThisSuite {
 #[cfg(feature = "ring")]
 ring_suite_types!(ThisSuite);
 
+impl super::SameCurve<super::bandersnatch::BandersnatchSha512Ell2> for ThisSuite {
+    fn map_point(pt: &AffinePoint) -> Option<AffinePoint> {
+        Some(*pt)
+    }
+}
+
+impl super::SameCurve<ThisSuite> for super::bandersnatch::BandersnatchSha512Ell2 {
+    fn map_point(pt: &AffinePoint) -> Option<AffinePoint> {
+        Some(*pt)
+    }
+}
+
+impl super::SameCurve<super::bandersnatch_sw::BandersnatchSha512Tai> for ThisSuite {
+    fn map_point(pt: &AffinePoint) -> Option<super::bandersnatch_sw::AffinePoint> {
+        utils::te_sw_map::te_to_sw(pt)
+    }
+}
+
+impl super::SameCurve<ThisSuite> for super::bandersnatch_sw::BandersnatchSha512Tai {
+    fn map_point(pt: &super::bandersnatch_sw::AffinePoint) -> Option<AffinePoint> {
+        utils::te_sw_map::sw_to_te(pt)
+    }
+}
+
+#[cfg(any(test, feature = "vectors"))]
+impl crate::vectors::SuiteExt for ThisSuite {
+    const SUITE_NAME: &str = "bandersnatch_shake128_ell2";
+}
+
+#[cfg(all(feature = "ring", any(test, feature = "vectors")))]
+impl crate::ring::vectors::RingSuiteExt for ThisSuite {
+    const SRS_FILE: &str = crate::vectors::BLS12_381_PCS_SRS_FILE;
+
+    fn ring_setup() -> &'static RingSetup {
+        use std::sync::OnceLock;
+        static RING_SETUP: OnceLock<RingSetup> = OnceLock::new();
+        RING_SETUP.get_or_init(Self::load_ring_setup)
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
 
-    impl crate::testing::SuiteExt for ThisSuite {
-        const SUITE_NAME: &str = "bandersnatch_shake128_ell2";
-    }
-
     tiny_suite_tests!(ThisSuite);
     pedersen_suite_tests!(ThisSuite);
     thin_suite_tests!(ThisSuite);
@@ -91,17 +146,6 @@ pub(crate) mod tests {
     #[cfg(feature = "ring")]
     ring_suite_tests!(ThisSuite);
 
-    #[cfg(feature = "ring")]
-    impl crate::ring::testing::RingSuiteExt for ThisSuite {
-        const SRS_FILE: &str = crate::testing::BLS12_381_PCS_SRS_FILE;
-
-        fn ring_setup() -> &'static RingSetup {
-            use std::sync::OnceLock;
-            static RING_SETUP: OnceLock<RingSetup> = OnceLock::new();
-            RING_SETUP.get_or_init(Self::load_ring_setup)
-        }
-    }
-
     #[test]
     fn elligator2_hash_to_curve() {
         use crate::testing::CheckPoint;
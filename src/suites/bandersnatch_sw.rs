@@ -46,7 +46,7 @@
 //!   with `h2c_suite_ID_string` = `"Bandersnatch_XMD:SHA-512_TAI_RO_"`
 //!   and domain separation tag `DST = "ECVRF_" || h2c_suite_ID_string || suite_string`.
 
-use super::{SuiteId, curve, h2c, hash};
+use super::{SuiteId, SuiteInfo, curve, h2c, hash};
 use crate::{pedersen::PedersenSuite, utils::te_sw_map::*, *};
 use ark_ff::MontFp;
 
@@ -59,6 +59,17 @@ impl Suite for ThisSuite {
     const SUITE_ID: SuiteId = SuiteId::new(1, curve::BANDERSNATCH_SW, hash::SHA512, h2c::TAI);
     type Affine = ark_ed_on_bls12_381_bandersnatch::SWAffine;
     type Transcript = utils::HashTranscript<sha2::Sha512>;
+
+    fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(Self::generator)
+    }
+}
+
+impl SuiteInfo for ThisSuite {
+    const NAME: &'static str = "bandersnatch-sw";
+    const SUPPORTS_PEDERSEN: bool = true;
+    const SUPPORTS_RING: bool = cfg!(feature = "ring");
 }
 
 impl PedersenSuite for ThisSuite {
@@ -70,9 +81,16 @@ impl PedersenSuite for ThisSuite {
             MontFp!("605975869554501667057064844799976277818323013043881651153113184398732331110");
         AffinePoint::new_unchecked(X, Y)
     };
+
+    fn blinding_base_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(|| Self::BLINDING_BASE)
+    }
 }
 
 suite_types!(ThisSuite);
+#[cfg(feature = "signature")]
+signature_suite_types!(ThisSuite);
 
 #[cfg(feature = "ring")]
 impl crate::ring::RingSuite for ThisSuite {
@@ -115,16 +133,40 @@ impl MapConfig for ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig {
         MontFp!("41180284393978236561320365279764246793818536543197771097409483252169927600582");
 }
 
+impl super::SameCurve<super::bandersnatch::BandersnatchSha512Ell2> for ThisSuite {
+    fn map_point(pt: &AffinePoint) -> Option<super::bandersnatch::AffinePoint> {
+        sw_to_te(pt)
+    }
+}
+
+impl super::SameCurve<ThisSuite> for super::bandersnatch::BandersnatchSha512Ell2 {
+    fn map_point(pt: &super::bandersnatch::AffinePoint) -> Option<AffinePoint> {
+        te_to_sw(pt)
+    }
+}
+
+#[cfg(any(test, feature = "vectors"))]
+impl crate::vectors::SuiteExt for ThisSuite {
+    const SUITE_NAME: &str = "bandersnatch_sw_sha-512_tai";
+}
+
+#[cfg(all(feature = "ring", any(test, feature = "vectors")))]
+impl crate::ring::vectors::RingSuiteExt for ThisSuite {
+    const SRS_FILE: &str = crate::vectors::BLS12_381_PCS_SRS_FILE;
+
+    fn ring_setup() -> &'static RingSetup {
+        use std::sync::OnceLock;
+        static RING_SETUP: OnceLock<RingSetup> = OnceLock::new();
+        RING_SETUP.get_or_init(Self::load_ring_setup)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{testing, tiny_suite_tests};
     use ark_ed_on_bls12_381_bandersnatch::{BandersnatchConfig, SWAffine};
 
-    impl crate::testing::SuiteExt for ThisSuite {
-        const SUITE_NAME: &str = "bandersnatch_sw_sha-512_tai";
-    }
-
     tiny_suite_tests!(ThisSuite);
     pedersen_suite_tests!(ThisSuite);
     thin_suite_tests!(ThisSuite);
@@ -132,17 +174,6 @@ mod tests {
     #[cfg(feature = "ring")]
     ring_suite_tests!(ThisSuite);
 
-    #[cfg(feature = "ring")]
-    impl crate::ring::testing::RingSuiteExt for ThisSuite {
-        const SRS_FILE: &str = crate::testing::BLS12_381_PCS_SRS_FILE;
-
-        fn ring_setup() -> &'static RingSetup {
-            use std::sync::OnceLock;
-            static RING_SETUP: OnceLock<RingSetup> = OnceLock::new();
-            RING_SETUP.get_or_init(Self::load_ring_setup)
-        }
-    }
-
     #[test]
     fn sw_to_te_roundtrip() {
         let roundtrip = |org_point| {
@@ -156,6 +187,31 @@ mod tests {
         roundtrip(AffinePoint::generator());
     }
 
+    #[test]
+    fn sw_to_te_batch_roundtrip() {
+        use ark_ed_on_bls12_381_bandersnatch::EdwardsAffine;
+
+        let rng = &mut ark_std::test_rng();
+        let sw_points: Vec<SWAffine> = (0..10).map(|_| testing::random_val(Some(rng))).collect();
+        let te_points =
+            <EdwardsAffine as SWMapping<BandersnatchConfig>>::from_sw_slice(&sw_points).unwrap();
+        for (sw, te) in sw_points.iter().zip(te_points.iter()) {
+            assert_eq!(sw_to_te::<BandersnatchConfig>(sw).unwrap(), *te);
+        }
+        let sw_back =
+            <SWAffine as TEMapping<BandersnatchConfig>>::from_te_slice(&te_points).unwrap();
+        assert_eq!(sw_points, sw_back.into_owned());
+
+        let mut with_identity = sw_points.clone();
+        with_identity[3] = SWAffine::zero();
+        assert!(sw_to_te_batch::<BandersnatchConfig>(&with_identity).is_none());
+    }
+
+    #[test]
+    fn map_config_constants() {
+        assert!(check_map_config::<BandersnatchConfig>());
+    }
+
     #[test]
     fn identity_point_rejected() {
         use ark_ed_on_bls12_381_bandersnatch::EdwardsAffine;
@@ -0,0 +1,179 @@
+//! # Custom generator
+//!
+//! Suite adapter replacing the generator of an existing suite, for protocols
+//! using per-application generators (e.g. nothing-up-my-sleeve points
+//! obtained by hashing an application tag to the curve).
+//!
+//! [`WithGenerator<S, G>`] delegates everything but [`Suite::generator`] to
+//! `S`, so it supports the same schemes as `S` and keys, proofs and
+//! precomputed tables follow the generator `G` without any further change.
+//!
+//! The suite identifier is the one of `S`: keys and proofs of different
+//! generators are not interchangeable anyway, the generator being bound into
+//! the key and proof relations.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::suites::{bandersnatch::BandersnatchSha512Ell2, custom::*};
+//! use ark_vrf::{AffinePoint, Secret};
+//!
+//! #[derive(Clone, Copy)]
+//! struct AppGenerator;
+//!
+//! impl CustomGenerator<BandersnatchSha512Ell2> for AppGenerator {
+//!     fn generator() -> AffinePoint<BandersnatchSha512Ell2> {
+//!         APP_GENERATOR
+//!     }
+//! }
+//!
+//! type AppSuite = WithGenerator<BandersnatchSha512Ell2, AppGenerator>;
+//!
+//! let secret = Secret::<AppSuite>::from_seed(seed);
+//! ```
+
+use crate::*;
+use core::marker::PhantomData;
+
+/// Generator of a [`WithGenerator`] suite.
+pub trait CustomGenerator<S: Suite> {
+    /// Generator point.
+    ///
+    /// Invoked by every operation involving the generator, so it is expected
+    /// to be cheap (e.g. a constant). The point must be a non-identity element
+    /// of the prime-order subgroup with no known discrete log relation to the
+    /// other suite bases: custom suites should check it with
+    /// [`utils::check_suite`] from their tests.
+    fn generator() -> AffinePoint<S>;
+}
+
+/// Suite `S` with generator `G`.
+pub struct WithGenerator<S, G>(PhantomData<(S, G)>);
+
+impl<S, G> Clone for WithGenerator<S, G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, G> Copy for WithGenerator<S, G> {}
+
+impl<S, G> core::fmt::Debug for WithGenerator<S, G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("WithGenerator")
+    }
+}
+
+impl<S: Suite, G: CustomGenerator<S>> Suite for WithGenerator<S, G> {
+    const SUITE_ID: suites::SuiteId = S::SUITE_ID;
+    const THIN_VRF_DOMAIN: thin::Domain = S::THIN_VRF_DOMAIN;
+    const TAI_FIXED: bool = S::TAI_FIXED;
+
+    type Affine = S::Affine;
+    type Transcript = S::Transcript;
+
+    fn generator() -> AffinePoint<Self> {
+        G::generator()
+    }
+
+    fn nonce(sk: &ScalarField<Self>, transcript: Option<Self::Transcript>) -> ScalarField<Self> {
+        S::nonce(sk, transcript)
+    }
+
+    fn challenge(
+        pts: &[&AffinePoint<Self>],
+        transcript: Option<Self::Transcript>,
+    ) -> ScalarField<Self> {
+        S::challenge(pts, transcript)
+    }
+
+    fn absorb_ad(transcript: &mut Self::Transcript, ad: &[u8]) {
+        S::absorb_ad(transcript, ad)
+    }
+
+    fn data_to_point(data: &[u8]) -> Option<AffinePoint<Self>> {
+        S::data_to_point(data)
+    }
+
+    fn point_to_hash<const N: usize>(pt: &AffinePoint<Self>) -> [u8; N] {
+        S::point_to_hash(pt)
+    }
+}
+
+impl<S: pedersen::PedersenSuite, G: CustomGenerator<S>> pedersen::PedersenSuite
+    for WithGenerator<S, G>
+{
+    const BLINDING_BASE: AffinePoint<Self> = S::BLINDING_BASE;
+
+    fn blinding(secret: &ScalarField<Self>, transcript: Self::Transcript) -> ScalarField<Self> {
+        S::blinding(secret, transcript)
+    }
+
+    fn blinding_with_context(
+        secret: &ScalarField<Self>,
+        transcript: Self::Transcript,
+        context: &[u8],
+    ) -> ScalarField<Self> {
+        S::blinding_with_context(secret, transcript, context)
+    }
+}
+
+impl<S: suites::SuiteInfo, G: CustomGenerator<S>> suites::SuiteInfo for WithGenerator<S, G> {
+    const NAME: &'static str = S::NAME;
+    const DATA_TO_POINT: &'static str = S::DATA_TO_POINT;
+    const POINT_TO_HASH: &'static str = S::POINT_TO_HASH;
+    const CHALLENGE: &'static str = S::CHALLENGE;
+    const NONCE: &'static str = S::NONCE;
+    const SUPPORTS_PEDERSEN: bool = S::SUPPORTS_PEDERSEN;
+    const SUPPORTS_RING: bool = S::SUPPORTS_RING;
+}
+
+#[cfg(feature = "ring")]
+impl<S: ring::RingSuite, G: CustomGenerator<S>> ring::RingSuite for WithGenerator<S, G> {
+    type Pairing = S::Pairing;
+
+    const ACCUMULATOR_BASE: AffinePoint<Self> = S::ACCUMULATOR_BASE;
+
+    const PADDING: AffinePoint<Self> = S::PADDING;
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2;
+
+    #[derive(Clone, Copy)]
+    struct TestGenerator;
+
+    impl CustomGenerator<BandersnatchSha512Ell2> for TestGenerator {
+        fn generator() -> AffinePoint<BandersnatchSha512Ell2> {
+            BandersnatchSha512Ell2::data_to_point(b"custom generator").unwrap()
+        }
+    }
+
+    type S = WithGenerator<BandersnatchSha512Ell2, TestGenerator>;
+
+    #[test]
+    fn custom_generator() {
+        use pedersen::{Prover as _, Verifier as _};
+        assert_eq!(utils::check_suite::<S>(), Ok(()));
+
+        let secret = Secret::<S>::from_seed([7; 32]);
+        let base = Secret::<BandersnatchSha512Ell2>::from_seed([7; 32]);
+        let public = (TestGenerator::generator() * secret.scalar).into_affine();
+        assert_eq!(secret.public().0, public);
+        assert_ne!(secret.public().0, base.public().0);
+
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = tiny::Prover::prove(&secret, io, b"bar");
+        assert!(tiny::Verifier::verify(&secret.public(), io, b"bar", &proof).is_ok());
+
+        let (proof, _) = secret.prove(io, b"bar");
+        assert!(Public::<S>::verify(io, b"bar", &proof).is_ok());
+
+        // Base suite key with the same secret scalar.
+        let public = Public::<S>(base.public().0);
+        let proof = tiny::Prover::prove(&secret, io, b"bar");
+        assert!(tiny::Verifier::verify(&public, io, b"bar", &proof).is_err());
+    }
+}
@@ -11,7 +11,9 @@
 //! *  `cLen` = 16.
 //!
 //! *  The secret key and generation of the secret scalar and the public
-//!    key are specified in Section 5.1.5 of `[RFC8032]`.
+//!    key are specified in Section 5.1.5 of `[RFC8032]` (see
+//!    [`secret_from_rfc_8032_seed`]). Note that [`Secret::from_seed`]
+//!    derives a different scalar from the same seed.
 //!
 //! *  Nonce generation is inspired by Section 5.4.2.2 of RFC-9381,
 //!    adapted to use the suite's pluggable transcript.
@@ -37,8 +39,11 @@
 //!
 //! *  The ECVRF_encode_to_curve function uses Try-And-Increment, inspired
 //!    by Section 5.4.1.1 of RFC-9381.
+//!
+//! *  Thin VRF proofs can be verified with the cofactored equation, as
+//!    RFC-9381 permits for this curve (see [`thin::VerifyPolicy`]).
 
-use super::{SuiteId, curve, h2c, hash};
+use super::{SuiteId, SuiteInfo, curve, h2c, hash};
 use crate::{pedersen::PedersenSuite, *};
 use ark_ff::MontFp;
 
@@ -52,6 +57,16 @@ impl Suite for ThisSuite {
     const SUITE_ID: SuiteId = SuiteId::new(1, curve::ED25519, hash::SHA512, h2c::TAI);
     type Affine = ark_ed25519::EdwardsAffine;
     type Transcript = utils::HashTranscript;
+
+    fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(Self::generator)
+    }
+}
+
+impl SuiteInfo for ThisSuite {
+    const NAME: &'static str = "ed25519";
+    const SUPPORTS_PEDERSEN: bool = true;
 }
 
 impl PedersenSuite for ThisSuite {
@@ -63,19 +78,68 @@ impl PedersenSuite for ThisSuite {
             MontFp!("8628250443818480863934028036369439777606731830107058507107120454741634818992");
         AffinePoint::new_unchecked(X, Y)
     };
+
+    fn blinding_base_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(|| Self::BLINDING_BASE)
+    }
 }
 
 suite_types!(ThisSuite);
+#[cfg(feature = "signature")]
+signature_suite_types!(ThisSuite);
+
+/// Construct a secret from an RFC-8032 ed25519 secret key.
+///
+/// The secret scalar is the clamped lower half of the SHA-512 hash of `seed`
+/// (RFC-8032 section 5.1.5), reduced modulo the group order. The public key
+/// is then the one of the ed25519 key pair with the same secret key, and the
+/// VRF keys are the ones derived by RFC-9381 ECVRF-EDWARDS25519-SHA512
+/// implementations.
+///
+/// Unlike [`Secret::from_seed`], which hashes the seed with the suite
+/// transcript and doesn't clamp.
+#[cfg(feature = "prove")]
+pub fn secret_from_rfc_8032_seed(seed: [u8; 32]) -> Secret {
+    use digest::Digest;
+    use zeroize::Zeroize;
+
+    let mut hash = sha2::Sha512::digest(seed);
+    let mut scalar = [0; 32];
+    scalar.copy_from_slice(&hash[..32]);
+    scalar[0] &= 248;
+    scalar[31] &= 127;
+    scalar[31] |= 64;
+    // Clamped scalars are non-zero multiples of 8 lower than 8 times the
+    // group order, thus never reduce to zero.
+    let secret = Secret::from_scalar(ScalarField::from_le_bytes_mod_order(&scalar));
+    hash.as_mut_slice().zeroize();
+    scalar.zeroize();
+    secret
+}
+
+#[cfg(any(test, feature = "vectors"))]
+impl crate::vectors::SuiteExt for ThisSuite {
+    const SUITE_NAME: &str = "ed25519_sha-512_tai";
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    impl crate::testing::SuiteExt for ThisSuite {
-        const SUITE_NAME: &str = "ed25519_sha-512_tai";
-    }
-
     tiny_suite_tests!(ThisSuite);
     pedersen_suite_tests!(ThisSuite);
     thin_suite_tests!(ThisSuite);
+
+    #[test]
+    fn rfc_9381_key_pairs() {
+        use crate::vectors::key_derivation;
+        let path =
+            crate::testing::VECTORS_DIR.to_string() + "/rfc-9381/ecvrf_edwards25519_sha512.txt";
+        let vectors = key_derivation::parse(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(vectors.len(), 3);
+        for vector in &vectors {
+            assert_eq!(key_derivation::check_ed25519_key_pair(vector), Ok(()));
+        }
+    }
 }
@@ -21,6 +21,9 @@
 //! - **Baby-JubJub**: Edwards curve defined over the BN254 scalar field with
 //!   SHA-512 hash function. Supports Tiny, Thin, Pedersen, and Ring VRF schemes.
 //!   Optimized for Ethereum compatibility.
+//!
+//! Any suite can be used with a different generator through the
+//! [`custom::WithGenerator`] adapter.
 
 /// Suite identifier.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -48,6 +51,10 @@ impl SuiteId {
     pub const fn to_bytes(&self) -> [u8; 4] {
         [self.version, self.curve, self.hash, self.h2c]
     }
+
+    pub const fn from_bytes(bytes: [u8; 4]) -> Self {
+        Self::new(bytes[0], bytes[1], bytes[2], bytes[3])
+    }
 }
 
 impl core::fmt::LowerHex for SuiteId {
@@ -84,9 +91,131 @@ pub mod h2c {
     pub const TAI: u8 = 0x02;
 }
 
+/// Human readable suite metadata.
+///
+/// Describes the methods chosen by the suite for the [`Suite`](crate::Suite)
+/// procedures, for tools displaying or comparing suites at runtime. Methods
+/// default to the ones of the default procedures.
+pub trait SuiteInfo: crate::Suite {
+    /// Suite name.
+    const NAME: &'static str;
+    /// [`Suite::data_to_point`](crate::Suite::data_to_point) method.
+    const DATA_TO_POINT: &'static str = "tai_rfc_9381";
+    /// [`Suite::point_to_hash`](crate::Suite::point_to_hash) method.
+    const POINT_TO_HASH: &'static str = "rfc_9381";
+    /// [`Suite::challenge`](crate::Suite::challenge) method.
+    const CHALLENGE: &'static str = "rfc_9381";
+    /// [`Suite::nonce`](crate::Suite::nonce) method.
+    const NONCE: &'static str = "rfc_8032";
+    /// The suite implements [`PedersenSuite`](crate::pedersen::PedersenSuite).
+    const SUPPORTS_PEDERSEN: bool = false;
+    /// The suite implements `ring::RingSuite` and the `ring` feature is enabled.
+    const SUPPORTS_RING: bool = false;
+}
+
+/// Marker of suites sharing the curve and generator of the suite `T`.
+///
+/// The two suites may differ in the hash function, the hash-to-curve method
+/// or the curve model (e.g. Twisted Edwards and Short Weierstrass forms of
+/// Bandersnatch), but a secret scalar yields the same public key point in
+/// both. Keys thus convert between the suites with [`Secret::to_suite`] and
+/// [`Public::to_suite`], rather than by reinterpreting the scalar.
+///
+/// Converted keys are the same key, not independent ones:
+/// - Every suite hash is domain separated by the suite identifier, so VRF
+///   inputs, outputs, nonces and proofs of the two suites are unrelated.
+///   A proof of one suite never verifies in the other, and the same secret
+///   never reuses a nonce across suites.
+/// - The public key identifies the same owner in both suites, so the
+///   activity in the two suites is linkable, and the compromise of the key
+///   in one suite compromises it in the other.
+///
+/// Suites with a different generator (e.g. [`custom::WithGenerator`]) are
+/// not marked: the same scalar yields unrelated public keys.
+///
+/// [`Secret::to_suite`]: crate::Secret::to_suite
+/// [`Public::to_suite`]: crate::Public::to_suite
+pub trait SameCurve<T: crate::Suite>:
+    crate::Suite<Affine: ark_ec::AffineRepr<ScalarField = crate::ScalarField<T>>>
+{
+    /// Map a point to the curve model of `T`.
+    ///
+    /// Returns `None` for points without image (e.g. the identity when
+    /// mapping to a Short Weierstrass model).
+    fn map_point(pt: &crate::AffinePoint<Self>) -> Option<crate::AffinePoint<T>>;
+}
+
+/// Schemes supported by a suite.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Tiny VRF (see [`crate::tiny`]).
+    pub tiny: bool,
+    /// Thin VRF (see [`crate::thin`]).
+    pub thin: bool,
+    /// Pedersen VRF (see [`crate::pedersen`]).
+    pub pedersen: bool,
+    /// Ring VRF, only if the `ring` feature is enabled.
+    pub ring: bool,
+}
+
+/// Built-in suite descriptor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SuiteDescriptor {
+    /// Suite name (see [`SuiteInfo::NAME`]).
+    pub name: &'static str,
+    /// Suite identifier.
+    pub id: SuiteId,
+    /// Supported schemes.
+    pub capabilities: Capabilities,
+}
+
+impl SuiteDescriptor {
+    /// Descriptor of the suite `S`.
+    pub const fn new<S: SuiteInfo>() -> Self {
+        Self {
+            name: S::NAME,
+            id: S::SUITE_ID,
+            capabilities: Capabilities {
+                tiny: true,
+                thin: true,
+                pedersen: S::SUPPORTS_PEDERSEN,
+                ring: S::SUPPORTS_RING,
+            },
+        }
+    }
+}
+
+const AVAILABLE: &[SuiteDescriptor] = &[
+    #[cfg(feature = "ed25519")]
+    SuiteDescriptor::new::<ed25519::Ed25519Sha512Tai>(),
+    #[cfg(feature = "secp256r1")]
+    SuiteDescriptor::new::<secp256r1::Secp256r1Sha256Tai>(),
+    #[cfg(feature = "bandersnatch")]
+    SuiteDescriptor::new::<bandersnatch::BandersnatchSha512Ell2>(),
+    #[cfg(all(feature = "bandersnatch", feature = "shake128"))]
+    SuiteDescriptor::new::<bandersnatch_shake128::BandersnatchShake128Ell2>(),
+    #[cfg(feature = "bandersnatch")]
+    SuiteDescriptor::new::<bandersnatch_sw::BandersnatchSha512Tai>(),
+    #[cfg(feature = "jubjub")]
+    SuiteDescriptor::new::<jubjub::JubJubSha512Ell2>(),
+    #[cfg(feature = "baby-jubjub")]
+    SuiteDescriptor::new::<baby_jubjub::BabyJubJubSha512Ell2>(),
+];
+
+/// Descriptors of the built-in suites enabled by features.
+///
+/// Peers can exchange the identifiers of their available suites to agree on
+/// a common one at runtime.
+pub fn available() -> &'static [SuiteDescriptor] {
+    AVAILABLE
+}
+
 #[cfg(test)]
 pub(crate) mod testing;
 
+pub mod custom;
+pub mod router;
+
 #[cfg(feature = "ed25519")]
 pub mod ed25519;
 
@@ -95,6 +224,10 @@ pub mod secp256r1;
 
 #[cfg(feature = "bandersnatch")]
 pub mod bandersnatch;
+#[cfg(feature = "bandersnatch")]
+pub mod bandersnatch_host;
+#[cfg(feature = "bandersnatch")]
+pub mod bandersnatch_legacy;
 #[cfg(all(feature = "bandersnatch", feature = "shake128"))]
 pub mod bandersnatch_shake128;
 #[cfg(feature = "bandersnatch")]
@@ -105,3 +238,61 @@ pub mod jubjub;
 
 #[cfg(feature = "baby-jubjub")]
 pub mod baby_jubjub;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn available_suites() {
+        let suites = available();
+        for (i, a) in suites.iter().enumerate() {
+            for b in &suites[i + 1..] {
+                assert_ne!(a.id, b.id);
+                assert_ne!(a.name, b.name);
+            }
+        }
+        #[cfg(feature = "bandersnatch")]
+        {
+            use crate::Suite;
+            let id = bandersnatch::BandersnatchSha512Ell2::SUITE_ID;
+            let suite = suites.iter().find(|d| d.id == id).unwrap();
+            assert_eq!(suite.name, "bandersnatch");
+            assert!(suite.capabilities.pedersen);
+            assert_eq!(suite.capabilities.ring, cfg!(feature = "ring"));
+        }
+    }
+
+    #[cfg(feature = "bandersnatch")]
+    #[test]
+    fn same_curve_conversions() {
+        use crate::{Input, Secret, Suite};
+        use bandersnatch::BandersnatchSha512Ell2 as Te;
+        use bandersnatch_sw::BandersnatchSha512Tai as Sw;
+
+        fn check<S: SameCurve<T>, T: Suite>() {
+            assert_eq!(S::map_point(&S::generator()), Some(T::generator()));
+            let secret = Secret::<S>::from_seed([7; 32]);
+            let converted = secret.to_suite::<T>();
+            assert_eq!(converted.scalar(), secret.scalar());
+            assert_eq!(
+                secret.public().to_suite::<T>().unwrap().0,
+                converted.public().0
+            );
+            // Unrelated outputs for the same data.
+            let output = secret.output(Input::new(b"foo").unwrap());
+            let other = converted.output(Input::new(b"foo").unwrap());
+            assert_ne!(output.hash::<32>(), other.hash::<32>());
+        }
+        check::<Te, Sw>();
+        check::<Sw, Te>();
+        #[cfg(feature = "shake128")]
+        {
+            use bandersnatch_shake128::BandersnatchShake128Ell2 as Shake;
+            check::<Te, Shake>();
+            check::<Shake, Te>();
+            check::<Sw, Shake>();
+            check::<Shake, Sw>();
+        }
+    }
+}
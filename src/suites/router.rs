@@ -0,0 +1,164 @@
+//! # Verification router
+//!
+//! Single verification entry point over the built-in suites enabled by
+//! features and the Tiny, Thin and Pedersen schemes, for services receiving
+//! proofs of different suites and schemes (e.g. gateways) as bytes.
+//!
+//! The suite is selected by its [`SuiteId`] and the scheme by its
+//! [`Scheme`] tag. All the values use the suite compressed encoding and must
+//! be canonical. Ring proofs are not routed, as they also require the ring
+//! parameters.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::suites::{SuiteId, router};
+//!
+//! let suite_id = SuiteId::from_bytes(msg.suite_id);
+//! let hash = router::verify_any(suite_id, msg.scheme, &msg.public, &msg.input,
+//!     &msg.output, &msg.ad, &msg.proof)?;
+//! ```
+
+use super::SuiteId;
+use crate::pedersen::PedersenSuite;
+use crate::remote::Scheme;
+use crate::utils::decode_canonical;
+use crate::*;
+
+/// VRF output hash returned by [`verify_any`].
+pub type OutputBytes = [u8; 32];
+
+type VerifyFn = fn(Scheme, &[u8], &[u8], &[u8], &[u8], &[u8]) -> Result<OutputBytes, Error>;
+
+const ROUTES: &[(SuiteId, VerifyFn)] = &[
+    #[cfg(feature = "ed25519")]
+    route::<super::ed25519::Ed25519Sha512Tai>(),
+    #[cfg(feature = "secp256r1")]
+    route::<super::secp256r1::Secp256r1Sha256Tai>(),
+    #[cfg(feature = "bandersnatch")]
+    route::<super::bandersnatch::BandersnatchSha512Ell2>(),
+    #[cfg(all(feature = "bandersnatch", feature = "shake128"))]
+    route::<super::bandersnatch_shake128::BandersnatchShake128Ell2>(),
+    #[cfg(feature = "bandersnatch")]
+    route::<super::bandersnatch_sw::BandersnatchSha512Tai>(),
+    #[cfg(feature = "jubjub")]
+    route::<super::jubjub::JubJubSha512Ell2>(),
+    #[cfg(feature = "baby-jubjub")]
+    route::<super::baby_jubjub::BabyJubJubSha512Ell2>(),
+];
+
+// Unused without built-in suites.
+#[allow(dead_code)]
+const fn route<S: PedersenSuite>() -> (SuiteId, VerifyFn) {
+    (S::SUITE_ID, verify::<S>)
+}
+
+/// Verify a proof of the suite `suite_id` and scheme `scheme_tag`.
+///
+/// - `public`: encoded public key, ignored by Pedersen proofs which only
+///   carry a blinded one.
+/// - `input`: VRF input data, hashed to curve with [`Input::new`].
+/// - `output`: encoded VRF output point.
+/// - `ad`: additional data.
+/// - `proof`: encoded proof.
+///
+/// Returns the VRF output hash if the proof is valid.
+///
+/// Returns `Error::UnsupportedSuite` if the suite is not a built-in suite
+/// enabled by features, `Error::InvalidData` if the scheme tag is unknown,
+/// or the first decoding or verification error.
+pub fn verify_any(
+    suite_id: SuiteId,
+    scheme_tag: u8,
+    public: &[u8],
+    input: &[u8],
+    output: &[u8],
+    ad: &[u8],
+    proof: &[u8],
+) -> Result<OutputBytes, Error> {
+    let (_, verify) = ROUTES
+        .iter()
+        .find(|(id, _)| *id == suite_id)
+        .ok_or(Error::UnsupportedSuite)?;
+    let scheme = Scheme::try_from(scheme_tag)?;
+    verify(scheme, public, input, output, ad, proof)
+}
+
+#[allow(dead_code)]
+fn verify<S: PedersenSuite>(
+    scheme: Scheme,
+    public: &[u8],
+    input: &[u8],
+    output: &[u8],
+    ad: &[u8],
+    proof: &[u8],
+) -> Result<OutputBytes, Error> {
+    let io = VrfIo {
+        input: Input::<S>::new(input).ok_or(Error::HashToCurveFailure)?,
+        output: decode_canonical::<Output<S>>(output)?,
+    };
+    match scheme {
+        Scheme::Tiny => {
+            let public = decode_canonical::<Public<S>>(public)?;
+            let proof = decode_canonical::<tiny::Proof<S>>(proof)?;
+            tiny::Verifier::verify(&public, io, ad, &proof)?
+        }
+        Scheme::Thin => {
+            let public = decode_canonical::<Public<S>>(public)?;
+            let proof = decode_canonical::<thin::Proof<S>>(proof)?;
+            thin::Verifier::verify(&public, io, ad, &proof)?
+        }
+        Scheme::Pedersen => {
+            let proof = decode_canonical::<pedersen::Proof<S>>(proof)?;
+            <Public<S> as pedersen::Verifier<S>>::verify(io, ad, &proof)?
+        }
+    }
+    Ok(io.output.hash())
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+    use crate::suites::bandersnatch_sw::BandersnatchSha512Tai as Sw;
+    use utils::common::encode_compressed as encode;
+
+    #[test]
+    fn verify_any_routes() {
+        let secret = Secret::<S>::from_seed([0; 32]);
+        let public = encode(&secret.public());
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let output = encode(&io.output);
+        let hash = io.output.hash();
+
+        let proofs = [
+            (
+                Scheme::Tiny,
+                encode(&tiny::Prover::prove(&secret, io, b"ad")),
+            ),
+            (
+                Scheme::Thin,
+                encode(&thin::Prover::prove(&secret, io, b"ad")),
+            ),
+            (
+                Scheme::Pedersen,
+                encode(&pedersen::Prover::prove(&secret, io, b"ad").0),
+            ),
+        ];
+        for (scheme, proof) in &proofs {
+            let verify = |suite_id, ad: &[u8]| {
+                verify_any(suite_id, *scheme as u8, &public, b"foo", &output, ad, proof)
+            };
+            assert_eq!(verify(S::SUITE_ID, b"ad"), Ok(hash));
+            assert_eq!(verify(S::SUITE_ID, b"bar"), Err(Error::VerificationFailure));
+            assert!(verify(Sw::SUITE_ID, b"ad").is_err());
+        }
+
+        let (_, proof) = &proofs[0];
+        let unknown = SuiteId::new(1, 0x7F, 0, 0);
+        let res = verify_any(unknown, 0, &public, b"foo", &output, b"ad", proof);
+        assert_eq!(res, Err(Error::UnsupportedSuite));
+        let res = verify_any(S::SUITE_ID, 3, &public, b"foo", &output, b"ad", proof);
+        assert_eq!(res, Err(Error::InvalidData));
+    }
+}
@@ -29,7 +29,8 @@
 //! *  The point_to_string function converts a point on E to an octet
 //!    string according to the encoding specified in Section 2.3.3 of
 //!    SECG1 with point compression on.  This implies that
-//!    ptLen = fLen + 1 = 33.
+//!    ptLen = fLen + 1 = 33.  The crate types use the arkworks compressed
+//!    encoding: proofs are converted with [`utils::sec1`].
 //!
 //! *  The string_to_point function converts an octet string to a point
 //!    on E according to the encoding specified in Section 2.3.4 of
@@ -42,7 +43,7 @@
 //! *  The ECVRF_encode_to_curve function uses Try-And-Increment, inspired
 //!    by Section 5.4.1.1 of RFC-9381.
 
-use super::{SuiteId, curve, h2c, hash};
+use super::{SuiteId, SuiteInfo, curve, h2c, hash};
 use crate::{pedersen::PedersenSuite, *};
 use ark_ff::MontFp;
 
@@ -55,6 +56,16 @@ impl Suite for ThisSuite {
     const SUITE_ID: SuiteId = SuiteId::new(1, curve::SECP256R1, hash::SHA256, h2c::TAI);
     type Affine = ark_secp256r1::Affine;
     type Transcript = utils::HashTranscript<sha2::Sha256>;
+
+    fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(Self::generator)
+    }
+}
+
+impl SuiteInfo for ThisSuite {
+    const NAME: &'static str = "secp256r1";
+    const SUPPORTS_PEDERSEN: bool = true;
 }
 
 impl PedersenSuite for ThisSuite {
@@ -67,20 +78,65 @@ impl PedersenSuite for ThisSuite {
         );
         AffinePoint::new_unchecked(X, Y)
     };
+
+    fn blinding_base_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<ThisSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(|| Self::BLINDING_BASE)
+    }
 }
 
 suite_types!(ThisSuite);
+#[cfg(feature = "signature")]
+signature_suite_types!(ThisSuite);
+
+#[cfg(any(test, feature = "vectors"))]
+impl crate::vectors::SuiteExt for ThisSuite {
+    const SUITE_NAME: &str = "secp256r1_sha-256_tai";
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::testing::SuiteExt;
-
-    impl SuiteExt for ThisSuite {
-        const SUITE_NAME: &str = "secp256r1_sha-256_tai";
-    }
 
     tiny_suite_tests!(ThisSuite);
     pedersen_suite_tests!(ThisSuite);
     thin_suite_tests!(ThisSuite);
+
+    #[test]
+    fn rfc_9381_key_pairs() {
+        use crate::vectors::key_derivation;
+        let path = crate::testing::VECTORS_DIR.to_string() + "/rfc-9381/ecvrf_p256_sha256.txt";
+        let vectors = key_derivation::parse(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(vectors.len(), 2);
+        for vector in &vectors {
+            assert_eq!(key_derivation::check_p256_key_pair(vector), Ok(()));
+        }
+    }
+
+    /// RFC-6979 appendix A.2.5 (P-256, SHA-256) nonces.
+    #[test]
+    fn nonce_rfc_6979_vectors() {
+        use ark_ff::PrimeField;
+        use digest::Digest;
+
+        let sk = hex::decode("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721")
+            .unwrap();
+        let sk = ScalarField::from_be_bytes_mod_order(&sk);
+        let vectors = [
+            (
+                b"sample".as_slice(),
+                "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+            ),
+            (
+                b"test".as_slice(),
+                "D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0",
+            ),
+        ];
+        for (msg, k) in vectors {
+            let h1 = sha2::Sha256::digest(msg);
+            let nonce = utils::nonce_rfc_6979_with_hash::<ThisSuite, sha2::Sha256>(&sk, &h1);
+            let expected = ScalarField::from_be_bytes_mod_order(&hex::decode(k).unwrap());
+            assert_eq!(nonce, expected);
+        }
+    }
 }
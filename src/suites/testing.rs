@@ -11,6 +11,11 @@ impl Suite for TestSuite {
     const SUITE_ID: SuiteId = SuiteId::new(1, curve::TESTING, hash::SHA256, h2c::TAI);
     type Affine = ark_ed25519::EdwardsAffine;
     type Transcript = utils::HashTranscript<sha2::Sha256>;
+
+    fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<TestSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(Self::generator)
+    }
 }
 
 impl PedersenSuite for TestSuite {
@@ -22,11 +27,18 @@ impl PedersenSuite for TestSuite {
             MontFp!("2426300771129523663036212467424815004619017977680480195630888849825854203381");
         AffinePoint::new_unchecked(X, Y)
     };
+
+    fn blinding_base_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+        static TABLE: utils::StaticTable<TestSuite> = utils::StaticTable::new();
+        TABLE.get_or_init(|| Self::BLINDING_BASE)
+    }
 }
 
 suite_types!(TestSuite);
+#[cfg(feature = "signature")]
+signature_suite_types!(TestSuite);
 
-impl crate::testing::SuiteExt for TestSuite {
+impl crate::vectors::SuiteExt for TestSuite {
     const SUITE_NAME: &str = "testing_sha-256_tai";
 }
 
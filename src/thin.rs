@@ -21,6 +21,43 @@
 //! // Verification
 //! let result = public.verify(io, b"aux data", &proof);
 //! ```
+//!
+//! ## Transcript
+//!
+//! Thin VRF is the batch-compatible variant of the RFC-9381 ECVRF offered by
+//! this crate. Interoperating implementations must build the proof transcript
+//! byte for byte as follows, where all the points and scalars are absorbed
+//! with their compressed little-endian encodings and the tags are the
+//! [`dst`](utils::dst) registry values:
+//!
+//! 1. The 4 bytes of the [`SuiteId`](suites::SuiteId).
+//! 2. The [`Domain::scheme`] tag.
+//! 3. The number of I/O pairs `n + 1` (little-endian `u32`), followed by the
+//!    pairs `(G, Y)`, `(I_1, O_1)`, ..., `(I_n, O_n)`, each as input then
+//!    output point.
+//! 4. The additional data length (little-endian `u32`), followed by the data.
+//!
+//! Steps 3 and 4 are swapped with the [`Order::AdFirst`] [`Domain::order`].
+//!
+//! The delinearization scalars are squeezed (128 bits each, reduced modulo
+//! the group order) from a fork of this transcript absorbing the
+//! [`Domain::delinearize`] tag, the first scalar being fixed to one. The
+//! challenge is squeezed the same way from the transcript after absorbing
+//! the [`CHALLENGE`](utils::dst::CHALLENGE) tag and `R`.
+//!
+//! The [`Domain`] tags default to [`THIN_VRF`](utils::dst::THIN_VRF) and
+//! [`DELINEARIZE`](utils::dst::DELINEARIZE), with [`Order::IosFirst`], and
+//! are set per suite through [`Suite::THIN_VRF_DOMAIN`]. The challenge tag is overridden through
+//! [`Suite::challenge`] (see [`utils::challenge_with_tag`]).
+//!
+//! The additional data absorption, challenge and nonce procedures are suite
+//! knobs (see [`Suite::absorb_ad`], [`Suite::challenge`] and
+//! [`Suite::nonce`]), the nonce never affecting verification.
+//!
+//! The instance implemented for a suite is named by its [`SchemeId`]: suite
+//! identifier, construction [`VERSION`] and transcript domain. The vectors in
+//! `data/vectors/*_thin.json` pin the construction for each suite, carrying
+//! the encoded scheme identifier in their `scheme_id` field.
 
 use crate::{utils::challenge_scalar, utils::common::DomSep, utils::straus::short_msm, *};
 
@@ -31,6 +68,116 @@ pub trait ThinVrfSuite: Suite {}
 
 impl<T> ThinVrfSuite for T where T: Suite {}
 
+/// Version of the Thin VRF construction.
+///
+/// Bumped on any change of the proof transcript or verification equation.
+pub const VERSION: u8 = 1;
+
+/// Thin VRF transcript domain: separation tags and absorption order.
+///
+/// Set per suite through [`Suite::THIN_VRF_DOMAIN`]. Custom tags should be
+/// taken from the [`suite_tag`](utils::dst::suite_tag) range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Domain {
+    /// Scheme tag, absorbed right after the suite identifier.
+    pub scheme: u8,
+    /// Delinearization scalars tag.
+    pub delinearize: u8,
+    /// Order of the I/O pairs and additional data.
+    pub order: Order,
+}
+
+impl Domain {
+    /// Crate tags: [`THIN_VRF`](utils::dst::THIN_VRF) and
+    /// [`DELINEARIZE`](utils::dst::DELINEARIZE), I/O pairs first.
+    pub const DEFAULT: Self = Self {
+        scheme: utils::dst::THIN_VRF,
+        delinearize: utils::dst::DELINEARIZE,
+        order: Order::IosFirst,
+    };
+}
+
+impl From<Domain> for utils::common::SchemeTags {
+    fn from(domain: Domain) -> Self {
+        Self {
+            scheme: domain.scheme,
+            delinearize: domain.delinearize,
+            ad_first: domain.order == Order::AdFirst,
+        }
+    }
+}
+
+/// Order of the I/O pairs and additional data in the Thin VRF transcript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Order {
+    /// I/O pairs, then additional data.
+    IosFirst = 0,
+    /// Additional data, then I/O pairs.
+    AdFirst = 1,
+}
+
+/// Thin VRF scheme identifier.
+///
+/// Names the concrete Thin VRF instance of a suite: the suite identifier,
+/// the construction [`VERSION`] and the transcript [`Domain`]. Encoded as
+/// the 4 suite identifier bytes, the version byte, the scheme and
+/// delinearization tags, then the [`Order`] byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemeId {
+    /// Suite identifier.
+    pub suite: suites::SuiteId,
+    /// Construction version.
+    pub version: u8,
+    /// Transcript domain.
+    pub domain: Domain,
+}
+
+impl SchemeId {
+    /// Encoded length in bytes.
+    pub const LEN: usize = 8;
+
+    /// Scheme identifier of the suite `S`.
+    pub const fn of<S: ThinVrfSuite>() -> Self {
+        Self {
+            suite: S::SUITE_ID,
+            version: VERSION,
+            domain: S::THIN_VRF_DOMAIN,
+        }
+    }
+
+    pub const fn to_bytes(&self) -> [u8; Self::LEN] {
+        let [v, c, h, m] = self.suite.to_bytes();
+        let Domain {
+            scheme,
+            delinearize,
+            order,
+        } = self.domain;
+        [v, c, h, m, self.version, scheme, delinearize, order as u8]
+    }
+
+    /// Decode a scheme identifier.
+    ///
+    /// Returns `None` if the order byte is not an [`Order`].
+    pub const fn from_bytes(bytes: [u8; Self::LEN]) -> Option<Self> {
+        let [v, c, h, m, version, scheme, delinearize, order] = bytes;
+        let order = match order {
+            0 => Order::IosFirst,
+            1 => Order::AdFirst,
+            _ => return None,
+        };
+        Some(Self {
+            suite: suites::SuiteId::new(v, c, h, m),
+            version,
+            domain: Domain {
+                scheme,
+                delinearize,
+                order,
+            },
+        })
+    }
+}
+
 /// Thin VRF proof.
 ///
 /// Schnorr-like proof over the delinearized merged DLEQ relation:
@@ -39,7 +186,7 @@ impl<T> ThinVrfSuite for T where T: Suite {}
 ///
 /// Deserialization via [`CanonicalDeserialize`] includes subgroup checks for
 /// curve points, so deserialized proofs are guaranteed to contain valid points.
-#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Proof<S: ThinVrfSuite> {
     /// Nonce commitment on the merged input.
     pub r: AffinePoint<S>,
@@ -47,13 +194,117 @@ pub struct Proof<S: ThinVrfSuite> {
     pub s: ScalarField<S>,
 }
 
+impl<S: ThinVrfSuite> core::fmt::Debug for Proof<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use utils::common::{Hex, encode_compressed};
+        f.debug_struct("Proof")
+            .field("suite", &format_args!("{:08x}", S::SUITE_ID))
+            .field("r", &Hex(&encode_compressed(&self.r)))
+            .field("s", &Hex(&encode_compressed(&self.s)))
+            .finish()
+    }
+}
+
+impl<S: ThinVrfSuite> core::fmt::Display for Proof<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        utils::common::fmt_proof::<S>(self, f)
+    }
+}
+
+impl<S: ThinVrfSuite> Proof<S> {
+    /// Check that the proof has a unique canonical encoding.
+    ///
+    /// Scalars are always reduced, so this checks that `r` is a valid
+    /// prime-order subgroup point (the only values the decoder accepts).
+    /// Verifiers requiring a unique byte representation per accepted proof
+    /// should call this on proofs not obtained through
+    /// [`decode_canonical`](utils::decode_canonical).
+    pub fn check_canonical(&self) -> Result<(), Error> {
+        utils::common::check_point::<S>(&self.r)
+    }
+
+    /// Returns `true` if [`Self::check_canonical`] succeeds.
+    pub fn is_canonical(&self) -> bool {
+        self.check_canonical().is_ok()
+    }
+
+    /// Compare with the proof of `secret` for the same I/O pairs and additional data.
+    ///
+    /// Meant for debugging proofs produced by other implementations. The
+    /// proof challenge is the one implied by the response for the expected
+    /// nonce `k`, i.e. `(s - k) / x`.
+    #[cfg(feature = "prove")]
+    pub fn recompute_and_compare(
+        &self,
+        secret: &Secret<S>,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+    ) -> utils::ProofDiff {
+        let (ios, ad) = (ios.as_ref(), ad.as_ref());
+        let (t, _) = vrf_transcript::<S>(secret.public.0, ios, ad);
+        let k = S::nonce(&secret.scalar, Some(t.clone()));
+        let proof = secret.prove(ios, ad);
+        let c = S::challenge(&[&proof.r], Some(t));
+        utils::ProofDiff {
+            nonce: self.r != proof.r,
+            challenge: self.s - k != c * secret.scalar,
+            response: self.s != proof.s,
+        }
+    }
+
+    /// Verify the proof under the given verification equation `policy`.
+    ///
+    /// Same as [`Verifier::verify`] with [`VerifyPolicy::Cofactorless`].
+    pub fn verify_with_policy(
+        &self,
+        public: &Public<S>,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        policy: VerifyPolicy,
+    ) -> Result<(), Error> {
+        if policy == VerifyPolicy::Cofactorless {
+            return public.verify(ios, ad, self);
+        }
+        let ios = ios.as_ref();
+        utils::check_non_identity::<S>(Some(&public.0), ios)?;
+        let (t, merged) = vrf_transcript::<S>(public.0, ios, ad);
+        let c = S::challenge(&[&self.r], Some(t));
+        let lhs = short_msm(&[merged.input.0, merged.output.0], &[self.s, -c], 2);
+        if !utils::is_small_order::<S>(&(lhs - self.r).into_affine()) {
+            return Err(Error::VerificationFailure);
+        }
+        Ok(())
+    }
+}
+
+/// Verification equation policy.
+///
+/// The two equations only disagree on points with a torsion component, which
+/// the checked constructors and decoders reject. They matter for suites with
+/// a cofactor (e.g. ed25519), when agreeing with other implementations on
+/// edge-case points obtained through unchecked constructors.
+///
+/// Only Thin VRF proofs take a policy, as they carry the nonce commitment `R`
+/// the equation is checked against. Tiny VRF proofs carry the challenge
+/// instead, which is checked as is, as in RFC-9381 ECVRF verification: the
+/// ECVRF implementations of ed25519 never apply the cofactor to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VerifyPolicy {
+    /// `s·I_m - c·O_m == R`.
+    #[default]
+    Cofactorless,
+    /// `h·(s·I_m - c·O_m) == h·R`, with `h` the curve cofactor, as permitted
+    /// by RFC-8032 for Ed25519 signatures.
+    Cofactored,
+}
+
 #[inline(always)]
 fn vrf_transcript<S: ThinVrfSuite>(
     public: AffinePoint<S>,
     ios: impl AsRef<[VrfIo<S>]>,
     ad: impl AsRef<[u8]>,
 ) -> (S::Transcript, VrfIo<S>) {
-    utils::vrf_transcript_with_schnorr(DomSep::ThinVrf, public, ios, ad)
+    utils::vrf_transcript_with_schnorr(S::THIN_VRF_DOMAIN, public, ios, ad)
 }
 
 #[inline(always)]
@@ -62,15 +313,28 @@ fn vrf_transcript_scalars<S: ThinVrfSuite>(
     ios: impl AsRef<[VrfIo<S>]>,
     ad: impl AsRef<[u8]>,
 ) -> (S::Transcript, Vec<ScalarField<S>>) {
-    utils::vrf_transcript_scalars_with_schnorr(DomSep::ThinVrf, public, ios, ad)
+    utils::vrf_transcript_scalars_with_schnorr(S::THIN_VRF_DOMAIN, public, ios, ad)
 }
 
 /// Trait for types that can generate Thin VRF proofs.
+#[cfg(feature = "prove")]
 pub trait Prover<S: ThinVrfSuite> {
     /// Generate a proof for the given VRF I/O pairs and additional data.
     ///
     /// Multiple I/O pairs are delinearized into a single merged pair before proving.
     fn prove(&self, ios: impl AsRef<[VrfIo<S>]>, ad: impl AsRef<[u8]>) -> Proof<S>;
+
+    /// Generate a proof for the input derived from `salt` and `data`.
+    ///
+    /// The input is [`Input::new_salted`], returned with its output in the
+    /// proven I/O pair. Returns `Error::HashToCurveFailure` if the input can't
+    /// be derived.
+    fn prove_salted(
+        &self,
+        salt: &[u8],
+        data: &[u8],
+        ad: impl AsRef<[u8]>,
+    ) -> Result<(VrfIo<S>, Proof<S>), Error>;
 }
 
 /// Trait for entities that can verify Thin VRF proofs.
@@ -86,6 +350,8 @@ pub trait Prover<S: ThinVrfSuite> {
 /// Using unchecked constructors (e.g. [`Input::from_affine_unchecked`]) places
 /// the burden of subgroup validation on the caller. Passing points with
 /// cofactor components leads to undefined verification behavior.
+///
+/// Proofs involving an identity public key or VRF output are always rejected.
 pub trait Verifier<S: ThinVrfSuite> {
     /// Verify a proof for the given VRF I/O pairs and additional data.
     ///
@@ -98,10 +364,29 @@ pub trait Verifier<S: ThinVrfSuite> {
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> Result<(), Error>;
+
+    /// Verify a proof for `output` of the input derived from `salt` and `data`.
+    ///
+    /// Same as [`Self::verify`] for the I/O pair of [`Input::new_salted`] and
+    /// `output`. Returns `Error::HashToCurveFailure` if the input can't be
+    /// derived.
+    fn verify_salted(
+        &self,
+        salt: &[u8],
+        data: &[u8],
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        let input = Input::new_salted(salt, data).ok_or(Error::HashToCurveFailure)?;
+        self.verify(VrfIo { input, output }, ad, proof)
+    }
 }
 
+#[cfg(feature = "prove")]
 impl<S: ThinVrfSuite> Prover<S> for Secret<S> {
     fn prove(&self, ios: impl AsRef<[VrfIo<S>]>, ad: impl AsRef<[u8]>) -> Proof<S> {
+        trace_span!("thin_prove", ios = ios.as_ref().len());
         let (t, merged) = vrf_transcript::<S>(self.public.0, ios, ad);
 
         // Nonce
@@ -118,6 +403,16 @@ impl<S: ThinVrfSuite> Prover<S> for Secret<S> {
 
         Proof { r, s }
     }
+
+    fn prove_salted(
+        &self,
+        salt: &[u8],
+        data: &[u8],
+        ad: impl AsRef<[u8]>,
+    ) -> Result<(VrfIo<S>, Proof<S>), Error> {
+        let io = self.vrf_io(Input::new_salted(salt, data).ok_or(Error::HashToCurveFailure)?);
+        Ok((io, self.prove(io, ad)))
+    }
 }
 
 impl<S: ThinVrfSuite> Verifier<S> for Public<S> {
@@ -128,6 +423,9 @@ impl<S: ThinVrfSuite> Verifier<S> for Public<S> {
         proof: &Proof<S>,
     ) -> Result<(), Error> {
         let Proof { r, s } = proof;
+        let ios = ios.as_ref();
+        trace_span!("thin_verify", ios = ios.len());
+        utils::check_non_identity::<S>(Some(&self.0), ios)?;
         let (t, merged) = vrf_transcript::<S>(self.0, ios, ad);
 
         // Challenge
@@ -143,20 +441,196 @@ impl<S: ThinVrfSuite> Verifier<S> for Public<S> {
     }
 }
 
+impl<S: ThinVrfSuite> OutputProof<S> for Proof<S> {
+    fn verify_output(&self, public: &Public<S>, io: VrfIo<S>, ad: &[u8]) -> Result<(), Error> {
+        public.verify(io, ad, self)
+    }
+}
+
+impl<S: ThinVrfSuite> Verifier<S> for utils::VerifierContext<'_, S> {
+    fn verify(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        let Proof { r, s } = proof;
+        let ios = ios.as_ref();
+        trace_span!("thin_verify", ios = ios.len());
+        utils::check_non_identity::<S>(Some(&self.public().0), ios)?;
+        let (t, zs) = vrf_transcript_scalars::<S>(self.public().0, ios, ad);
+
+        // Challenge
+        let c = S::challenge(&[r], Some(t));
+
+        // Verification: s * I_m - c * O_m == R
+        let lhs = self.schnorr_lhs(ios, &zs, *s, c);
+        if lhs != r.into_group() {
+            return Err(Error::VerificationFailure);
+        }
+
+        Ok(())
+    }
+}
+
 /// Deferred Thin VRF verification data for batch verification.
 ///
 /// Stores raw points and delinearization scalars instead of the merged pair,
 /// so that `prepare` requires no EC ops (just hashing). The expanded
 /// verification equation uses these directly in the batch MSM.
+///
+/// Items can be cached and pushed again (cloned) into later batches, to
+/// repeat verification without hashing again.
+///
+/// Items are serializable, so that proofs collected on one host (e.g. on
+/// mempool ingress) can be verified by a worker on another one. Only the
+/// public key, the I/O pairs, the additional data and the proof are encoded:
+/// the challenge and delinearization scalars are recomputed on decoding, so
+/// the worker doesn't trust the sending host.
+#[derive(Clone)]
 pub struct BatchItem<S: ThinVrfSuite> {
     c: ScalarField<S>,
     pk: AffinePoint<S>,
     ios: Vec<VrfIo<S>>,
+    ad: Vec<u8>,
     zs: Vec<ScalarField<S>>,
     r: AffinePoint<S>,
     s: ScalarField<S>,
 }
 
+impl<S: ThinVrfSuite> CanonicalSerialize for BatchItem<S> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> Result<(), ark_serialize::SerializationError> {
+        self.pk.serialize_with_mode(&mut writer, compress)?;
+        self.ios.serialize_with_mode(&mut writer, compress)?;
+        self.ad.serialize_with_mode(&mut writer, compress)?;
+        self.r.serialize_with_mode(&mut writer, compress)?;
+        self.s.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.pk.serialized_size(compress)
+            + self.ios.serialized_size(compress)
+            + self.ad.serialized_size(compress)
+            + self.r.serialized_size(compress)
+            + self.s.serialized_size(compress)
+    }
+}
+
+impl<S: ThinVrfSuite> CanonicalDeserialize for BatchItem<S> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> Result<Self, ark_serialize::SerializationError> {
+        let pk: AffinePoint<S> =
+            CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let ios: Vec<VrfIo<S>> =
+            CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let ad: Vec<u8> =
+            CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let proof = Proof {
+            r: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            s: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        Ok(Self::new(pk, ios, ad, &proof))
+    }
+}
+
+impl<S: ThinVrfSuite> ark_serialize::Valid for BatchItem<S> {
+    fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        self.pk.check()?;
+        self.ios.check()?;
+        self.r.check()
+    }
+}
+
+impl<S: ThinVrfSuite> BatchItem<S> {
+    /// Derive the challenge and delinearization scalars (hashing only).
+    fn new(pk: AffinePoint<S>, ios: Vec<VrfIo<S>>, ad: Vec<u8>, proof: &Proof<S>) -> Self {
+        let (t, zs) = vrf_transcript_scalars::<S>(pk, &ios, &ad);
+        let c = S::challenge(&[&proof.r], Some(t));
+        Self {
+            c,
+            pk,
+            ios,
+            ad,
+            zs,
+            r: proof.r,
+            s: proof.s,
+        }
+    }
+
+    /// Challenge scalar.
+    pub fn challenge(&self) -> ScalarField<S> {
+        self.c
+    }
+
+    /// Public key.
+    pub fn public(&self) -> Public<S> {
+        Public(self.pk)
+    }
+
+    /// VRF I/O pairs.
+    pub fn ios(&self) -> &[VrfIo<S>] {
+        &self.ios
+    }
+
+    /// Additional data.
+    pub fn ad(&self) -> &[u8] {
+        &self.ad
+    }
+
+    /// Delinearization scalars.
+    ///
+    /// One per pair, starting with the public key pair `(G, pk)` (whose
+    /// scalar is one) followed by the VRF I/O pairs.
+    pub fn delinearization_scalars(&self) -> &[ScalarField<S>] {
+        &self.zs
+    }
+
+    /// Nonce commitment `R`.
+    pub fn nonce_commitment(&self) -> AffinePoint<S> {
+        self.r
+    }
+
+    /// Response scalar `s`.
+    pub fn response(&self) -> ScalarField<S> {
+        self.s
+    }
+
+    /// Merged I/O pair.
+    ///
+    /// The public key pair and the VRF I/O pairs weighted by the
+    /// delinearization scalars, such that the proof is valid if
+    /// `s * input - c * output == R`. Costs two multi-scalar multiplications,
+    /// which batch verification avoids.
+    pub fn merged_io(&self) -> VrfIo<S> {
+        let (inputs, outputs): (Vec<_>, Vec<_>) = core::iter::once((S::generator(), self.pk))
+            .chain(self.ios.iter().map(|io| (io.input.0, io.output.0)))
+            .unzip();
+        VrfIo {
+            input: Input(utils::msm::batch_msm(&inputs, &self.zs).into_affine()),
+            output: Output(utils::msm::batch_msm(&outputs, &self.zs).into_affine()),
+        }
+    }
+
+    /// Verify the item alone.
+    ///
+    /// Same outcome as a batch with this item only.
+    pub fn verify(&self) -> Result<(), Error> {
+        utils::check_non_identity::<S>(Some(&self.pk), &self.ios)?;
+        let io = self.merged_io();
+        if io.input.0 * self.s - io.output.0 * self.c != self.r.into_group() {
+            return Err(Error::VerificationFailure);
+        }
+        Ok(())
+    }
+}
+
 /// Batch verifier for Thin VRF proofs.
 ///
 /// Collects multiple proofs and verifies them together via a single
@@ -164,13 +638,25 @@ pub struct BatchItem<S: ThinVrfSuite> {
 ///
 /// The same subgroup membership assumptions as [`Verifier`] apply to all
 /// points fed into the batch (public keys, I/O pairs, and proof points).
+///
+/// The verifier can be reused across rounds: [`Self::verify_and_clear`]
+/// empties the batch while retaining the internal allocations.
 pub struct BatchVerifier<S: ThinVrfSuite> {
     items: Vec<BatchItem<S>>,
+    // Scratch MSM buffers reused by `verify_and_clear`.
+    bases: Vec<AffinePoint<S>>,
+    scalars: Vec<ScalarField<S>>,
+    msm_config: utils::msm::MsmConfig,
 }
 
 impl<S: ThinVrfSuite> Default for BatchVerifier<S> {
     fn default() -> Self {
-        Self { items: Vec::new() }
+        Self {
+            items: Vec::new(),
+            bases: Vec::new(),
+            scalars: Vec::new(),
+            msm_config: utils::msm::MsmConfig::DEFAULT,
+        }
     }
 }
 
@@ -180,6 +666,40 @@ impl<S: ThinVrfSuite> BatchVerifier<S> {
         Self::default()
     }
 
+    /// Create a new empty batch verifier with room for `n` proofs.
+    pub fn with_capacity(n: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(n),
+            ..Self::default()
+        }
+    }
+
+    /// Set the configuration of the batch multi-scalar multiplication.
+    pub fn with_msm_config(mut self, config: utils::msm::MsmConfig) -> Self {
+        self.msm_config = config;
+        self
+    }
+
+    /// Number of proofs in the batch.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the batch contains no proofs.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Remove all the proofs from the batch, retaining the allocated memory.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Prepared items of the batch, e.g. to ship them to a verification worker.
+    pub fn items(&self) -> &[BatchItem<S>] {
+        &self.items
+    }
+
     /// Prepare a proof for batch verification.
     ///
     /// Computes delinearization scalars and challenge via hashing only (no EC
@@ -191,17 +711,7 @@ impl<S: ThinVrfSuite> BatchVerifier<S> {
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> BatchItem<S> {
-        let ios = ios.as_ref();
-        let (t, zs) = vrf_transcript_scalars::<S>(public.0, ios, ad);
-        let c = S::challenge(&[&proof.r], Some(t));
-        BatchItem {
-            c,
-            pk: public.0,
-            ios: ios.to_vec(),
-            zs,
-            r: proof.r,
-            s: proof.s,
-        }
+        BatchItem::new(public.0, ios.as_ref().to_vec(), ad.as_ref().to_vec(), proof)
     }
 
     /// Push a previously prepared entry into the batch.
@@ -221,6 +731,28 @@ impl<S: ThinVrfSuite> BatchVerifier<S> {
         self.push_prepared(entry);
     }
 
+    /// Prepare and push several proofs, in order.
+    ///
+    /// Under the `parallel` feature the proofs are prepared in parallel.
+    pub fn push_all_par<I, A>(&mut self, items: &[(&Public<S>, I, A, &Proof<S>)])
+    where
+        I: AsRef<[VrfIo<S>]> + Sync,
+        A: AsRef<[u8]> + Sync,
+    {
+        let prepare = |(public, ios, ad, proof): &(&Public<S>, I, A, &Proof<S>)| {
+            Self::prepare(public, ios, ad, proof)
+        };
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            self.items.par_extend(items.par_iter().map(prepare));
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.items.extend(items.iter().map(prepare));
+        }
+    }
+
     /// Batch-verify all collected proofs using a single multi-scalar multiplication.
     ///
     /// For each proof j, the expanded verification equation is:
@@ -232,17 +764,74 @@ impl<S: ThinVrfSuite> BatchVerifier<S> {
     ///
     /// Returns `Ok(())` if all proofs verify, `Err(VerificationFailure)` otherwise.
     pub fn verify(&self) -> Result<(), Error> {
-        use ark_ec::VariableBaseMSM;
+        Self::verify_items(
+            &self.items,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &self.msm_config,
+            None,
+        )
+    }
+
+    /// Same as [`Self::verify`], with the random weights also derived from
+    /// a caller supplied `seed`.
+    ///
+    /// Weights are otherwise derived from the batch contents only. A seed
+    /// shared by the nodes (e.g. a randomness beacon output) makes the batch
+    /// verification reproducible and auditable, while a seed unknown to the
+    /// provers prevents them from grinding the weights.
+    pub fn verify_with_seed(&self, seed: &[u8; 32]) -> Result<(), Error> {
+        Self::verify_items(
+            &self.items,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &self.msm_config,
+            Some(seed),
+        )
+    }
+
+    /// Batch-verify all collected proofs, then clear the batch.
+    ///
+    /// Same as [`Self::verify`], but reuses the verifier's internal buffers
+    /// and leaves it empty, ready for the next round. The batch is cleared
+    /// regardless of the verification outcome.
+    pub fn verify_and_clear(&mut self) -> Result<(), Error> {
+        let res = Self::verify_items(
+            &self.items,
+            &mut self.bases,
+            &mut self.scalars,
+            &self.msm_config,
+            None,
+        );
+        self.items.clear();
+        self.bases.clear();
+        self.scalars.clear();
+        res
+    }
+
+    fn verify_items(
+        items: &[BatchItem<S>],
+        bases: &mut Vec<AffinePoint<S>>,
+        scalars: &mut Vec<ScalarField<S>>,
+        msm_config: &utils::msm::MsmConfig,
+        seed: Option<&[u8; 32]>,
+    ) -> Result<(), Error> {
         use ark_ff::Zero;
 
-        let items = &self.items;
+        trace_span!("thin_batch_verify", proofs = items.len());
         if items.is_empty() {
             return Ok(());
         }
+        for e in items {
+            utils::check_non_identity::<S>(Some(&e.pk), &e.ios)?;
+        }
 
         // Deterministic random scalars derived from all (c, s) pairs.
         let mut t = S::Transcript::new(S::SUITE_ID);
         t.absorb_raw(&[DomSep::ThinBatch as u8]);
+        if let Some(seed) = seed {
+            t.absorb_raw(seed);
+        }
         for e in items {
             t.absorb_serialize(&e.c);
             t.absorb_serialize(&e.s);
@@ -250,8 +839,10 @@ impl<S: ThinVrfSuite> BatchVerifier<S> {
 
         // Build MSM with expanded equation: per-proof (2+2M) points + 1 shared G.
         let total_points: usize = items.iter().map(|e| 2 + 2 * e.ios.len()).sum::<usize>() + 1;
-        let mut bases = Vec::with_capacity(total_points);
-        let mut scalars = Vec::with_capacity(total_points);
+        bases.clear();
+        bases.reserve(total_points);
+        scalars.clear();
+        scalars.reserve(total_points);
         let mut g_scalar = ScalarField::<S>::zero();
 
         for item in items.iter() {
@@ -286,7 +877,7 @@ impl<S: ThinVrfSuite> BatchVerifier<S> {
         bases.push(S::generator());
         scalars.push(g_scalar);
 
-        let result = <S::Affine as AffineRepr>::Group::msm_unchecked(&bases, &scalars);
+        let result = utils::msm::batch_msm_with_config(bases, scalars, msm_config);
         if !result.is_zero() {
             return Err(Error::VerificationFailure);
         }
@@ -295,10 +886,143 @@ impl<S: ThinVrfSuite> BatchVerifier<S> {
     }
 }
 
+#[cfg(any(test, feature = "vectors"))]
+pub mod vectors {
+    use super::*;
+    use crate::vectors::{self as common, SuiteExt};
+
+    /// Thin VRF test vector.
+    pub struct TestVector<S: ThinVrfSuite> {
+        pub base: common::TestVector<S>,
+        pub proof_r: AffinePoint<S>,
+        pub proof_s: ScalarField<S>,
+    }
+
+    impl<S: ThinVrfSuite> core::fmt::Debug for TestVector<S> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = hex::encode(common::point_encode::<S>(&self.proof_r));
+            let s = hex::encode(common::scalar_encode::<S>(&self.proof_s));
+            f.debug_struct("TestVector")
+                .field("base", &self.base)
+                .field("proof_r", &r)
+                .field("proof_s", &s)
+                .finish()
+        }
+    }
+
+    impl<S> common::TestVectorTrait for TestVector<S>
+    where
+        S: ThinVrfSuite + SuiteExt + std::fmt::Debug,
+    {
+        type Suite = S;
+
+        fn name() -> String {
+            S::SUITE_NAME.to_string() + "_thin"
+        }
+
+        fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
+            use super::Prover;
+            let base = common::TestVector::new(comment, seed, alpha, ad);
+            let io = VrfIo {
+                input: Input::<S>::from_affine_unchecked(base.h),
+                output: Output::from_affine_unchecked(base.gamma),
+            };
+            let secret = Secret::from_scalar(base.sk);
+            let proof: Proof<S> = secret.prove(io, ad);
+            Self {
+                base,
+                proof_r: proof.r,
+                proof_s: proof.s,
+            }
+        }
+
+        fn from_map(map: &common::TestVectorMap) -> Self {
+            let base = common::TestVector::from_map(map);
+            let proof_r = common::point_decode::<S>(&map.get_bytes("proof_r")).unwrap();
+            let proof_s = common::scalar_decode::<S>(&map.get_bytes("proof_s"));
+            let scheme_id = SchemeId::of::<S>().to_bytes();
+            assert_eq!(
+                map.get_bytes("scheme_id"),
+                scheme_id,
+                "Thin VRF scheme mismatch"
+            );
+            Self {
+                base,
+                proof_r,
+                proof_s,
+            }
+        }
+
+        fn to_map(&self) -> common::TestVectorMap {
+            let items = [
+                (
+                    "proof_r",
+                    hex::encode(common::point_encode::<S>(&self.proof_r)),
+                ),
+                (
+                    "proof_s",
+                    hex::encode(common::scalar_encode::<S>(&self.proof_s)),
+                ),
+                ("scheme_id", hex::encode(SchemeId::of::<S>().to_bytes())),
+            ];
+            let mut map = self.base.to_map();
+            items.into_iter().for_each(|(name, value)| {
+                map.0.insert(name.to_string(), value);
+            });
+            map
+        }
+
+        fn run(&self) {
+            self.base.run();
+            let io = VrfIo {
+                input: Input::<S>::from_affine_unchecked(self.base.h),
+                output: Output::from_affine_unchecked(self.base.gamma),
+            };
+            let sk = Secret::from_scalar(self.base.sk);
+            let proof = sk.prove(io, &self.base.ad);
+            assert_eq!(self.proof_r, proof.r, "Thin VRF proof R mismatch");
+            assert_eq!(self.proof_s, proof.s, "Thin VRF proof s mismatch");
+
+            let pk = Public(self.base.pk);
+            assert!(pk.verify(io, &self.base.ad, &proof).is_ok());
+        }
+
+        fn verify_map(map: &common::TestVectorMap) -> Result<(), Error> {
+            if map.try_get_bytes("scheme_id")? != SchemeId::of::<S>().to_bytes() {
+                return Err(Error::InvalidData);
+            }
+            let io = common::TestVector::<S>::verify_io(map)?;
+            let pk: Public<S> = map.try_get("pk")?;
+            let mut proof = map.try_get_bytes("proof_r")?;
+            proof.extend(map.try_get_bytes("proof_s")?);
+            let proof: Proof<S> = utils::decode_canonical(&proof)?;
+            pk.verify(io, map.try_get_bytes("ad")?, &proof)
+        }
+
+        fn wrong_suite_id(&self) -> Option<common::TestVectorMap> {
+            use super::Prover;
+            let io = VrfIo {
+                input: Input::<common::WrongSuiteId<S>>::from_affine_unchecked(self.base.h),
+                output: Output::from_affine_unchecked(self.base.gamma),
+            };
+            let proof = Secret::from_scalar(self.base.sk).prove(io, &self.base.ad);
+            Some(
+                Self {
+                    base: self.base.clone(),
+                    proof_r: proof.r,
+                    proof_s: proof.s,
+                }
+                .to_map(),
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod testing {
     use super::*;
-    use crate::testing::{self as common, SuiteExt, TEST_SEED, random_val};
+    use crate::suites::testing::TestSuite;
+    use crate::testing::{TEST_SEED, random_val};
 
     pub fn prove_verify<S: ThinVrfSuite>() {
         use thin::{Prover, Verifier};
@@ -342,6 +1066,27 @@ pub(crate) mod testing {
         batch.push_prepared(entry2);
         assert!(batch.verify().is_ok());
 
+        // Batch using push_all_par.
+        let mut batch = BatchVerifier::new();
+        batch.push_all_par(&[
+            (&public, io, b"foo", &proof1),
+            (&public, io, b"bar", &proof2),
+        ]);
+        assert_eq!(batch.len(), 2);
+        assert!(batch.verify().is_ok());
+        batch.push_all_par(&[(&public, io, b"baz", &proof1)]);
+        assert!(batch.verify().is_err());
+
+        // Custom MSM window.
+        let config = utils::msm::MsmConfig {
+            window: Some(4),
+            ..Default::default()
+        };
+        let mut batch = BatchVerifier::new().with_msm_config(config);
+        batch.push(&public, io, b"foo", &proof1);
+        batch.push(&public, io, b"bar", &proof2);
+        assert!(batch.verify().is_ok());
+
         // Empty batch is ok.
         let batch = BatchVerifier::<S>::new();
         assert!(batch.verify().is_ok());
@@ -351,6 +1096,85 @@ pub(crate) mod testing {
         batch.push(&public, io, b"foo", &proof1);
         batch.push(&public, io, b"wrong", &proof2);
         assert!(batch.verify().is_err());
+
+        // Reusable batch: verify_and_clear empties the batch.
+        let mut batch = BatchVerifier::with_capacity(2);
+        batch.push(&public, io, b"foo", &proof1);
+        batch.push(&public, io, b"wrong", &proof2);
+        assert_eq!(batch.len(), 2);
+        assert!(batch.verify_and_clear().is_err());
+        assert!(batch.is_empty());
+        batch.push(&public, io, b"foo", &proof1);
+        batch.push(&public, io, b"bar", &proof2);
+        assert!(batch.verify_and_clear().is_ok());
+        assert!(batch.is_empty());
+        batch.push(&public, io, b"wrong", &proof1);
+        batch.clear();
+        assert!(batch.verify().is_ok());
+
+        // Caller supplied weights seed.
+        batch.push(&public, io, b"foo", &proof1);
+        batch.push(&public, io, b"bar", &proof2);
+        assert!(batch.verify_with_seed(&[7; 32]).is_ok());
+        batch.push(&public, io, b"wrong", &proof2);
+        assert!(batch.verify_with_seed(&[7; 32]).is_err());
+    }
+
+    pub fn batch_item_reuse<S: ThinVrfSuite>() {
+        use thin::{BatchVerifier, Prover};
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+        let ios: Vec<_> = [b"foo".as_slice(), b"bar"]
+            .iter()
+            .map(|data| secret.vrf_io(Input::new(data).unwrap()))
+            .collect();
+        let proof = secret.prove(&ios[..], b"ad");
+
+        let item = BatchVerifier::prepare(&public, &ios[..], b"ad", &proof);
+        assert_eq!(item.public().0, public.0);
+        let same_io = |a: &VrfIo<S>, b: &VrfIo<S>| a.input == b.input && a.output == b.output;
+        assert!(item.ios().iter().zip(&ios).all(|(a, b)| same_io(a, b)));
+        assert_eq!(item.delinearization_scalars().len(), 3);
+        assert_eq!(item.nonce_commitment(), proof.r);
+        assert_eq!(item.response(), proof.s);
+        assert!(item.verify().is_ok());
+
+        let io = item.merged_io();
+        let lhs = io.input.0 * item.response() - io.output.0 * item.challenge();
+        assert_eq!(lhs.into_affine(), item.nonce_commitment());
+
+        // Cached item pushed into successive batches.
+        let mut batch = BatchVerifier::new();
+        for _ in 0..2 {
+            batch.push_prepared(item.clone());
+            assert!(batch.verify_and_clear().is_ok());
+        }
+
+        // Items shipped to another batch verifier.
+        batch.push_prepared(item.clone());
+        let buf = utils::common::encode_compressed(&batch.items().to_vec());
+        let items = Vec::<thin::BatchItem<S>>::deserialize_compressed(&buf[..]).unwrap();
+        let mut worker = BatchVerifier::new();
+        items
+            .into_iter()
+            .for_each(|item| worker.push_prepared(item));
+        assert!(worker.verify().is_ok());
+
+        // Challenge and delinearization scalars are recomputed by the worker:
+        // forged ones are not trusted.
+        let mut forged = item.clone();
+        forged.ad = b"other".to_vec();
+        let buf = utils::common::encode_compressed(&forged);
+        let decoded = thin::BatchItem::<S>::deserialize_compressed(&buf[..]).unwrap();
+        assert_eq!(decoded.ad(), b"other");
+        assert!(decoded.verify().is_err());
+        let mut worker = BatchVerifier::new();
+        worker.push_prepared(decoded);
+        assert!(worker.verify().is_err());
+
+        let item = BatchVerifier::prepare(&public, &ios[..], b"other", &proof);
+        assert!(item.verify().is_err());
     }
 
     /// N=1 slice produces same proof as passing a single `VrfIo`.
@@ -423,6 +1247,56 @@ pub(crate) mod testing {
         assert!(public.verify([], b"baz", &proof).is_err());
     }
 
+    pub fn verify_with_context<S: ThinVrfSuite>() {
+        use thin::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+        let precomp = utils::SuitePrecomp::<S>::new();
+        let ctx = utils::VerifierContext::new(public, &precomp);
+        let other_ctx =
+            utils::VerifierContext::new(Secret::<S>::from_seed([1; 32]).public(), &precomp);
+
+        for n in 0..4u8 {
+            let ios: Vec<VrfIo<S>> = (0..n)
+                .map(|i| secret.vrf_io(Input::new(&[i + 1]).unwrap()))
+                .collect();
+            let proof = secret.prove(&ios[..], b"foo");
+            assert!(ctx.verify(&ios[..], b"foo", &proof).is_ok());
+            assert!(ctx.verify(&ios[..], b"bar", &proof).is_err());
+            assert!(other_ctx.verify(&ios[..], b"foo", &proof).is_err());
+        }
+    }
+
+    pub fn recompute_and_compare<S: ThinVrfSuite>() {
+        use thin::Prover;
+        use utils::ProofDiff;
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar");
+        assert!(proof.recompute_and_compare(&secret, io, b"bar").is_empty());
+
+        let diff = |proof: &Proof<S>| proof.recompute_and_compare(&secret, io, b"bar");
+        let mut bad = proof.clone();
+        bad.r = (bad.r + S::generator()).into_affine();
+        let expected = ProofDiff {
+            nonce: true,
+            challenge: false,
+            response: false,
+        };
+        assert_eq!(diff(&bad), expected);
+
+        let mut bad = proof.clone();
+        bad.s += ScalarField::<S>::from(1u8);
+        let expected = ProofDiff {
+            nonce: false,
+            challenge: true,
+            response: true,
+        };
+        assert_eq!(diff(&bad), expected);
+    }
+
     #[macro_export]
     macro_rules! thin_suite_tests {
         ($suite:ty) => {
@@ -454,96 +1328,161 @@ pub(crate) mod testing {
                     $crate::thin::testing::batch_verify::<$suite>();
                 }
 
-                $crate::test_vectors!($crate::thin::testing::TestVector<$suite>);
+                #[test]
+                fn batch_item_reuse() {
+                    $crate::thin::testing::batch_item_reuse::<$suite>();
+                }
+
+                #[test]
+                fn recompute_and_compare() {
+                    $crate::thin::testing::recompute_and_compare::<$suite>();
+                }
+
+                #[test]
+                fn verify_with_context() {
+                    $crate::thin::testing::verify_with_context::<$suite>();
+                }
+
+                $crate::test_vectors!($crate::thin::vectors::TestVector<$suite>);
             }
         };
     }
 
-    pub struct TestVector<S: ThinVrfSuite> {
-        pub base: common::TestVector<S>,
-        pub proof_r: AffinePoint<S>,
-        pub proof_s: ScalarField<S>,
-    }
+    /// Suite with custom Thin VRF transcript tags.
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct DomainSuite;
 
-    impl<S: ThinVrfSuite> core::fmt::Debug for TestVector<S> {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let r = hex::encode(common::point_encode::<S>(&self.proof_r));
-            let s = hex::encode(common::scalar_encode::<S>(&self.proof_s));
-            f.debug_struct("TestVector")
-                .field("base", &self.base)
-                .field("proof_r", &r)
-                .field("proof_s", &s)
-                .finish()
+    impl Suite for DomainSuite {
+        const SUITE_ID: suites::SuiteId = TestSuite::SUITE_ID;
+        const THIN_VRF_DOMAIN: Domain = Domain {
+            scheme: utils::dst::suite_tag(0),
+            delinearize: utils::dst::suite_tag(1),
+            order: Order::AdFirst,
+        };
+        type Affine = <TestSuite as Suite>::Affine;
+        type Transcript = <TestSuite as Suite>::Transcript;
+
+        fn challenge(
+            pts: &[&AffinePoint<Self>],
+            transcript: Option<Self::Transcript>,
+        ) -> ScalarField<Self> {
+            utils::challenge_with_tag::<Self>(utils::dst::suite_tag(2), pts, transcript)
         }
     }
 
-    impl<S> common::TestVectorTrait for TestVector<S>
-    where
-        S: ThinVrfSuite + SuiteExt + std::fmt::Debug,
-    {
-        fn name() -> String {
-            S::SUITE_NAME.to_string() + "_thin"
-        }
+    /// Suite with the default tags, additional data first.
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct OrderSuite;
 
-        fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
-            use super::Prover;
-            let base = common::TestVector::new(comment, seed, alpha, ad);
-            let io = VrfIo {
-                input: Input::<S>::from_affine_unchecked(base.h),
-                output: Output::from_affine_unchecked(base.gamma),
-            };
-            let secret = Secret::from_scalar(base.sk);
-            let proof: Proof<S> = secret.prove(io, ad);
-            Self {
-                base,
-                proof_r: proof.r,
-                proof_s: proof.s,
-            }
-        }
+    impl Suite for OrderSuite {
+        const SUITE_ID: suites::SuiteId = TestSuite::SUITE_ID;
+        const THIN_VRF_DOMAIN: Domain = Domain {
+            order: Order::AdFirst,
+            ..Domain::DEFAULT
+        };
+        type Affine = <TestSuite as Suite>::Affine;
+        type Transcript = <TestSuite as Suite>::Transcript;
+    }
 
-        fn from_map(map: &common::TestVectorMap) -> Self {
-            let base = common::TestVector::from_map(map);
-            let proof_r = common::point_decode::<S>(&map.get_bytes("proof_r")).unwrap();
-            let proof_s = common::scalar_decode::<S>(&map.get_bytes("proof_s"));
-            Self {
-                base,
-                proof_r,
-                proof_s,
-            }
-        }
+    #[test]
+    fn custom_domain() {
+        let id = SchemeId::of::<TestSuite>();
+        assert_eq!(id.to_bytes(), [1, 0xff, 2, 2, VERSION, 0x01, 0x30, 0]);
+        let custom = SchemeId::of::<DomainSuite>();
+        assert_eq!(SchemeId::from_bytes(custom.to_bytes()), Some(custom));
+        let mut bytes = custom.to_bytes();
+        bytes[7] = 2;
+        assert_eq!(SchemeId::from_bytes(bytes), None);
+        assert_ne!(custom, id);
+
+        let secret = Secret::<DomainSuite>::from_seed([0; 32]);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar");
+        assert!(secret.public().verify(io, b"bar", &proof).is_ok());
+
+        // Same keys and points, default tags.
+        let public = Public::<TestSuite>(secret.public().0);
+        let io = VrfIo {
+            input: Input(io.input.0),
+            output: Output(io.output.0),
+        };
+        let proof = Proof::<TestSuite> {
+            r: proof.r,
+            s: proof.s,
+        };
+        assert!(public.verify(io, b"bar", &proof).is_err());
+
+        // Same tags, different order.
+        let secret = Secret::<TestSuite>::from_seed([0; 32]);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar");
+        let public = Public::<OrderSuite>(secret.public().0);
+        let io = VrfIo {
+            input: Input(io.input.0),
+            output: Output(io.output.0),
+        };
+        let proof = Proof::<OrderSuite> {
+            r: proof.r,
+            s: proof.s,
+        };
+        assert!(public.verify(io, b"bar", &proof).is_err());
+        assert!(public.verify(io, b"", &proof).is_err());
+    }
 
-        fn to_map(&self) -> common::TestVectorMap {
-            let items = [
-                (
-                    "proof_r",
-                    hex::encode(common::point_encode::<S>(&self.proof_r)),
-                ),
-                (
-                    "proof_s",
-                    hex::encode(common::scalar_encode::<S>(&self.proof_s)),
-                ),
-            ];
-            let mut map = self.base.to_map();
-            items.into_iter().for_each(|(name, value)| {
-                map.0.insert(name.to_string(), value);
-            });
-            map
+    /// Both policies agree on honest proofs, while a nonce commitment with the
+    /// `torsion` small-order component only passes the cofactored equation.
+    fn cofactored_verification<S: ThinVrfSuite>(torsion: AffinePoint<S>) {
+        let secret = Secret::<S>::from_seed([0; 32]);
+        let public = secret.public();
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar");
+        for policy in [VerifyPolicy::Cofactorless, VerifyPolicy::Cofactored] {
+            assert!(
+                proof
+                    .verify_with_policy(&public, [io], b"bar", policy)
+                    .is_ok()
+            );
+            assert!(
+                proof
+                    .verify_with_policy(&public, [io], b"baz", policy)
+                    .is_err()
+            );
         }
 
-        fn run(&self) {
-            self.base.run();
-            let io = VrfIo {
-                input: Input::<S>::from_affine_unchecked(self.base.h),
-                output: Output::from_affine_unchecked(self.base.gamma),
-            };
-            let sk = Secret::from_scalar(self.base.sk);
-            let proof = sk.prove(io, &self.base.ad);
-            assert_eq!(self.proof_r, proof.r, "Thin VRF proof R mismatch");
-            assert_eq!(self.proof_s, proof.s, "Thin VRF proof s mismatch");
+        // Nonce commitment with a small-order component.
+        let (t, merged) = vrf_transcript::<S>(public.0, [io], b"bar");
+        let k = ScalarField::<S>::from(1234u32);
+        let r = (merged.input.0 * k + torsion).into_affine();
+        let c = S::challenge(&[&r], Some(t));
+        let proof = Proof::<S> {
+            r,
+            s: k + c * secret.scalar,
+        };
+        assert!(!proof.is_canonical());
+        let cofactorless = proof.verify_with_policy(&public, [io], b"bar", VerifyPolicy::default());
+        assert!(cofactorless.is_err());
+        let cofactored = proof.verify_with_policy(&public, [io], b"bar", VerifyPolicy::Cofactored);
+        assert!(cofactored.is_ok());
+    }
 
-            let pk = Public(self.base.pk);
-            assert!(pk.verify(io, &self.base.ad, &proof).is_ok());
-        }
+    #[cfg(feature = "bandersnatch")]
+    #[test]
+    fn cofactored_verification_bandersnatch() {
+        use crate::suites::bandersnatch::{AffinePoint, BandersnatchSha512Ell2 as S, BaseField};
+        use ark_ff::One;
+        // Order 2 point.
+        let torsion = AffinePoint::new_unchecked(BaseField::zero(), -BaseField::one());
+        cofactored_verification::<S>(torsion);
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn cofactored_verification_ed25519() {
+        use crate::suites::ed25519::{AffinePoint, BaseField, Ed25519Sha512Tai as S};
+        use ark_ff::One;
+        // Order 2 point.
+        let torsion = AffinePoint::new_unchecked(BaseField::zero(), -BaseField::one());
+        cofactored_verification::<S>(torsion);
     }
 
     /// Demonstrates that a malicious prover who knows the discrete-log relation
@@ -48,7 +48,7 @@ fn vrf_transcript<S: TinySuite>(
 /// Schnorr-based proof of correctness for a VRF evaluation:
 /// - `c`: Challenge scalar derived from public parameters
 /// - `s`: Response scalar satisfying the verification equation
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Proof<S: TinySuite> {
     /// Challenge scalar derived from public parameters.
     pub c: ScalarField<S>,
@@ -56,17 +56,100 @@ pub struct Proof<S: TinySuite> {
     pub s: ScalarField<S>,
 }
 
+impl<S: TinySuite> Proof<S> {
+    /// Length in bytes of the encoded proof.
+    ///
+    /// Truncated challenge followed by the compressed response scalar.
+    pub const ENCODED_LEN: usize = utils::common::CHALLENGE_LEN + utils::scalar_len::<S>();
+
+    /// Check that the proof survives an encoding round trip unchanged.
+    ///
+    /// The encoding truncates `c` to [`CHALLENGE_LEN`](utils::common::CHALLENGE_LEN)
+    /// bytes, so a proof with a larger challenge is not canonical and returns
+    /// `Error::NonCanonicalScalar`. Proofs decoded from bytes and proofs
+    /// produced by [`Prover::prove`] are always canonical.
+    pub fn check_canonical(&self) -> Result<(), Error> {
+        use ark_ff::BigInteger;
+        let c_bits = self.c.into_bigint().num_bits() as usize;
+        if c_bits > 8 * utils::common::CHALLENGE_LEN {
+            return Err(Error::NonCanonicalScalar);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if [`Self::check_canonical`] succeeds.
+    pub fn is_canonical(&self) -> bool {
+        self.check_canonical().is_ok()
+    }
+
+    /// Compare with the proof of `secret` for the same I/O pairs and additional data.
+    ///
+    /// Meant for debugging proofs produced by other implementations. The
+    /// proof nonce is the one implied by the response, i.e. `s - c * x`.
+    #[cfg(feature = "prove")]
+    pub fn recompute_and_compare(
+        &self,
+        secret: &Secret<S>,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+    ) -> utils::ProofDiff {
+        let (ios, ad) = (ios.as_ref(), ad.as_ref());
+        let (t, _) = vrf_transcript::<S>(secret.public.0, ios, ad);
+        let k = S::nonce(&secret.scalar, Some(t));
+        let proof = secret.prove(ios, ad);
+        utils::ProofDiff {
+            nonce: self.s - self.c * secret.scalar != k,
+            challenge: self.c != proof.c,
+            response: self.s != proof.s,
+        }
+    }
+
+    /// Recompute the nonce commitments `(U, V)` of the I/O pair `io`.
+    ///
+    /// Meant for auditing proofs, e.g. rejected ones. The points are the
+    /// RFC-9381 ones, `U = s * G - c * Y` and `V = s * I - c * O`: for a valid
+    /// proof they are `k * G` and `k * I`, with `k` the proof nonce, and the
+    /// challenge is computed from the delinearized merge of the two.
+    ///
+    /// The additional data only enters the challenge, so it does not affect
+    /// the points.
+    pub fn recover_nonce_commitments(
+        &self,
+        public: &Public<S>,
+        io: VrfIo<S>,
+    ) -> (AffinePoint<S>, AffinePoint<S>) {
+        let (c, s) = (-self.c, self.s);
+        let u = short_msm(&[S::generator(), public.0], &[s, c], 2);
+        let v = short_msm(&[io.input.0, io.output.0], &[s, c], 2);
+        (u.into_affine(), v.into_affine())
+    }
+}
+
+impl<S: TinySuite> core::fmt::Debug for Proof<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use utils::common::{CHALLENGE_LEN, Hex, encode_compressed};
+        let buf = encode_compressed(self);
+        f.debug_struct("Proof")
+            .field("suite", &format_args!("{:08x}", S::SUITE_ID))
+            .field("c", &Hex(&buf[..CHALLENGE_LEN]))
+            .field("s", &Hex(&buf[CHALLENGE_LEN..]))
+            .finish()
+    }
+}
+
+impl<S: TinySuite> core::fmt::Display for Proof<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        utils::common::fmt_proof::<S>(self, f)
+    }
+}
+
 impl<S: TinySuite> CanonicalSerialize for Proof<S> {
     fn serialize_with_mode<W: ark_serialize::Write>(
         &self,
         mut writer: W,
         compress: ark_serialize::Compress,
     ) -> Result<(), ark_serialize::SerializationError> {
-        let scalar_len = ScalarField::<S>::MODULUS_BIT_SIZE.div_ceil(8) as usize;
-        if scalar_len < utils::common::CHALLENGE_LEN {
-            // Encoded scalar length must be at least utils::common::CHALLENGE_LEN
-            return Err(ark_serialize::SerializationError::InvalidData);
-        }
+        let () = utils::common::SuiteAssertions::<S>::SCALAR_LENGTHS;
         let mut c_buf = [0; 128];
         self.c
             .serialize_compressed(&mut c_buf[..])
@@ -111,11 +194,24 @@ impl<S: TinySuite> ark_serialize::Valid for Proof<S> {
 }
 
 /// Trait for types that can generate Tiny VRF proofs.
+#[cfg(feature = "prove")]
 pub trait Prover<S: TinySuite> {
     /// Generate a proof for the given VRF I/O pairs and additional data.
     ///
     /// Multiple I/O pairs are delinearized into a single merged pair before proving.
     fn prove(&self, ios: impl AsRef<[VrfIo<S>]>, ad: impl AsRef<[u8]>) -> Proof<S>;
+
+    /// Generate a proof for the input derived from `salt` and `data`.
+    ///
+    /// The input is [`Input::new_salted`], returned with its output in the
+    /// proven I/O pair. Returns `Error::HashToCurveFailure` if the input can't
+    /// be derived.
+    fn prove_salted(
+        &self,
+        salt: &[u8],
+        data: &[u8],
+        ad: impl AsRef<[u8]>,
+    ) -> Result<(VrfIo<S>, Proof<S>), Error>;
 }
 
 /// Trait for entities that can verify Tiny VRF proofs.
@@ -129,6 +225,13 @@ pub trait Prover<S: TinySuite> {
 /// Using unchecked constructors (e.g. [`Input::from_affine_unchecked`]) places
 /// the burden of subgroup validation on the caller. Passing points with
 /// cofactor components leads to undefined verification behavior.
+///
+/// The recomputed nonce commitment is hashed and compared with the proof
+/// challenge as is, without a cofactored variant: as in RFC-9381 ECVRF
+/// verification, which other ed25519 implementations follow, the challenge
+/// can't be checked up to a small-order component.
+///
+/// Proofs involving an identity public key or VRF output are always rejected.
 pub trait Verifier<S: TinySuite> {
     /// Verify a proof for the given VRF I/O pairs and additional data.
     ///
@@ -141,8 +244,26 @@ pub trait Verifier<S: TinySuite> {
         aux: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> Result<(), Error>;
+
+    /// Verify a proof for `output` of the input derived from `salt` and `data`.
+    ///
+    /// Same as [`Self::verify`] for the I/O pair of [`Input::new_salted`] and
+    /// `output`. Returns `Error::HashToCurveFailure` if the input can't be
+    /// derived.
+    fn verify_salted(
+        &self,
+        salt: &[u8],
+        data: &[u8],
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        let input = Input::new_salted(salt, data).ok_or(Error::HashToCurveFailure)?;
+        self.verify(VrfIo { input, output }, ad, proof)
+    }
 }
 
+#[cfg(feature = "prove")]
 impl<S: TinySuite> Prover<S> for Secret<S> {
     /// Tiny VRF proving algorithm.
     ///
@@ -154,6 +275,7 @@ impl<S: TinySuite> Prover<S> for Secret<S> {
     /// 3. Compute the challenge `c`
     /// 4. Compute the response `s = k + c * x`
     fn prove(&self, ios: impl AsRef<[VrfIo<S>]>, ad: impl AsRef<[u8]>) -> Proof<S> {
+        trace_span!("tiny_prove", ios = ios.as_ref().len());
         let (t, io) = vrf_transcript::<S>(self.public.0, ios, ad);
 
         let k = S::nonce(&self.scalar, Some(t.clone()));
@@ -165,6 +287,16 @@ impl<S: TinySuite> Prover<S> for Secret<S> {
         let s = k + c * self.scalar;
         Proof { c, s }
     }
+
+    fn prove_salted(
+        &self,
+        salt: &[u8],
+        data: &[u8],
+        ad: impl AsRef<[u8]>,
+    ) -> Result<(VrfIo<S>, Proof<S>), Error> {
+        let io = self.vrf_io(Input::new_salted(salt, data).ok_or(Error::HashToCurveFailure)?);
+        Ok((io, self.prove(io, ad)))
+    }
 }
 
 impl<S: TinySuite> Verifier<S> for Public<S> {
@@ -179,6 +311,9 @@ impl<S: TinySuite> Verifier<S> for Public<S> {
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> Result<(), Error> {
+        let ios = ios.as_ref();
+        trace_span!("tiny_verify", ios = ios.len());
+        utils::check_non_identity::<S>(Some(&self.0), ios)?;
         let (t, io) = vrf_transcript::<S>(self.0, ios, ad);
 
         let Proof { c, s } = proof;
@@ -193,10 +328,160 @@ impl<S: TinySuite> Verifier<S> for Public<S> {
     }
 }
 
+impl<S: TinySuite> OutputProof<S> for Proof<S> {
+    fn verify_output(&self, public: &Public<S>, io: VrfIo<S>, ad: &[u8]) -> Result<(), Error> {
+        public.verify(io, ad, self)
+    }
+}
+
+impl<S: TinySuite> Verifier<S> for utils::VerifierContext<'_, S> {
+    /// Tiny VRF verification using the context's precomputed tables.
+    ///
+    /// Same as the [`Public`] verifier, with `R` computed from the expanded
+    /// merged pair.
+    fn verify(
+        &self,
+        ios: impl AsRef<[VrfIo<S>]>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        let ios = ios.as_ref();
+        trace_span!("tiny_verify", ios = ios.len());
+        utils::check_non_identity::<S>(Some(&self.public().0), ios)?;
+        let (t, zs) =
+            utils::vrf_transcript_scalars_with_schnorr(DomSep::TinyVrf, self.public().0, ios, ad);
+
+        let Proof { c, s } = proof;
+
+        // R = s * I_m - c * O_m
+        let r = self.schnorr_lhs(ios, &zs, *s, *c).into_affine();
+
+        let c_exp = S::challenge(&[&r], Some(t));
+        (c_exp == *c)
+            .then_some(())
+            .ok_or(Error::VerificationFailure)
+    }
+}
+
+#[cfg(any(test, feature = "vectors"))]
+pub mod vectors {
+    use super::*;
+    use crate::vectors::{self as common, SuiteExt};
+
+    /// Tiny VRF test vector.
+    pub struct TestVector<S: TinySuite> {
+        pub base: common::TestVector<S>,
+        pub c: ScalarField<S>,
+        pub s: ScalarField<S>,
+    }
+
+    impl<S: TinySuite> core::fmt::Debug for TestVector<S> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let c = hex::encode(common::scalar_encode::<S>(&self.c));
+            let s = hex::encode(common::scalar_encode::<S>(&self.s));
+            f.debug_struct("TestVector")
+                .field("base", &self.base)
+                .field("proof_c", &c)
+                .field("proof_s", &s)
+                .finish()
+        }
+    }
+
+    impl<S> common::TestVectorTrait for TestVector<S>
+    where
+        S: TinySuite + SuiteExt + std::fmt::Debug,
+    {
+        type Suite = S;
+
+        fn name() -> String {
+            S::SUITE_NAME.to_string() + "_tiny"
+        }
+
+        fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
+            use super::Prover;
+            let base = common::TestVector::new(comment, seed, alpha, ad);
+            let io = VrfIo {
+                input: Input::from_affine_unchecked(base.h),
+                output: Output::from_affine_unchecked(base.gamma),
+            };
+            let sk = Secret::from_scalar(base.sk);
+            let proof: Proof<S> = sk.prove(io, ad);
+            Self {
+                base,
+                c: proof.c,
+                s: proof.s,
+            }
+        }
+
+        fn from_map(map: &common::TestVectorMap) -> Self {
+            let base = common::TestVector::from_map(map);
+            let c = common::scalar_decode::<S>(&map.get_bytes("proof_c"));
+            let s = common::scalar_decode::<S>(&map.get_bytes("proof_s"));
+            Self { base, c, s }
+        }
+
+        fn to_map(&self) -> common::TestVectorMap {
+            let buf = common::scalar_encode::<S>(&self.c);
+            let proof_c = &buf[..utils::common::CHALLENGE_LEN];
+            let items = [
+                ("proof_c", hex::encode(proof_c)),
+                ("proof_s", hex::encode(common::scalar_encode::<S>(&self.s))),
+            ];
+            let mut map = self.base.to_map();
+            items.into_iter().for_each(|(name, value)| {
+                map.0.insert(name.to_string(), value);
+            });
+            map
+        }
+
+        fn run(&self) {
+            self.base.run();
+            let io = VrfIo {
+                input: Input::<S>::from_affine_unchecked(self.base.h),
+                output: Output::from_affine_unchecked(self.base.gamma),
+            };
+            let sk = Secret::from_scalar(self.base.sk);
+            let proof = sk.prove(io, &self.base.ad);
+            assert_eq!(self.c, proof.c, "VRF proof challenge ('c') mismatch");
+            assert_eq!(self.s, proof.s, "VRF proof response ('s') mismatch");
+
+            let pk = Public(self.base.pk);
+            assert!(pk.verify(io, &self.base.ad, &proof).is_ok());
+        }
+
+        fn verify_map(map: &common::TestVectorMap) -> Result<(), Error> {
+            let io = common::TestVector::<S>::verify_io(map)?;
+            let pk: Public<S> = map.try_get("pk")?;
+            let mut proof = map.try_get_bytes("proof_c")?;
+            proof.extend(map.try_get_bytes("proof_s")?);
+            let proof = utils::decode_canonical::<Proof<S>>(&proof)?;
+            pk.verify(io, map.try_get_bytes("ad")?, &proof)
+        }
+
+        fn wrong_suite_id(&self) -> Option<common::TestVectorMap> {
+            use super::Prover;
+            let io = VrfIo {
+                input: Input::<common::WrongSuiteId<S>>::from_affine_unchecked(self.base.h),
+                output: Output::from_affine_unchecked(self.base.gamma),
+            };
+            let proof = Secret::from_scalar(self.base.sk).prove(io, &self.base.ad);
+            let base = self.base.clone();
+            Some(
+                Self {
+                    base,
+                    c: proof.c,
+                    s: proof.s,
+                }
+                .to_map(),
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod testing {
     use super::*;
-    use crate::testing::{self as common, SuiteExt};
+    use crate::testing as common;
 
     pub fn prove_verify<S: TinySuite>() {
         let secret = Secret::<S>::from_seed(common::TEST_SEED);
@@ -278,12 +563,110 @@ pub mod testing {
         assert!(public.verify(&ios[..], b"baz", &proof).is_err());
     }
 
+    pub fn prove_verify_salted<S: TinySuite>() {
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let public = secret.public();
+
+        let (io, proof) = secret.prove_salted(b"salt", b"foo", b"bar").unwrap();
+        assert!(io.input == Input::new_salted(b"salt", b"foo").unwrap());
+        assert!(public.verify(io, b"bar", &proof).is_ok());
+        let res = public.verify_salted(b"salt", b"foo", io.output, b"bar", &proof);
+        assert!(res.is_ok());
+
+        // Salt not bound as plain data prefix.
+        let res = public.verify_salted(b"sal", b"tfoo", io.output, b"bar", &proof);
+        assert!(res.is_err());
+        let res = public.verify_salted(b"", b"foo", io.output, b"bar", &proof);
+        assert!(res.is_err());
+    }
+
+    pub fn verify_with_context<S: TinySuite>() {
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let public = secret.public();
+        let precomp = utils::SuitePrecomp::<S>::new();
+        let ctx = utils::VerifierContext::new(public, &precomp);
+        let other_ctx =
+            utils::VerifierContext::new(Secret::<S>::from_seed([1; 32]).public(), &precomp);
+
+        for n in 0..4u8 {
+            let ios: Vec<VrfIo<S>> = (0..n)
+                .map(|i| secret.vrf_io(Input::new(&[i + 1]).unwrap()))
+                .collect();
+            let proof = secret.prove(&ios[..], b"foo");
+            assert!(ctx.verify(&ios[..], b"foo", &proof).is_ok());
+            assert!(ctx.verify(&ios[..], b"bar", &proof).is_err());
+            assert!(other_ctx.verify(&ios[..], b"foo", &proof).is_err());
+        }
+    }
+
+    pub fn recompute_and_compare<S: TinySuite>() {
+        use utils::ProofDiff;
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar");
+        assert!(proof.recompute_and_compare(&secret, io, b"bar").is_empty());
+
+        let diff = |proof: &Proof<S>| proof.recompute_and_compare(&secret, io, b"bar");
+        let mut bad = proof.clone();
+        bad.c += ScalarField::<S>::from(1u8);
+        let expected = ProofDiff {
+            nonce: true,
+            challenge: true,
+            response: false,
+        };
+        assert_eq!(diff(&bad), expected);
+
+        let mut bad = proof.clone();
+        bad.s += ScalarField::<S>::from(1u8);
+        let expected = ProofDiff {
+            nonce: true,
+            challenge: false,
+            response: true,
+        };
+        assert_eq!(diff(&bad), expected);
+
+        // Different additional data: everything is derived from the transcript.
+        let expected = ProofDiff {
+            nonce: true,
+            challenge: true,
+            response: true,
+        };
+        assert_eq!(proof.recompute_and_compare(&secret, io, b"baz"), expected);
+    }
+
+    pub fn recover_nonce_commitments<S: TinySuite>() {
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let public = secret.public();
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar");
+
+        let k = proof.s - proof.c * secret.scalar;
+        let (u, v) = proof.recover_nonce_commitments(&public, io);
+        assert_eq!(u, (S::generator() * k).into_affine());
+        assert_eq!(v, (io.input.0 * k).into_affine());
+
+        // Wrong output: the Schnorr part still matches.
+        let bad_io = secret.vrf_io(Input::new(b"baz").unwrap());
+        let bad_io = VrfIo {
+            input: io.input,
+            output: bad_io.output,
+        };
+        let (bad_u, bad_v) = proof.recover_nonce_commitments(&public, bad_io);
+        assert_eq!(bad_u, u);
+        assert_ne!(bad_v, v);
+    }
+
     #[macro_export]
     macro_rules! tiny_suite_tests {
         ($suite:ty) => {
             mod tiny {
                 use super::*;
 
+                #[test]
+                fn check_suite() {
+                    assert_eq!($crate::utils::check_suite::<$suite>(), Ok(()));
+                }
+
                 #[test]
                 fn prove_verify() {
                     $crate::tiny::testing::prove_verify::<$suite>();
@@ -304,87 +687,28 @@ pub mod testing {
                     $crate::tiny::testing::prove_verify_multi_empty::<$suite>();
                 }
 
-                $crate::test_vectors!($crate::tiny::testing::TestVector<$suite>);
-            }
-        };
-    }
+                #[test]
+                fn prove_verify_salted() {
+                    $crate::tiny::testing::prove_verify_salted::<$suite>();
+                }
 
-    pub struct TestVector<S: TinySuite> {
-        pub base: common::TestVector<S>,
-        pub c: ScalarField<S>,
-        pub s: ScalarField<S>,
-    }
+                #[test]
+                fn verify_with_context() {
+                    $crate::tiny::testing::verify_with_context::<$suite>();
+                }
 
-    impl<S: TinySuite> core::fmt::Debug for TestVector<S> {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let c = hex::encode(common::scalar_encode::<S>(&self.c));
-            let s = hex::encode(common::scalar_encode::<S>(&self.s));
-            f.debug_struct("TestVector")
-                .field("base", &self.base)
-                .field("proof_c", &c)
-                .field("proof_s", &s)
-                .finish()
-        }
-    }
+                #[test]
+                fn recompute_and_compare() {
+                    $crate::tiny::testing::recompute_and_compare::<$suite>();
+                }
 
-    impl<S> common::TestVectorTrait for TestVector<S>
-    where
-        S: TinySuite + SuiteExt + std::fmt::Debug,
-    {
-        fn name() -> String {
-            S::SUITE_NAME.to_string() + "_tiny"
-        }
+                #[test]
+                fn recover_nonce_commitments() {
+                    $crate::tiny::testing::recover_nonce_commitments::<$suite>();
+                }
 
-        fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
-            use super::Prover;
-            let base = common::TestVector::new(comment, seed, alpha, ad);
-            let io = VrfIo {
-                input: Input::from_affine_unchecked(base.h),
-                output: Output::from_affine_unchecked(base.gamma),
-            };
-            let sk = Secret::from_scalar(base.sk);
-            let proof: Proof<S> = sk.prove(io, ad);
-            Self {
-                base,
-                c: proof.c,
-                s: proof.s,
+                $crate::test_vectors!($crate::tiny::vectors::TestVector<$suite>);
             }
-        }
-
-        fn from_map(map: &common::TestVectorMap) -> Self {
-            let base = common::TestVector::from_map(map);
-            let c = common::scalar_decode::<S>(&map.get_bytes("proof_c"));
-            let s = common::scalar_decode::<S>(&map.get_bytes("proof_s"));
-            Self { base, c, s }
-        }
-
-        fn to_map(&self) -> common::TestVectorMap {
-            let buf = common::scalar_encode::<S>(&self.c);
-            let proof_c = &buf[..utils::common::CHALLENGE_LEN];
-            let items = [
-                ("proof_c", hex::encode(proof_c)),
-                ("proof_s", hex::encode(common::scalar_encode::<S>(&self.s))),
-            ];
-            let mut map = self.base.to_map();
-            items.into_iter().for_each(|(name, value)| {
-                map.0.insert(name.to_string(), value);
-            });
-            map
-        }
-
-        fn run(&self) {
-            self.base.run();
-            let io = VrfIo {
-                input: Input::<S>::from_affine_unchecked(self.base.h),
-                output: Output::from_affine_unchecked(self.base.gamma),
-            };
-            let sk = Secret::from_scalar(self.base.sk);
-            let proof = sk.prove(io, &self.base.ad);
-            assert_eq!(self.c, proof.c, "VRF proof challenge ('c') mismatch");
-            assert_eq!(self.s, proof.s, "VRF proof response ('s') mismatch");
-
-            let pk = Public(self.base.pk);
-            assert!(pk.verify(io, &self.base.ad, &proof).is_ok());
-        }
+        };
     }
 }
@@ -4,6 +4,7 @@
 //! used throughout the VRF schemes, including challenge generation, nonce
 //! derivation, and delinearization.
 
+use crate::utils::dst;
 use crate::utils::transcript::Transcript;
 use crate::*;
 use ark_ec::AffineRepr;
@@ -63,10 +64,192 @@ pub const fn expanded_scalar_len<S: Suite>(sec_bits: usize) -> usize {
     base_field_size_with_security_padding_in_bits.div_ceil(8)
 }
 
-pub fn nonce_scalar<S: Suite>(t: &mut S::Transcript) -> ScalarField<S> {
-    stack_buf!(buf, expanded_scalar_len::<S>(SECURITY_PARAMETER));
+/// Encoded scalar length in bytes.
+pub const fn scalar_len<S: Suite>() -> usize {
+    ScalarField::<S>::MODULUS_BIT_SIZE.div_ceil(8) as usize
+}
+
+/// Compile-time suite parameter checks.
+///
+/// Referenced by the code paths depending on them, so a misconfigured suite
+/// fails to build (post-monomorphization error) instead of panicking at
+/// runtime. Checks which can't be evaluated in const context are performed
+/// by [`check_suite`].
+pub(crate) struct SuiteAssertions<S>(core::marker::PhantomData<S>);
+
+impl<S: Suite> SuiteAssertions<S> {
+    /// Scalar encoding lengths are consistent with the codec and stack buffers.
+    pub(crate) const SCALAR_LENGTHS: () = {
+        assert!(
+            scalar_len::<S>() >= CHALLENGE_LEN,
+            "scalar encoding shorter than the challenge encoding"
+        );
+        assert!(
+            scalar_len::<S>() <= STACK_BUF_SIZE,
+            "scalar encoding exceeds the stack buffer size"
+        );
+        assert!(
+            expanded_scalar_len::<S>(SECURITY_PARAMETER) <= STACK_BUF_SIZE,
+            "expanded scalar exceeds the stack buffer size"
+        );
+    };
+}
+
+/// Runtime suite self-check.
+///
+/// Complements the compile-time checks with the ones requiring curve
+/// arithmetic or serialization:
+/// - compile-time scalar length checks;
+/// - compressed point encoding fits the stack buffers;
+/// - the generator is a non-identity point of the prime-order subgroup;
+/// - hash-to-curve maps to a non-identity point of the prime-order subgroup.
+///
+/// The transcript is squeezed to the required length, so no constraint on the
+/// hasher output size applies. Invoked by the suite test macros; custom suites
+/// should call it from their own tests.
+///
+/// Returns a description of the first failed check.
+pub fn check_suite<S: Suite>() -> Result<(), &'static str> {
+    let () = SuiteAssertions::<S>::SCALAR_LENGTHS;
+    let generator = S::generator();
+    if generator.compressed_size() > STACK_BUF_SIZE {
+        return Err("point encoding exceeds the stack buffer size");
+    }
+    if generator.is_zero() || check_point::<S>(&generator).is_err() {
+        return Err("generator is not a prime-order subgroup generator");
+    }
+    match S::data_to_point(b"check") {
+        Some(pt) if !pt.is_zero() && check_point::<S>(&pt).is_ok() => Ok(()),
+        _ => Err("hash-to-curve output is not in the prime-order subgroup"),
+    }
+}
+
+/// Uniform field element derivation from uniform bytes.
+///
+/// Implemented for all the prime fields, e.g. `ScalarField::<S>` and (for the
+/// curves over prime fields) `BaseField::<S>`.
+pub trait FromUniformBytes: PrimeField {
+    /// Minimum input length in bytes of [`Self::from_uniform_bytes`].
+    ///
+    /// Modulus bit size plus the 128 bits security parameter (see
+    /// [`expanded_scalar_len`]).
+    const UNIFORM_LEN: usize = (Self::MODULUS_BIT_SIZE as usize + SECURITY_PARAMETER).div_ceil(8);
+
+    /// Wide reduction of little-endian uniform `bytes`.
+    ///
+    /// The result statistical distance from uniform is at most `2^-128`.
+    ///
+    /// Panics if `bytes` is shorter than [`Self::UNIFORM_LEN`].
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        assert!(
+            bytes.len() >= Self::UNIFORM_LEN,
+            "not enough bytes for an unbiased reduction"
+        );
+        field_from_le_bytes_ct(bytes)
+    }
+}
+
+impl<F: PrimeField> FromUniformBytes for F {}
+
+/// Squeeze a uniform scalar from the transcript.
+///
+/// Squeezes [`FromUniformBytes::UNIFORM_LEN`] bytes, reduced with
+/// [`FromUniformBytes::from_uniform_bytes`]. Used to derive the nonces and
+/// the Pedersen blinding factors.
+pub fn uniform_scalar<S: Suite>(t: &mut S::Transcript) -> ScalarField<S> {
+    let () = SuiteAssertions::<S>::SCALAR_LENGTHS;
+    stack_buf!(buf, ScalarField::<S>::UNIFORM_LEN);
     t.squeeze_raw(buf);
-    ScalarField::<S>::from_le_bytes_mod_order(buf)
+    let scalar = ScalarField::<S>::from_uniform_bytes(buf);
+    buf.zeroize();
+    scalar
+}
+
+/// Field element from little-endian `bytes`, reduced modulo the field order.
+///
+/// Same value as `from_le_bytes_mod_order`, computed by Horner evaluation over
+/// all the bytes, without data-dependent branches or allocations. Used for
+/// secret scalars (keys, nonces, blinding factors).
+///
+/// Field arithmetic itself is delegated to arkworks, which doesn't guarantee
+/// constant-time execution.
+pub fn field_from_le_bytes_ct<F: PrimeField>(bytes: &[u8]) -> F {
+    let window = F::from(256_u64);
+    bytes
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, b| acc * window + F::from(*b))
+}
+
+/// Constant-time decoding of a secret scalar.
+///
+/// Accepts the same encodings as [`decode_canonical`] followed by a zero
+/// check: [`scalar_len`] little-endian bytes of a non-zero scalar less than
+/// the field order. Returns `Error::NonCanonicalScalar` otherwise.
+///
+/// The range and zero checks scan all the bytes and are combined before the
+/// only branch on the data, so timing just reveals whether decoding
+/// succeeded. The scalar is then built with [`field_from_le_bytes_ct`].
+pub fn decode_secret_scalar<S: Suite>(bytes: &[u8]) -> Result<ScalarField<S>, Error> {
+    if bytes.len() != scalar_len::<S>() {
+        return Err(Error::NonCanonicalScalar);
+    }
+    let modulus = ScalarField::<S>::MODULUS;
+    let limbs = modulus.as_ref();
+    let (mut borrow, mut acc) = (0_u8, 0_u8);
+    for (i, &b) in bytes.iter().enumerate() {
+        let m = (limbs[i / 8] >> (8 * (i % 8))) as u8;
+        // Final borrow of `bytes - modulus` is set iff `bytes < modulus`.
+        let diff = (b as u16)
+            .wrapping_sub(m as u16)
+            .wrapping_sub(borrow as u16);
+        borrow = (diff >> 15) as u8;
+        acc |= b;
+    }
+    let non_zero = ((acc as u16).wrapping_neg() >> 15) as u8;
+    if core::hint::black_box(borrow & non_zero) == 0 {
+        return Err(Error::NonCanonicalScalar);
+    }
+    Ok(field_from_le_bytes_ct(bytes))
+}
+
+/// Read a secret scalar with [`decode_secret_scalar`].
+///
+/// The read bytes are kept on the stack and zeroized.
+#[cfg(feature = "prove")]
+pub(crate) fn read_secret_scalar<S: Suite>(
+    mut reader: impl ark_std::io::Read,
+) -> Result<ScalarField<S>, ark_serialize::SerializationError> {
+    stack_buf!(buf, scalar_len::<S>());
+    let res = reader.read_exact(buf);
+    let scalar = res.map_err(Into::into).and_then(|_| {
+        decode_secret_scalar::<S>(buf).map_err(|_| ark_serialize::SerializationError::InvalidData)
+    });
+    buf.zeroize();
+    scalar
+}
+
+/// Hash `data` to a uniform scalar.
+///
+/// The application domain separation tag `dst` and `data` are both absorbed
+/// length-prefixed (little-endian `u32`), after the suite identifier and a
+/// dedicated crate tag, then the scalar is squeezed with [`uniform_scalar`].
+pub fn hash_to_scalar<S: Suite>(dst: &[u8], data: &[u8]) -> ScalarField<S> {
+    let mut t = S::Transcript::new(S::SUITE_ID);
+    t.absorb_raw(&[DomSep::HashToScalar as u8]);
+    for field in [dst, data] {
+        let len = u32::try_from(field.len()).expect("data too long");
+        t.absorb_raw(&len.to_le_bytes());
+        t.absorb_raw(field);
+    }
+    uniform_scalar::<S>(&mut t)
+}
+
+/// Squeeze a uniform nonce scalar from the transcript.
+///
+/// Same as [`uniform_scalar`].
+pub fn nonce_scalar<S: Suite>(t: &mut S::Transcript) -> ScalarField<S> {
+    uniform_scalar::<S>(t)
 }
 
 pub fn challenge_scalar<S: Suite>(t: &mut S::Transcript) -> ScalarField<S> {
@@ -121,20 +304,66 @@ where
 }
 
 /// Internal domain separation tags for protocol hashing.
+///
+/// Values from the [`dst`](super::dst) registry.
 #[repr(u8)]
 pub(crate) enum DomSep {
-    TinyVrf = 0x00,
-    ThinVrf = 0x01,
-    PedersenVrf = 0x02,
-    NonceExpand = 0x10,
-    Nonce = 0x11,
-    PedersenBlinding = 0x12,
-    PointToHash = 0x20,
-    Delinearize = 0x30,
-    Challenge = 0x40,
-    ThinBatch = 0x50,
-    PedersenBatch = 0x51,
-    HashToCurveTai = 0xFE,
+    TinyVrf = dst::TINY_VRF,
+    PedersenVrf = dst::PEDERSEN_VRF,
+    NonceExpand = dst::NONCE_EXPAND,
+    Nonce = dst::NONCE,
+    PedersenBlinding = dst::PEDERSEN_BLINDING,
+    HashToScalar = dst::HASH_TO_SCALAR,
+    #[cfg(feature = "prove")]
+    ChildKey = dst::CHILD_KEY,
+    PointToHash = dst::POINT_TO_HASH,
+    InputContext = dst::INPUT_CONTEXT,
+    AdPrehash = dst::AD_PREHASH,
+    PointToHashLen = dst::POINT_TO_HASH_LEN,
+    #[cfg(feature = "prove")]
+    Prf = dst::PRF,
+    OutputScalar = dst::OUTPUT_SCALAR,
+    OutputBaseField = dst::OUTPUT_BASE_FIELD,
+    InputSalt = dst::INPUT_SALT,
+    Delinearize = dst::DELINEARIZE,
+    Challenge = dst::CHALLENGE,
+    ChallengeFramed = dst::CHALLENGE_FRAMED,
+    ThinBatch = dst::THIN_BATCH,
+    PedersenBatch = dst::PEDERSEN_BATCH,
+    #[cfg(feature = "ring")]
+    RingCommitment = dst::RING_COMMITMENT,
+    #[cfg(feature = "ring")]
+    SrsTau = dst::SRS_TAU,
+    #[cfg(feature = "ring")]
+    SrsContribution = dst::SRS_CONTRIBUTION,
+    #[cfg(feature = "ring")]
+    SrsCheck = dst::SRS_CHECK,
+    #[cfg(feature = "ring")]
+    RingMembership = dst::RING_MEMBERSHIP,
+    Sortition = dst::SORTITION,
+    Shuffle = dst::SHUFFLE,
+    HashToCurveTai = dst::HASH_TO_CURVE_TAI,
+}
+
+/// Transcript tags of a VRF scheme.
+#[derive(Clone, Copy)]
+pub(crate) struct SchemeTags {
+    /// Scheme tag, absorbed first.
+    pub scheme: u8,
+    /// Delinearization scalars tag.
+    pub delinearize: u8,
+    /// Absorb the additional data before the I/O pairs.
+    pub ad_first: bool,
+}
+
+impl From<DomSep> for SchemeTags {
+    fn from(scheme: DomSep) -> Self {
+        SchemeTags {
+            scheme: scheme as u8,
+            delinearize: DomSep::Delinearize as u8,
+            ad_first: false,
+        }
+    }
 }
 
 /// Common VRF transcript construction: absorb scheme tag, I/O pairs, fork for
@@ -143,21 +372,65 @@ pub(crate) enum DomSep {
 /// Returns the transcript (with ad absorbed), the delinearization scalar
 /// stream, and the number of I/O pairs.
 fn vrf_transcript_base<S: Suite>(
-    scheme: DomSep,
+    tags: impl Into<SchemeTags>,
     ios: impl ExactSizeIterator<Item = VrfIo<S>> + Clone,
     ad: impl AsRef<[u8]>,
 ) -> (S::Transcript, DelinearizeScalars<S>, usize) {
+    let tags = tags.into();
     let n = ios.len();
     let mut t = S::Transcript::new(S::SUITE_ID);
-    t.absorb_raw(&[scheme as u8]);
-    absorb_ios::<S>(&mut t, ios);
-    let ad_len = u32::try_from(ad.as_ref().len()).expect("ad too long");
-    t.absorb_raw(&ad_len.to_le_bytes());
-    t.absorb_raw(ad.as_ref());
-    let scalars = DelinearizeScalars::new(t.clone());
+    t.absorb_raw(&[tags.scheme]);
+    if tags.ad_first {
+        S::absorb_ad(&mut t, ad.as_ref());
+        absorb_ios::<S>(&mut t, ios);
+    } else {
+        absorb_ios::<S>(&mut t, ios);
+        S::absorb_ad(&mut t, ad.as_ref());
+    }
+    let scalars = DelinearizeScalars::new(t.clone(), tags.delinearize);
     (t, scalars, n)
 }
 
+/// Additional data absorption.
+///
+/// Absorbs the data length (little-endian `u32`) followed by the data.
+pub fn absorb_ad<S: Suite>(t: &mut S::Transcript, ad: &[u8]) {
+    let ad_len = u32::try_from(ad.len()).expect("ad too long");
+    t.absorb_raw(&ad_len.to_le_bytes());
+    t.absorb_raw(ad);
+}
+
+/// Additional data digest length in bytes.
+pub const AD_DIGEST_LEN: usize = 2 * SECURITY_PARAMETER / 8;
+
+/// Additional data digest.
+///
+/// Squeezed from a fresh suite transcript absorbing a dedicated domain
+/// separation tag, the data length (little-endian `u64`) and the data.
+pub fn ad_digest<S: Suite>(ad: &[u8]) -> [u8; AD_DIGEST_LEN] {
+    let mut t = S::Transcript::new(S::SUITE_ID);
+    t.absorb_raw(&[DomSep::AdPrehash as u8]);
+    t.absorb_raw(&(ad.len() as u64).to_le_bytes());
+    t.absorb_raw(ad);
+    let mut digest = [0; AD_DIGEST_LEN];
+    t.squeeze_raw(&mut digest);
+    digest
+}
+
+/// Pre-hashed additional data absorption.
+///
+/// Same as [`absorb_ad`] over the [`ad_digest`] of the data, so that the
+/// VRF transcript (cloned for nonce, challenge and delinearization) only
+/// carries a short digest of multi-megabyte payloads.
+///
+/// Suites can opt in by overriding [`Suite::absorb_ad`]. This changes every
+/// proof of the suite: implementations interoperating with such a suite must
+/// pre-hash as well, and suites differing only in the option must use
+/// distinct suite identifiers.
+pub fn absorb_ad_prehashed<S: Suite>(t: &mut S::Transcript, ad: &[u8]) {
+    absorb_ad::<S>(t, &ad_digest::<S>(ad));
+}
+
 /// Build a shared VRF transcript from I/O pairs and additional data.
 ///
 /// Absorbs the scheme tag and raw I/O pairs into the transcript, derives
@@ -165,12 +438,12 @@ fn vrf_transcript_base<S: Suite>(
 /// merges the pairs into a single I/O, then absorbs the length-prefixed
 /// additional data.
 pub(crate) fn vrf_transcript_from_iter<S: Suite>(
-    scheme: DomSep,
+    tags: impl Into<SchemeTags>,
     ios: impl ExactSizeIterator<Item = VrfIo<S>> + Clone,
     ad: impl AsRef<[u8]>,
 ) -> (S::Transcript, VrfIo<S>) {
     let n = ios.len();
-    let (t, scalars, _) = vrf_transcript_base(scheme, ios.clone(), ad);
+    let (t, scalars, _) = vrf_transcript_base(tags, ios.clone(), ad);
 
     let zero = AffinePoint::<S>::zero();
     let io = if n == 0 {
@@ -194,20 +467,20 @@ pub(crate) fn vrf_transcript_from_iter<S: Suite>(
 /// which needs the individual points and z scalars to build an expanded MSM
 /// without computing the merged pair.
 pub(crate) fn vrf_transcript_scalars_from_iter<S: Suite>(
-    scheme: DomSep,
+    tags: impl Into<SchemeTags>,
     ios: impl ExactSizeIterator<Item = VrfIo<S>> + Clone,
     ad: impl AsRef<[u8]>,
 ) -> (S::Transcript, Vec<ScalarField<S>>) {
-    let (t, mut scalars, n) = vrf_transcript_base(scheme, ios, ad);
+    let (t, mut scalars, n) = vrf_transcript_base(tags, ios, ad);
     (t, scalars.take(n))
 }
 
 pub(crate) fn vrf_transcript<S: Suite>(
-    scheme: DomSep,
+    tags: impl Into<SchemeTags>,
     ios: impl AsRef<[VrfIo<S>]>,
     ad: impl AsRef<[u8]>,
 ) -> (S::Transcript, VrfIo<S>) {
-    vrf_transcript_from_iter(scheme, ios.as_ref().iter().copied(), ad)
+    vrf_transcript_from_iter(tags, ios.as_ref().iter().copied(), ad)
 }
 
 /// Prepend the Schnorr pair `(G, Y)` to the I/O list, then build the VRF transcript.
@@ -226,21 +499,21 @@ fn chain_ios<'a, S: Suite>(
 }
 
 pub(crate) fn vrf_transcript_with_schnorr<S: Suite>(
-    scheme: DomSep,
+    tags: impl Into<SchemeTags>,
     public: AffinePoint<S>,
     ios: impl AsRef<[VrfIo<S>]>,
     ad: impl AsRef<[u8]>,
 ) -> (S::Transcript, VrfIo<S>) {
-    vrf_transcript_from_iter(scheme, chain_ios(public, ios.as_ref()), ad)
+    vrf_transcript_from_iter(tags, chain_ios(public, ios.as_ref()), ad)
 }
 
 pub(crate) fn vrf_transcript_scalars_with_schnorr<S: Suite>(
-    scheme: DomSep,
+    tags: impl Into<SchemeTags>,
     public: AffinePoint<S>,
     ios: impl AsRef<[VrfIo<S>]>,
     ad: impl AsRef<[u8]>,
 ) -> (S::Transcript, Vec<ScalarField<S>>) {
-    vrf_transcript_scalars_from_iter(scheme, chain_ios(public, ios.as_ref()), ad)
+    vrf_transcript_scalars_from_iter(tags, chain_ios(public, ios.as_ref()), ad)
 }
 
 /// Challenge generation inspired by RFC-9381 section 5.4.3.
@@ -256,10 +529,69 @@ pub(crate) fn vrf_transcript_scalars_with_schnorr<S: Suite>(
 pub fn challenge<S: Suite>(
     pts: &[&AffinePoint<S>],
     transcript: Option<S::Transcript>,
+) -> ScalarField<S> {
+    challenge_with_tag::<S>(DomSep::Challenge as u8, pts, transcript)
+}
+
+/// Challenge generation under a custom domain separation tag.
+///
+/// Same as [`challenge`], absorbing `tag` in place of the
+/// [`CHALLENGE`](dst::CHALLENGE) tag. Suites can override the challenge tag
+/// (e.g. with one of the [`suite_tag`](dst::suite_tag) values) by overriding
+/// [`Suite::challenge`].
+pub fn challenge_with_tag<S: Suite>(
+    tag: u8,
+    pts: &[&AffinePoint<S>],
+    transcript: Option<S::Transcript>,
+) -> ScalarField<S> {
+    let mut t = transcript.unwrap_or_else(|| S::Transcript::new(S::SUITE_ID));
+    t.absorb_raw(&[tag]);
+    for p in pts {
+        t.absorb_serialize(*p);
+    }
+    challenge_scalar::<S>(&mut t)
+}
+
+/// Challenge generation over externally supplied point encodings.
+///
+/// Same as [`challenge`], but absorbs the given byte strings as they are,
+/// for verifiers holding the exact encodings received on the wire. These
+/// are not decoded nor checked, so the result matches [`challenge`] only
+/// for the compressed encodings of the points, and only for suites not
+/// overriding [`Suite::challenge`].
+pub fn challenge_encoded<S: Suite>(
+    pts: &[&[u8]],
+    transcript: Option<S::Transcript>,
 ) -> ScalarField<S> {
     let mut t = transcript.unwrap_or_else(|| S::Transcript::new(S::SUITE_ID));
     t.absorb_raw(&[DomSep::Challenge as u8]);
     for p in pts {
+        t.absorb_raw(p);
+    }
+    challenge_scalar::<S>(&mut t)
+}
+
+/// Challenge generation with explicit framing of the absorbed points.
+///
+/// Same as [`challenge`], but absorbs the number of points and prefixes each
+/// point encoding with its length (little-endian `u32`), under a distinct
+/// domain separation tag. The additional data is already length-prefixed by
+/// the VRF transcript construction. With the default fixed-length point
+/// encodings the framing is redundant, but it keeps the challenge input
+/// unambiguous for suites with variable-length encodings.
+///
+/// Suites can opt in by overriding [`Suite::challenge`].
+pub fn challenge_framed<S: Suite>(
+    pts: &[&AffinePoint<S>],
+    transcript: Option<S::Transcript>,
+) -> ScalarField<S> {
+    let mut t = transcript.unwrap_or_else(|| S::Transcript::new(S::SUITE_ID));
+    t.absorb_raw(&[DomSep::ChallengeFramed as u8]);
+    let n = u32::try_from(pts.len()).expect("too many points");
+    t.absorb_raw(&n.to_le_bytes());
+    for p in pts {
+        let len = u32::try_from(p.compressed_size()).expect("point encoding too long");
+        t.absorb_raw(&len.to_le_bytes());
         t.absorb_serialize(*p);
     }
     challenge_scalar::<S>(&mut t)
@@ -290,12 +622,209 @@ pub fn point_to_hash<S: Suite, const N: usize>(
     out
 }
 
+/// Point-to-hash bound to the hash length.
+///
+/// Same as [`point_to_hash`] (without cofactor multiplication), but the hash
+/// length `N` is absorbed after a dedicated domain separation tag. Hashes of
+/// different lengths are thus unrelated, rather than one being a prefix of
+/// the other.
+pub fn point_to_hash_n<S: Suite, const N: usize>(pt: &AffinePoint<S>) -> [u8; N] {
+    let len = u32::try_from(N).expect("hash too long");
+    let mut t = S::Transcript::new(S::SUITE_ID);
+    t.absorb_raw(&[DomSep::PointToHashLen as u8]);
+    t.absorb_raw(&len.to_le_bytes());
+    t.absorb_serialize(pt);
+    let mut out = [0; N];
+    t.squeeze_raw(&mut out);
+    out
+}
+
+/// Point to uniform field element.
+///
+/// Hashes `pt` after the `tag` domain separation tag, and wide-reduces
+/// [`FromUniformBytes::UNIFORM_LEN`] bytes of the hash.
+fn point_to_field<S: Suite, F: PrimeField>(tag: u8, pt: &AffinePoint<S>) -> F {
+    let mut t = S::Transcript::new(S::SUITE_ID);
+    t.absorb_raw(&[tag]);
+    t.absorb_serialize(pt);
+    stack_buf!(buf, F::UNIFORM_LEN);
+    t.squeeze_raw(buf);
+    F::from_uniform_bytes(buf)
+}
+
+/// Point to uniform scalar.
+///
+/// Unlike reducing a [`point_to_hash`] modulo the scalar field order, the
+/// result statistical distance from uniform is at most `2^-128`.
+pub fn point_to_scalar<S: Suite>(pt: &AffinePoint<S>) -> ScalarField<S> {
+    point_to_field::<S, _>(DomSep::OutputScalar as u8, pt)
+}
+
+/// Point to uniform base field element.
+///
+/// Same as [`point_to_scalar`], with an independent hash, for the base field.
+pub fn point_to_base_field<S: Suite>(pt: &AffinePoint<S>) -> BaseField<S>
+where
+    BaseField<S>: PrimeField,
+{
+    point_to_field::<S, _>(DomSep::OutputBaseField as u8, pt)
+}
+
+/// Returns `true` if `pt` is on the curve and in the prime-order subgroup.
+///
+/// The identity point is in the subgroup. This is the check performed by the
+/// checked point constructors and decoders.
+pub fn is_prime_order<S: Suite>(pt: &AffinePoint<S>) -> bool {
+    ark_serialize::Valid::check(pt).is_ok()
+}
+
+/// Returns `true` if `pt` is in the torsion subgroup.
+///
+/// That is, the cofactor multiple of `pt` is the identity point (e.g. the
+/// identity itself, or any of the eight small-order points of ed25519).
+/// Assumes `pt` is on the curve.
+pub fn is_small_order<S: Suite>(pt: &AffinePoint<S>) -> bool {
+    pt.mul_by_cofactor_to_group().is_zero()
+}
+
+/// Map `pt` to the prime-order subgroup by clearing its torsion component.
+///
+/// Assumes `pt` is on the curve. Note that this doesn't preserve the prime
+/// order component: for most curves it multiplies `pt` by the cofactor.
+pub fn clear_cofactor<S: Suite>(pt: &AffinePoint<S>) -> AffinePoint<S> {
+    pt.clear_cofactor()
+}
+
+/// Validate curve and prime-order subgroup membership of a point.
+///
+/// `Valid::check` reports both failures with the same error. To tell them
+/// apart, a failing point is decompressed back from its compressed encoding:
+/// decompression recomputes one coordinate from the curve equation, so only
+/// a point on the curve survives the round trip unchanged.
+pub(crate) fn check_point<S: Suite>(pt: &AffinePoint<S>) -> Result<(), Error> {
+    if is_prime_order::<S>(pt) {
+        return Ok(());
+    }
+    stack_buf!(buf, pt.compressed_size());
+    pt.serialize_compressed(&mut buf[..])?;
+    let on_curve = AffinePoint::<S>::deserialize_compressed_unchecked(&buf[..])
+        .is_ok_and(|decoded| decoded == *pt);
+    Err(match on_curve {
+        true => Error::PointNotInSubgroup,
+        false => Error::PointNotOnCurve,
+    })
+}
+
+/// Decode a compressed encoding, accepting only the canonical one.
+///
+/// Returns `Error::NonCanonicalEncoding` if `bytes` has trailing data or if
+/// re-encoding the decoded value doesn't reproduce `bytes` (e.g. a point at
+/// infinity flag followed by non-zero coordinate bytes). Every accepted value
+/// thus has a unique byte representation, as required when proofs are
+/// consensus-critical.
+pub fn decode_canonical<T>(bytes: &[u8]) -> Result<T, Error>
+where
+    T: CanonicalSerialize + CanonicalDeserialize,
+{
+    let mut reader = bytes;
+    let value = T::deserialize_compressed(&mut reader)?;
+    if !reader.is_empty() {
+        return Err(Error::NonCanonicalEncoding);
+    }
+    let mut encoded = Vec::with_capacity(bytes.len());
+    value.serialize_compressed(&mut encoded)?;
+    if encoded != bytes {
+        return Err(Error::NonCanonicalEncoding);
+    }
+    Ok(value)
+}
+
+/// Bytes formatted as lowercase hex, for `Debug` and `Display` output.
+pub(crate) struct Hex<'a>(pub &'a [u8]);
+
+impl core::fmt::Debug for Hex<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+impl core::fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Compressed encoding of `value`, for `Debug` and `Display` output.
+pub(crate) fn encode_compressed<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut buf)
+        .expect("serialization into a vector can't fail");
+    buf
+}
+
+/// Format a proof as its suite identifier followed by its hex encoding.
+///
+/// Shared `Display` implementation of the proof types: `<suite id>:<encoding>`,
+/// both in lowercase hex.
+pub(crate) fn fmt_proof<S: Suite>(
+    proof: &impl CanonicalSerialize,
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+    write!(f, "{:08x}:{}", S::SUITE_ID, Hex(&encode_compressed(proof)))
+}
+
+/// Components of a proof differing from the re-derived one.
+///
+/// Returned by the `recompute_and_compare` methods of the deterministic
+/// schemes proofs. As each component depends on the previous ones, the first
+/// mismatch (in field order) usually points to the diverging procedure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProofDiff {
+    /// Nonce (or nonce commitment) differs.
+    pub nonce: bool,
+    /// Challenge differs.
+    pub challenge: bool,
+    /// Response differs.
+    pub response: bool,
+}
+
+impl ProofDiff {
+    /// Returns `true` if no component differs.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Reject an identity public key or identity VRF outputs.
+///
+/// An identity output only satisfies the DLEQ relation for an identity input
+/// or a zero secret key. Accepting either breaks VRF uniqueness, as the same
+/// proof then verifies for unrelated inputs.
+pub(crate) fn check_non_identity<S: Suite>(
+    public: Option<&AffinePoint<S>>,
+    ios: &[VrfIo<S>],
+) -> Result<(), Error> {
+    let identity =
+        public.is_some_and(|pk| pk.is_zero()) || ios.iter().any(|io| io.output.0.is_zero());
+    match identity {
+        true => Err(Error::VerificationFailure),
+        false => Ok(()),
+    }
+}
+
 /// Deterministic nonce generation inspired by RFC-8032 section 5.1.6.
 ///
 /// Hashes the secret key to derive a 64-byte expanded key, then absorbs the
 /// upper half into the transcript and squeezes a nonce. The transcript typically
 /// carries shared state from `vrf_transcript`, binding the nonce to the I/O
 /// pairs and additional data.
+///
+/// The expanded key is always squeezed from the transcript, so the hasher
+/// output size is irrelevant: for fixed-output hashers shorter than 64 bytes
+/// (e.g. SHA-256) the [`DigestXof`](crate::utils::DigestXof) counter-mode
+/// expansion applies, i.e. `sk_hash = H(seed || 0u32) || H(seed || 1u32)`
+/// with `seed = H(state)`.
 pub fn nonce<S: Suite>(sk: &ScalarField<S>, transcript: Option<S::Transcript>) -> ScalarField<S> {
     let mut t = transcript.unwrap_or_else(|| S::Transcript::new(S::SUITE_ID));
 
@@ -313,6 +842,131 @@ pub fn nonce<S: Suite>(sk: &ScalarField<S>, transcript: Option<S::Transcript>) -
     nonce_scalar::<S>(&mut t)
 }
 
+/// Deterministic nonce generation as specified by RFC-6979 section 3.2.
+///
+/// The message hash `h1` (`H` output length) is squeezed from the transcript,
+/// binding the nonce to the I/O pairs and additional data, then fed to
+/// [`nonce_rfc_6979_with_hash`]. Suites can opt in by overriding [`Suite::nonce`].
+pub fn nonce_rfc_6979<S: Suite, H>(
+    sk: &ScalarField<S>,
+    transcript: Option<S::Transcript>,
+) -> ScalarField<S>
+where
+    H: digest::Digest + digest::core_api::BlockSizeUser + Clone,
+{
+    let mut t = transcript.unwrap_or_else(|| S::Transcript::new(S::SUITE_ID));
+    t.absorb_raw(&[DomSep::Nonce as u8]);
+    let mut h1 = digest::Output::<H>::default();
+    t.squeeze_raw(&mut h1);
+    nonce_rfc_6979_with_hash::<S, H>(sk, &h1)
+}
+
+/// RFC-6979 section 3.2 nonce generation from a message hash `h1`.
+///
+/// Runs the full HMAC-DRBG loop, rejecting candidates outside `[1, q)` and
+/// retrying as mandated by step 3.2.h.3.
+///
+/// Octet strings (`int2octets`, `bits2octets`) are big-endian as required by
+/// the RFC, regardless of the little-endian scalar encoding used elsewhere
+/// in this crate.
+pub fn nonce_rfc_6979_with_hash<S: Suite, H>(sk: &ScalarField<S>, h1: &[u8]) -> ScalarField<S>
+where
+    H: digest::Digest + digest::core_api::BlockSizeUser + Clone,
+{
+    use ark_ff::BigInteger;
+
+    let qlen = ScalarField::<S>::MODULUS_BIT_SIZE as usize;
+    let rlen = qlen.div_ceil(8);
+    let int2octets = |x: &ScalarField<S>| {
+        let bytes = x.into_bigint().to_bytes_be();
+        bytes[bytes.len() - rlen..].to_vec()
+    };
+    let q = {
+        let bytes = ScalarField::<S>::MODULUS.to_bytes_be();
+        bytes[bytes.len() - rlen..].to_vec()
+    };
+
+    let mut x = int2octets(sk);
+    let h = int2octets(&ScalarField::<S>::from_be_bytes_mod_order(&bits2int(
+        h1, qlen,
+    )));
+
+    let hlen = <H as digest::Digest>::output_size();
+    let mut v = ark_std::vec![0x01; hlen];
+    let mut k = ark_std::vec![0x00; hlen];
+    for tag in [0x00, 0x01] {
+        k = hmac::<H>(&k, &[&v, &[tag], &x, &h]);
+        v = hmac::<H>(&k, &[&v]);
+    }
+    x.zeroize();
+
+    loop {
+        let mut t = Vec::with_capacity(rlen + v.len());
+        while t.len() < rlen {
+            v = hmac::<H>(&k, &[&v]);
+            t.extend_from_slice(&v);
+        }
+        let candidate = bits2int(&t, qlen);
+        t.zeroize();
+        if candidate.iter().any(|&b| b != 0) && candidate < q {
+            k.zeroize();
+            return ScalarField::<S>::from_be_bytes_mod_order(&candidate);
+        }
+        k = hmac::<H>(&k, &[&v, &[0x00]]);
+        v = hmac::<H>(&k, &[&v]);
+    }
+}
+
+/// RFC-6979 `bits2int`: leftmost `qlen` bits of `b`, as `ceil(qlen/8)` big-endian bytes.
+fn bits2int(b: &[u8], qlen: usize) -> Vec<u8> {
+    let rlen = qlen.div_ceil(8);
+    let mut out = ark_std::vec![0; rlen];
+    if b.len() * 8 <= qlen {
+        out[rlen - b.len()..].copy_from_slice(b);
+        return out;
+    }
+    out.copy_from_slice(&b[..rlen]);
+    let shift = rlen * 8 - qlen;
+    if shift > 0 {
+        for i in (0..rlen).rev() {
+            let carry = if i > 0 { out[i - 1] << (8 - shift) } else { 0 };
+            out[i] = (out[i] >> shift) | carry;
+        }
+    }
+    out
+}
+
+/// HMAC (RFC-2104) over the concatenation of `data`.
+fn hmac<H>(key: &[u8], data: &[&[u8]]) -> Vec<u8>
+where
+    H: digest::Digest + digest::core_api::BlockSizeUser + Clone,
+{
+    let block_size = H::block_size();
+    let mut key_block = ark_std::vec![0u8; block_size];
+    if key.len() > block_size {
+        let key = H::digest(key);
+        key_block[..key.len()].copy_from_slice(&key);
+    } else {
+        key_block[..key.len()].copy_from_slice(key);
+    }
+    let mut pad: Vec<u8> = key_block.iter().map(|b| b ^ 0x36).collect();
+    let mut inner = H::new();
+    inner.update(&pad);
+    for d in data {
+        inner.update(d);
+    }
+    let inner = inner.finalize();
+    pad.iter_mut()
+        .zip(key_block.iter())
+        .for_each(|(p, k)| *p = k ^ 0x5c);
+    let mut outer = H::new();
+    outer.update(&pad);
+    outer.update(inner);
+    pad.zeroize();
+    key_block.zeroize();
+    outer.finalize().to_vec()
+}
+
 /// Stateful stream of delinearization scalars backed by a transcript's
 /// squeeze stream.
 ///
@@ -325,12 +979,13 @@ pub(crate) struct DelinearizeScalars<S: Suite> {
 
 impl<S: Suite> DelinearizeScalars<S> {
     /// Create a [`DelinearizeScalars`] stream from a transcript that has already
-    /// absorbed the I/O pairs. Adds domain separation and starts the squeeze.
+    /// absorbed the I/O pairs. Absorbs the domain separation `tag` and starts
+    /// the squeeze.
     ///
     /// The caller must have absorbed the I/O pairs into `transcript` before
     /// calling this function (e.g. via [`absorb_ios`]).
-    pub fn new(mut transcript: S::Transcript) -> DelinearizeScalars<S> {
-        transcript.absorb_raw(&[DomSep::Delinearize as u8]);
+    pub fn new(mut transcript: S::Transcript, tag: u8) -> DelinearizeScalars<S> {
+        transcript.absorb_raw(&[tag]);
         DelinearizeScalars {
             transcript,
             first: true,
@@ -409,6 +1064,21 @@ mod tests {
     use super::*;
     use suites::testing::TestSuite;
 
+    #[test]
+    fn proof_fmt() {
+        use crate::{Input, Secret};
+
+        let secret = Secret::<TestSuite>::from_seed([0; 32]);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = tiny::Prover::prove(&secret, io, b"bar");
+        let buf = encode_compressed(&proof);
+
+        let display = format!("{:08x}:{}", TestSuite::SUITE_ID, Hex(&buf));
+        assert_eq!(proof.to_string(), display);
+        let debug = format!("{proof:?}");
+        assert!(debug.contains(&Hex(&buf[CHALLENGE_LEN..]).to_string()));
+    }
+
     /// Verify that the scheme tag produces distinct transcripts.
     #[test]
     fn scheme_tag_domain_separation() {
@@ -427,7 +1097,7 @@ mod tests {
             .collect();
 
         let (_, io_tiny) = vrf_transcript::<TestSuite>(DomSep::TinyVrf, &ios, b"foo");
-        let (_, io_thin) = vrf_transcript::<TestSuite>(DomSep::ThinVrf, &ios, b"foo");
+        let (_, io_thin) = vrf_transcript::<TestSuite>(thin::Domain::DEFAULT, &ios, b"foo");
         let (_, io_ped) = vrf_transcript::<TestSuite>(DomSep::PedersenVrf, &ios, b"foo");
 
         // Different scheme tags must produce different merged pairs (for n >= 2).
@@ -435,4 +1105,224 @@ mod tests {
         assert_ne!(io_tiny, io_ped);
         assert_ne!(io_thin, io_ped);
     }
+
+    /// With a 32-byte hasher the expanded key is produced by counter-mode
+    /// expansion of the transcript state.
+    #[test]
+    fn nonce_with_short_output_hasher() {
+        use digest::Digest;
+        use sha2::Sha256;
+
+        let sk = ScalarField::<TestSuite>::from(42u64);
+        let nonce = nonce::<TestSuite>(&sk, None);
+
+        let mut t = <TestSuite as Suite>::Transcript::new(TestSuite::SUITE_ID);
+        let mut seed = Sha256::new();
+        seed.update(TestSuite::SUITE_ID.to_bytes());
+        seed.update([DomSep::NonceExpand as u8]);
+        let mut sk_buf = Vec::new();
+        sk.serialize_compressed(&mut sk_buf).unwrap();
+        seed.update(&sk_buf);
+        let seed = seed.finalize();
+        let mut sk_hash = Vec::new();
+        for i in 0u32..2 {
+            sk_hash.extend_from_slice(
+                &Sha256::new()
+                    .chain_update(seed)
+                    .chain_update(i.to_le_bytes())
+                    .finalize(),
+            );
+        }
+        t.absorb_raw(&[DomSep::Nonce as u8]);
+        t.absorb_raw(&sk_hash);
+        assert_eq!(nonce, nonce_scalar::<TestSuite>(&mut t));
+    }
+
+    #[cfg(feature = "bandersnatch")]
+    #[test]
+    fn torsion_checks() {
+        use ark_ff::One;
+        use suites::bandersnatch::{AffinePoint, BandersnatchSha512Ell2 as S, BaseField};
+
+        // Order 2 point of twisted Edwards curves.
+        let t = AffinePoint::new_unchecked(BaseField::zero(), -BaseField::one());
+        let g = S::generator();
+        let p = (g + t).into_affine();
+        assert!(is_prime_order::<S>(&g));
+        assert!(is_prime_order::<S>(&AffinePoint::zero()));
+        assert!(!is_prime_order::<S>(&t));
+        assert!(!is_prime_order::<S>(&p));
+        assert!(is_small_order::<S>(&t));
+        assert!(is_small_order::<S>(&AffinePoint::zero()));
+        assert!(!is_small_order::<S>(&g));
+        assert!(!is_small_order::<S>(&p));
+        assert!(clear_cofactor::<S>(&t).is_zero());
+        assert_eq!(clear_cofactor::<S>(&p), clear_cofactor::<S>(&g));
+        assert!(is_prime_order::<S>(&clear_cofactor::<S>(&p)));
+    }
+
+    #[test]
+    fn uniform_derivation() {
+        type F = ScalarField<TestSuite>;
+        assert_eq!(F::UNIFORM_LEN, expanded_scalar_len::<TestSuite>(128));
+        let bytes = [0xAB; 64];
+        let len = F::UNIFORM_LEN;
+        assert_eq!(
+            F::from_uniform_bytes(&bytes[..len]),
+            F::from_le_bytes_mod_order(&bytes[..len])
+        );
+        assert_eq!(
+            field_from_le_bytes_ct::<F>(&bytes),
+            F::from_le_bytes_mod_order(&bytes)
+        );
+        let short = std::panic::catch_unwind(|| F::from_uniform_bytes(&bytes[..len - 1]));
+        assert!(short.is_err());
+
+        let h = hash_to_scalar::<TestSuite>(b"app", b"data");
+        assert_eq!(h, hash_to_scalar::<TestSuite>(b"app", b"data"));
+        assert_ne!(h, hash_to_scalar::<TestSuite>(b"appd", b"ata"));
+        assert_ne!(h, hash_to_scalar::<TestSuite>(b"", b"appdata"));
+    }
+
+    #[test]
+    fn secret_scalar_decoding() {
+        use ark_ff::{BigInteger, One};
+        type F = ScalarField<TestSuite>;
+        let decode = decode_secret_scalar::<TestSuite>;
+        let len = scalar_len::<TestSuite>();
+        let modulus = F::MODULUS.to_bytes_le();
+
+        let x = F::from(0x1234_u64) - F::one();
+        let bytes = encode_compressed(&x);
+        assert_eq!(decode(&bytes).unwrap(), x);
+        assert_eq!(decode(&bytes).ok(), decode_canonical::<F>(&bytes).ok());
+        let max = encode_compressed(&-F::one());
+        assert_eq!(decode(&max).unwrap(), -F::one());
+
+        assert!(decode(&modulus[..len]).is_err());
+        assert!(decode(&vec![0; len]).is_err());
+        assert!(decode(&vec![0xFF; len]).is_err());
+        assert!(decode(&bytes[..len - 1]).is_err());
+        assert!(decode(&[&bytes[..], &[0]].concat()).is_err());
+
+        let mut reader = &bytes[..];
+        assert_eq!(read_secret_scalar::<TestSuite>(&mut reader).unwrap(), x);
+        assert!(read_secret_scalar::<TestSuite>(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn encoded_challenge_matches_points() {
+        let pts = [
+            TestSuite::generator(),
+            TestSuite::data_to_point(b"foo").unwrap(),
+        ];
+        let encoded = pts.map(|p| encode_compressed(&p));
+        let t = <TestSuite as Suite>::Transcript::new(TestSuite::SUITE_ID);
+        assert_eq!(
+            challenge_encoded::<TestSuite>(&[&encoded[0], &encoded[1]], Some(t.clone())),
+            challenge::<TestSuite>(&[&pts[0], &pts[1]], Some(t))
+        );
+        assert_ne!(
+            challenge_encoded::<TestSuite>(&[&encoded[1], &encoded[0]], None),
+            challenge::<TestSuite>(&[&pts[0], &pts[1]], None)
+        );
+    }
+
+    /// Suite opting in to the framed challenge.
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct FramedSuite;
+
+    impl Suite for FramedSuite {
+        const SUITE_ID: suites::SuiteId = TestSuite::SUITE_ID;
+        type Affine = <TestSuite as Suite>::Affine;
+        type Transcript = <TestSuite as Suite>::Transcript;
+
+        fn challenge(
+            pts: &[&AffinePoint<Self>],
+            transcript: Option<Self::Transcript>,
+        ) -> ScalarField<Self> {
+            challenge_framed::<Self>(pts, transcript)
+        }
+    }
+
+    #[test]
+    fn framed_challenge_suite() {
+        use crate::tiny::{Prover, Verifier};
+
+        let pts = [
+            TestSuite::generator(),
+            TestSuite::data_to_point(b"foo").unwrap(),
+        ];
+        let pts = [&pts[0], &pts[1]];
+        assert_ne!(
+            challenge::<TestSuite>(&pts, None),
+            challenge_framed::<TestSuite>(&pts, None)
+        );
+
+        let secret = Secret::<FramedSuite>::from_seed([0; 32]);
+        let io = secret.vrf_io(Input::new(b"bar").unwrap());
+        let proof = secret.prove(io, b"ad");
+        assert!(secret.public().verify(io, b"ad", &proof).is_ok());
+        assert!(secret.public().verify(io, b"da", &proof).is_err());
+
+        // Same keys and points, default framing.
+        let plain = Secret::<TestSuite>::from_seed([0; 32]);
+        let plain_io = VrfIo {
+            input: Input(io.input.0),
+            output: Output(io.output.0),
+        };
+        let plain_proof = crate::tiny::Proof::<TestSuite> {
+            c: proof.c,
+            s: proof.s,
+        };
+        assert!(
+            plain
+                .public()
+                .verify(plain_io, b"ad", &plain_proof)
+                .is_err()
+        );
+    }
+
+    /// Suite opting in to the pre-hashed additional data.
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct PrehashSuite;
+
+    impl Suite for PrehashSuite {
+        const SUITE_ID: suites::SuiteId = TestSuite::SUITE_ID;
+        type Affine = <TestSuite as Suite>::Affine;
+        type Transcript = <TestSuite as Suite>::Transcript;
+
+        fn absorb_ad(transcript: &mut Self::Transcript, ad: &[u8]) {
+            absorb_ad_prehashed::<Self>(transcript, ad)
+        }
+    }
+
+    #[test]
+    fn prehashed_ad_suite() {
+        use crate::thin::{Prover, Verifier};
+
+        let ad = vec![0xAB; 1 << 20];
+        assert_ne!(
+            ad_digest::<TestSuite>(&ad),
+            ad_digest::<TestSuite>(&ad[1..])
+        );
+
+        let secret = Secret::<PrehashSuite>::from_seed([0; 32]);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, &ad);
+        assert!(secret.public().verify(io, &ad, &proof).is_ok());
+        assert!(secret.public().verify(io, &ad[1..], &proof).is_err());
+
+        // Same keys and points, raw additional data.
+        let plain = Public::<TestSuite>(secret.public().0);
+        let plain_io = VrfIo {
+            input: Input(io.input.0),
+            output: Output(io.output.0),
+        };
+        let plain_proof = crate::thin::Proof::<TestSuite> {
+            r: proof.r,
+            s: proof.s,
+        };
+        assert!(plain.verify(plain_io, &ad, &plain_proof).is_err());
+    }
 }
@@ -0,0 +1,142 @@
+//! Proof size and cost model.
+//!
+//! Serialized sizes of the proofs of each scheme and the number of scalar
+//! multiplications performed to prove and verify them, to budget bandwidth
+//! and verification weight without running the schemes.
+//!
+//! Scalar multiplications are counted as MSM terms (an MSM of `k` points
+//! counts as `k`), for a single proof over `n` I/O pairs. Field operations,
+//! hashing and hash-to-curve of the inputs are not accounted.
+
+use crate::*;
+use utils::common::scalar_len;
+
+/// Proof cost for a given number of I/O pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cost {
+    /// Compressed proof length in bytes.
+    pub encoded_len: usize,
+    /// Scalar multiplications performed by the prover.
+    pub prover_muls: usize,
+    /// Scalar multiplications performed by the verifier.
+    pub verifier_muls: usize,
+}
+
+/// Compressed point length in bytes.
+pub fn point_len<S: Suite>() -> usize {
+    S::generator().compressed_size()
+}
+
+/// Scalar multiplications merging `n` I/O pairs into one.
+///
+/// Two MSMs of `n` terms (inputs and outputs), skipped for a single pair.
+pub const fn merge_muls(n: usize) -> usize {
+    if n > 1 { 2 * n } else { 0 }
+}
+
+impl Cost {
+    /// Tiny VRF cost.
+    ///
+    /// The public key pair is merged with the I/O pairs. The prover computes
+    /// the nonce commitment, the verifier recomputes it from the merged pair.
+    pub fn tiny<S: tiny::TinySuite>(n: usize) -> Self {
+        Self {
+            encoded_len: tiny::Proof::<S>::ENCODED_LEN,
+            prover_muls: merge_muls(n + 1) + 1,
+            verifier_muls: merge_muls(n + 1) + 2,
+        }
+    }
+
+    /// Thin VRF cost.
+    ///
+    /// Same operations as [`Self::tiny`], with the nonce commitment point in
+    /// place of the challenge.
+    pub fn thin<S: thin::ThinVrfSuite>(n: usize) -> Self {
+        Self {
+            encoded_len: point_len::<S>() + scalar_len::<S>(),
+            prover_muls: merge_muls(n + 1) + 1,
+            verifier_muls: merge_muls(n + 1) + 2,
+        }
+    }
+
+    /// Pedersen VRF cost.
+    ///
+    /// The prover computes the key commitment blinding and the three nonce
+    /// commitment terms, the verifier checks the output and key equations.
+    /// Precomputed generator tables don't change the counts, only the cost of
+    /// the fixed-base terms.
+    pub fn pedersen<S: pedersen::PedersenSuite>(n: usize) -> Self {
+        Self {
+            encoded_len: 3 * point_len::<S>() + 2 * scalar_len::<S>(),
+            prover_muls: merge_muls(n) + 4,
+            verifier_muls: merge_muls(n) + 5,
+        }
+    }
+
+    /// Ring VRF cost.
+    ///
+    /// The ring proof has constant size, independent of the ring and domain
+    /// size: 7 commitments in `G1` and 8 evaluations in the suite base field.
+    ///
+    /// Only the Pedersen part is accounted in the scalar multiplication counts.
+    /// The ring prover cost is dominated by MSMs and FFTs over the PIOP domain
+    /// (see [`ring::piop_domain_size`]), the ring verifier one by two pairings.
+    #[cfg(feature = "ring")]
+    pub fn ring<S: ring::RingSuite>(n: usize) -> Self {
+        let g1_len = ring::G1Affine::<S>::generator().compressed_size();
+        let field_len = BaseField::<S>::zero().compressed_size();
+        let pedersen = Self::pedersen::<S>(n);
+        Self {
+            encoded_len: pedersen.encoded_len + 7 * g1_len + 8 * field_len,
+            ..pedersen
+        }
+    }
+}
+
+/// Compressed PCS parameters length in bytes.
+///
+/// `pcs_domain_size` powers in `G1` and two in `G2`, each vector prefixed by
+/// its length (see [`ring::pcs_domain_size`]).
+#[cfg(feature = "ring")]
+pub fn pcs_params_len<S: ring::RingSuite>(pcs_domain_size: usize) -> usize {
+    let g1_len = ring::G1Affine::<S>::generator().compressed_size();
+    let g2_len = ring::G2Affine::<S>::generator().compressed_size();
+    8 + pcs_domain_size * g1_len + 8 + 2 * g2_len
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+
+    #[test]
+    fn proof_lengths() {
+        let secret = Secret::<S>::from_seed([0; 32]);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+
+        let proof = tiny::Prover::prove(&secret, io, b"");
+        assert_eq!(proof.compressed_size(), Cost::tiny::<S>(1).encoded_len);
+
+        let proof = thin::Prover::prove(&secret, io, b"");
+        assert_eq!(proof.compressed_size(), Cost::thin::<S>(1).encoded_len);
+
+        let (proof, _) = pedersen::Prover::prove(&secret, io, b"");
+        assert_eq!(proof.compressed_size(), Cost::pedersen::<S>(1).encoded_len);
+        assert_eq!(Cost::pedersen::<S>(1).encoded_len, 160);
+    }
+
+    #[test]
+    fn muls() {
+        assert_eq!(Cost::tiny::<S>(1).prover_muls, 5);
+        assert_eq!(Cost::tiny::<S>(0).verifier_muls, 2);
+        assert_eq!(Cost::pedersen::<S>(1).verifier_muls, 5);
+        assert_eq!(Cost::pedersen::<S>(3).prover_muls, 10);
+    }
+
+    #[cfg(feature = "ring")]
+    #[test]
+    fn ring_lengths() {
+        assert_eq!(Cost::ring::<S>(1).encoded_len, 160 + 592);
+        assert_eq!(pcs_params_len::<S>(ring::pcs_domain_size::<S>(1)), 73984);
+    }
+}
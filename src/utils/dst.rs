@@ -0,0 +1,147 @@
+//! # Domain separation tags
+//!
+//! Registry of the single byte tags absorbed first by every hash computed by
+//! the crate protocols, so that hashes of different protocol steps never
+//! collide even when absorbing the same data.
+//!
+//! Tags are namespaced per suite: all the transcripts are constructed from
+//! the suite identifier (see [`Transcript::new`](super::Transcript::new)), so
+//! the same tag used by two suites still yields unrelated hashes.
+//!
+//! Tag ranges:
+//! - `0x00..=0x7F`: crate protocols (the constants below).
+//! - `0x80..=0xEF`: suites and applications extending the crate protocols
+//!   (see [`suite_tag`]). Never used by the crate.
+//! - `0xF0..=0xFF`: crate hash-to-curve procedures.
+
+use core::ops::RangeInclusive;
+
+/// Tiny VRF transcript.
+pub const TINY_VRF: u8 = 0x00;
+/// Thin VRF transcript.
+pub const THIN_VRF: u8 = 0x01;
+/// Pedersen VRF transcript.
+pub const PEDERSEN_VRF: u8 = 0x02;
+/// Nonce secret key expansion.
+pub const NONCE_EXPAND: u8 = 0x10;
+/// Nonce derivation.
+pub const NONCE: u8 = 0x11;
+/// Pedersen key commitment blinding factor derivation.
+pub const PEDERSEN_BLINDING: u8 = 0x12;
+/// Application data hashing to scalar.
+pub const HASH_TO_SCALAR: u8 = 0x13;
+/// Child secret key derivation.
+pub const CHILD_KEY: u8 = 0x14;
+/// VRF output point hashing.
+pub const POINT_TO_HASH: u8 = 0x20;
+/// Contextual VRF input framing.
+pub const INPUT_CONTEXT: u8 = 0x21;
+/// VRF output point hashing bound to the hash length.
+pub const POINT_TO_HASH_LEN: u8 = 0x22;
+/// Keyed PRF hash-to-curve (replaces the Try-And-Increment tag).
+pub const PRF: u8 = 0x23;
+/// Additional data pre-hashing.
+pub const AD_PREHASH: u8 = 0x24;
+/// VRF output point hashing to scalar.
+pub const OUTPUT_SCALAR: u8 = 0x25;
+/// VRF output point hashing to base field element.
+pub const OUTPUT_BASE_FIELD: u8 = 0x26;
+/// Salted VRF input framing.
+pub const INPUT_SALT: u8 = 0x27;
+/// Delinearization scalars of multiple I/O pairs.
+pub const DELINEARIZE: u8 = 0x30;
+/// Challenge derivation.
+pub const CHALLENGE: u8 = 0x40;
+/// Challenge derivation with length framed points.
+pub const CHALLENGE_FRAMED: u8 = 0x41;
+/// Thin VRF batch verification weights.
+pub const THIN_BATCH: u8 = 0x50;
+/// Pedersen VRF batch verification weights.
+pub const PEDERSEN_BATCH: u8 = 0x51;
+/// Ring commitment identifier.
+pub const RING_COMMITMENT: u8 = 0x60;
+/// SRS ceremony contributor secret derivation.
+pub const SRS_TAU: u8 = 0x61;
+/// SRS ceremony contribution hashing.
+pub const SRS_CONTRIBUTION: u8 = 0x62;
+/// SRS ceremony parameters check weights.
+pub const SRS_CHECK: u8 = 0x63;
+/// Ring membership proof columns batching.
+pub const RING_MEMBERSHIP: u8 = 0x64;
+/// Sortition draws from VRF outputs.
+pub const SORTITION: u8 = 0x70;
+/// Shuffling seed from VRF outputs.
+pub const SHUFFLE: u8 = 0x71;
+/// Try-and-increment hash-to-curve.
+pub const HASH_TO_CURVE_TAI: u8 = 0xFE;
+
+/// All the crate tags, by name.
+pub const REGISTRY: &[(&str, u8)] = &[
+    ("TINY_VRF", TINY_VRF),
+    ("THIN_VRF", THIN_VRF),
+    ("PEDERSEN_VRF", PEDERSEN_VRF),
+    ("NONCE_EXPAND", NONCE_EXPAND),
+    ("NONCE", NONCE),
+    ("PEDERSEN_BLINDING", PEDERSEN_BLINDING),
+    ("HASH_TO_SCALAR", HASH_TO_SCALAR),
+    ("CHILD_KEY", CHILD_KEY),
+    ("POINT_TO_HASH", POINT_TO_HASH),
+    ("INPUT_CONTEXT", INPUT_CONTEXT),
+    ("POINT_TO_HASH_LEN", POINT_TO_HASH_LEN),
+    ("PRF", PRF),
+    ("AD_PREHASH", AD_PREHASH),
+    ("OUTPUT_SCALAR", OUTPUT_SCALAR),
+    ("OUTPUT_BASE_FIELD", OUTPUT_BASE_FIELD),
+    ("INPUT_SALT", INPUT_SALT),
+    ("DELINEARIZE", DELINEARIZE),
+    ("CHALLENGE", CHALLENGE),
+    ("CHALLENGE_FRAMED", CHALLENGE_FRAMED),
+    ("THIN_BATCH", THIN_BATCH),
+    ("PEDERSEN_BATCH", PEDERSEN_BATCH),
+    ("RING_COMMITMENT", RING_COMMITMENT),
+    ("SRS_TAU", SRS_TAU),
+    ("SRS_CONTRIBUTION", SRS_CONTRIBUTION),
+    ("SRS_CHECK", SRS_CHECK),
+    ("RING_MEMBERSHIP", RING_MEMBERSHIP),
+    ("SORTITION", SORTITION),
+    ("SHUFFLE", SHUFFLE),
+    ("HASH_TO_CURVE_TAI", HASH_TO_CURVE_TAI),
+];
+
+/// Tags reserved to suites and applications.
+pub const SUITE_TAGS: RangeInclusive<u8> = 0x80..=0xEF;
+
+/// The `index`-th tag of [`SUITE_TAGS`].
+///
+/// Panics (at compile time in const contexts) if `index` is out of range.
+pub const fn suite_tag(index: u8) -> u8 {
+    assert!(index <= *SUITE_TAGS.end() - *SUITE_TAGS.start());
+    *SUITE_TAGS.start() + index
+}
+
+/// Name of `tag` in the [`REGISTRY`], if used by the crate.
+pub fn name(tag: u8) -> Option<&'static str> {
+    REGISTRY
+        .iter()
+        .find(|(_, value)| *value == tag)
+        .map(|(name, _)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry() {
+        for (i, (name_i, tag_i)) in REGISTRY.iter().enumerate() {
+            assert!(!SUITE_TAGS.contains(tag_i), "{name_i} in suite range");
+            for (name_j, tag_j) in &REGISTRY[i + 1..] {
+                assert_ne!(tag_i, tag_j, "{name_i} collides with {name_j}");
+            }
+            assert_eq!(name(*tag_i), Some(*name_i));
+        }
+        assert_eq!(suite_tag(0), 0x80);
+        assert_eq!(suite_tag(0x6F), 0xEF);
+        assert_eq!(name(suite_tag(1)), None);
+    }
+}
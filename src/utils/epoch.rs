@@ -0,0 +1,108 @@
+//! # Epoch binding
+//!
+//! Standard layout binding proofs to an epoch (or round, slot, ...) through
+//! the additional data, so that a proof produced for an epoch never verifies
+//! in another one.
+//!
+//! The bound additional data is the epoch as a little-endian `u64`, followed
+//! by the application additional data. The verifier rebuilds it from its own
+//! epoch, so that replaying an old proof fails verification without any
+//! application specific encoding.
+//!
+//! An application should either bind all its proofs under a key or none:
+//! the layout is not distinguishable from unbound additional data starting
+//! with eight arbitrary bytes.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::utils::epoch;
+//!
+//! // Prover
+//! let proof = secret.prove(io, epoch::bind(epoch, ad));
+//!
+//! // Verifier
+//! epoch::verify_in_epoch(&public, io, ad, &proof, current_epoch)?;
+//! ```
+
+use crate::*;
+
+/// Length in bytes of the epoch prefix.
+pub const EPOCH_LEN: usize = 8;
+
+/// Additional data `ad` bound to `epoch`.
+pub fn bind(epoch: u64, ad: &[u8]) -> Vec<u8> {
+    [&epoch.to_le_bytes()[..], ad].concat()
+}
+
+/// Split bound additional data into the epoch and the application data.
+///
+/// Returns `None` if `ad` is shorter than the epoch prefix.
+pub fn split(ad: &[u8]) -> Option<(u64, &[u8])> {
+    let (epoch, ad) = ad.split_first_chunk::<EPOCH_LEN>()?;
+    Some((u64::from_le_bytes(*epoch), ad))
+}
+
+/// Verify `proof` for `io` and the additional data `ad` bound to `epoch`.
+///
+/// Returns `Error::VerificationFailure` if the proof is invalid, in
+/// particular if it was produced for another epoch.
+pub fn verify_in_epoch<S: Suite, P: OutputProof<S>>(
+    public: &Public<S>,
+    io: VrfIo<S>,
+    ad: &[u8],
+    proof: &P,
+    epoch: u64,
+) -> Result<(), Error> {
+    proof.verify_output(public, io, &bind(epoch, ad))
+}
+
+/// Verify `proof` produced at `epoch`, accepting it up to `lifetime` epochs
+/// after it.
+///
+/// Same as [`verify_in_epoch`], additionally returning
+/// `Error::VerificationFailure` if `epoch` is after `current` or expired,
+/// i.e. more than `lifetime` epochs before it.
+pub fn verify_unexpired<S: Suite, P: OutputProof<S>>(
+    public: &Public<S>,
+    io: VrfIo<S>,
+    ad: &[u8],
+    proof: &P,
+    epoch: u64,
+    current: u64,
+    lifetime: u64,
+) -> Result<(), Error> {
+    if epoch > current || current - epoch > lifetime {
+        return Err(Error::VerificationFailure);
+    }
+    verify_in_epoch(public, io, ad, proof, epoch)
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+    use crate::tiny::Prover;
+
+    #[test]
+    fn epoch_binding() {
+        let secret = Secret::<S>::from_seed([0; 32]);
+        let public = secret.public();
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+
+        let ad = bind(5, b"bar");
+        assert_eq!(split(&ad), Some((5, &b"bar"[..])));
+        assert_eq!(split(&ad[..EPOCH_LEN - 1]), None);
+
+        let proof = secret.prove(io, &ad);
+        assert!(verify_in_epoch(&public, io, b"bar", &proof, 5).is_ok());
+        assert!(verify_in_epoch(&public, io, b"bar", &proof, 6).is_err());
+        assert!(verify_in_epoch(&public, io, b"baz", &proof, 5).is_err());
+
+        let verify = |current| verify_unexpired(&public, io, b"bar", &proof, 5, current, 2);
+        assert!(verify(5).is_ok());
+        assert!(verify(7).is_ok());
+        assert_eq!(verify(8), Err(Error::VerificationFailure));
+        assert_eq!(verify(4), Err(Error::VerificationFailure));
+    }
+}
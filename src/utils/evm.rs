@@ -0,0 +1,189 @@
+//! # EVM proof encoding
+//!
+//! Lays out proofs as sequences of 32-byte big-endian words, the format
+//! expected by Solidity verifier contracts (`abi.decode` of `uint256`s),
+//! instead of the arkworks compressed encoding.
+//!
+//! - A field element or scalar is a single word.
+//! - A point is uncompressed: affine `x` word followed by affine `y` word.
+//!   The point at infinity has no affine form and can't be encoded.
+//!
+//! Only suites whose base and scalar fields fit in 256 bits can be encoded
+//! (e.g. secp256r1, whose arithmetic is natively available on the EVM through
+//! the P-256 precompile, and the Bandersnatch suites, whose coordinates live in
+//! the BLS12-381 scalar field). Other suites return `Error::InvalidData`.
+//!
+//! The VRF input point is included in every layout: contracts are expected to
+//! recompute it from the input data and compare, or to trust it when the
+//! input is checked by other means.
+//!
+//! Fixtures for the Bandersnatch suites, with key seed, input data, additional
+//! data and the encoding of each proof type, are in `data/evm`.
+
+use crate::*;
+use ark_ff::BigInteger;
+
+/// Length of an EVM word.
+pub const WORD_LEN: usize = 32;
+
+/// Encode a prime field element as a big-endian word.
+///
+/// Returns `Error::InvalidData` if the field modulus exceeds 256 bits.
+pub fn field_word<F: PrimeField>(value: &F) -> Result<[u8; WORD_LEN], Error> {
+    if F::MODULUS_BIT_SIZE as usize > 8 * WORD_LEN {
+        return Err(Error::InvalidData);
+    }
+    let bytes = value.into_bigint().to_bytes_be();
+    let (pad, digits) = bytes.split_at(bytes.len().saturating_sub(WORD_LEN));
+    debug_assert!(pad.iter().all(|&b| b == 0));
+    let mut word = [0; WORD_LEN];
+    word[WORD_LEN - digits.len()..].copy_from_slice(digits);
+    Ok(word)
+}
+
+/// Encode a point as two words, affine `x` followed by affine `y`.
+///
+/// Returns `Error::IdentityPoint` for the point at infinity and
+/// `Error::InvalidData` if the base field exceeds 256 bits.
+pub fn point_words<S: Suite>(point: &AffinePoint<S>) -> Result<[u8; 2 * WORD_LEN], Error>
+where
+    BaseField<S>: PrimeField,
+{
+    let (x, y) = point.xy().ok_or(Error::IdentityPoint)?;
+    let mut words = [0; 2 * WORD_LEN];
+    words[..WORD_LEN].copy_from_slice(&field_word(&x)?);
+    words[WORD_LEN..].copy_from_slice(&field_word(&y)?);
+    Ok(words)
+}
+
+/// Encode a Tiny VRF proof.
+///
+/// Layout: `pk.x, pk.y, input.x, input.y, output.x, output.y, c, s`.
+pub fn encode_tiny<S: Suite>(
+    public: &Public<S>,
+    io: &VrfIo<S>,
+    proof: &tiny::Proof<S>,
+) -> Result<Vec<u8>, Error>
+where
+    BaseField<S>: PrimeField,
+{
+    let mut buf = Vec::with_capacity(8 * WORD_LEN);
+    buf.extend(point_words::<S>(&public.0)?);
+    buf.extend(point_words::<S>(&io.input.0)?);
+    buf.extend(point_words::<S>(&io.output.0)?);
+    buf.extend(field_word(&proof.c)?);
+    buf.extend(field_word(&proof.s)?);
+    Ok(buf)
+}
+
+/// Encode a Thin VRF proof.
+///
+/// Layout: `pk.x, pk.y, input.x, input.y, output.x, output.y, r.x, r.y, s`.
+pub fn encode_thin<S: thin::ThinVrfSuite>(
+    public: &Public<S>,
+    io: &VrfIo<S>,
+    proof: &thin::Proof<S>,
+) -> Result<Vec<u8>, Error>
+where
+    BaseField<S>: PrimeField,
+{
+    let mut buf = Vec::with_capacity(9 * WORD_LEN);
+    buf.extend(point_words::<S>(&public.0)?);
+    buf.extend(point_words::<S>(&io.input.0)?);
+    buf.extend(point_words::<S>(&io.output.0)?);
+    buf.extend(point_words::<S>(&proof.r)?);
+    buf.extend(field_word(&proof.s)?);
+    Ok(buf)
+}
+
+/// Encode a Pedersen VRF proof.
+///
+/// Layout: `input.x, input.y, output.x, output.y, pk_com.x, pk_com.y, r.x,
+/// r.y, ok.x, ok.y, s, sb`.
+pub fn encode_pedersen<S: pedersen::PedersenSuite>(
+    io: &VrfIo<S>,
+    proof: &pedersen::Proof<S>,
+) -> Result<Vec<u8>, Error>
+where
+    BaseField<S>: PrimeField,
+{
+    let mut buf = Vec::with_capacity(12 * WORD_LEN);
+    buf.extend(point_words::<S>(&io.input.0)?);
+    buf.extend(point_words::<S>(&io.output.0)?);
+    buf.extend(point_words::<S>(&proof.pk_com)?);
+    buf.extend(point_words::<S>(&proof.r)?);
+    buf.extend(point_words::<S>(&proof.ok)?);
+    buf.extend(field_word(&proof.s)?);
+    buf.extend(field_word(&proof.sb)?);
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_SEED;
+    use suites::testing::TestSuite;
+
+    #[test]
+    fn tiny_layout() {
+        use tiny::Prover;
+        let secret = Secret::<TestSuite>::from_seed(TEST_SEED);
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let proof = secret.prove(io, b"bar");
+        let buf = encode_tiny(&secret.public(), &io, &proof).unwrap();
+        assert_eq!(buf.len(), 8 * WORD_LEN);
+
+        let words: Vec<_> = buf.chunks(WORD_LEN).collect();
+        let (x, y) = io.output.0.xy().unwrap();
+        assert_eq!(BaseField::<TestSuite>::from_be_bytes_mod_order(words[4]), x);
+        assert_eq!(BaseField::<TestSuite>::from_be_bytes_mod_order(words[5]), y);
+        assert_eq!(
+            ScalarField::<TestSuite>::from_be_bytes_mod_order(words[6]),
+            proof.c
+        );
+        assert_eq!(
+            ScalarField::<TestSuite>::from_be_bytes_mod_order(words[7]),
+            proof.s
+        );
+
+        let identity = AffinePoint::<TestSuite>::zero();
+        assert_eq!(
+            point_words::<TestSuite>(&identity),
+            Err(Error::IdentityPoint)
+        );
+    }
+
+    #[allow(unused)]
+    fn check_fixtures<S: pedersen::PedersenSuite>(name: &str)
+    where
+        BaseField<S>: PrimeField,
+    {
+        let path = format!("{}/data/evm/{name}.json", env!("CARGO_MANIFEST_DIR"));
+        let json = std::fs::read_to_string(path).unwrap();
+        let fixtures: Vec<std::collections::HashMap<String, String>> =
+            serde_json::from_str(&json).unwrap();
+        for fixture in fixtures {
+            let field = |name: &str| hex::decode(&fixture[name]).unwrap();
+            let secret = Secret::<S>::from_seed(field("seed").try_into().unwrap());
+            let public = secret.public();
+            let io = secret.vrf_io(Input::new(&field("alpha")).unwrap());
+            let ad = field("ad");
+            let proof = tiny::Prover::prove(&secret, io, &ad);
+            assert_eq!(encode_tiny(&public, &io, &proof).unwrap(), field("tiny"));
+            let proof = thin::Prover::prove(&secret, io, &ad);
+            assert_eq!(encode_thin(&public, &io, &proof).unwrap(), field("thin"));
+            let (proof, _) = pedersen::Prover::prove(&secret, io, &ad);
+            assert_eq!(encode_pedersen(&io, &proof).unwrap(), field("pedersen"));
+        }
+    }
+
+    #[cfg(feature = "bandersnatch")]
+    #[test]
+    fn bandersnatch_fixtures() {
+        use suites::{
+            bandersnatch::BandersnatchSha512Ell2, bandersnatch_sw::BandersnatchSha512Tai,
+        };
+        check_fixtures::<BandersnatchSha512Ell2>("bandersnatch_sha-512_ell2");
+        check_fixtures::<BandersnatchSha512Tai>("bandersnatch_sw_sha-512_tai");
+    }
+}
@@ -0,0 +1,404 @@
+//! # Fixed-base scalar multiplication tables.
+//!
+//! Windowed precomputation for points which are multiplied by many different
+//! scalars over the lifetime of a process, such as the suite generator and the
+//! Pedersen blinding base.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::suites::bandersnatch::*;
+//! use ark_vrf::pedersen::{Prover, Verifier};
+//! use ark_vrf::utils::SuitePrecomp;
+//!
+//! // Build once per process and reuse.
+//! let precomp = SuitePrecomp::<BandersnatchSha512Ell2>::new_pedersen();
+//!
+//! let (proof, _) = secret.prove_with_precomp(io, b"ad", &precomp);
+//! let result = Public::verify_with_precomp(io, b"ad", &proof, &precomp);
+//! ```
+//!
+//! ## Static tables
+//!
+//! With the `static-tables` feature the generator and blinding base tables of
+//! the built-in suites are also built on first use and kept for the rest of
+//! the process (see [`Suite::generator_table`] and [`StaticTable`]). They are
+//! used by [`Secret::from_scalar`] and, when no [`SuitePrecomp`] is given, by
+//! the Pedersen prover and verifier. Secret scalars go through
+//! [`FixedBaseTable::mul_secret`].
+//!
+//! Tiny and Thin fold the generator into the merged I/O pair, so they don't
+//! multiply it on its own and don't use the tables: their verifiers use
+//! [`VerifierContext`] instead.
+//!
+//! Each table takes about `43 * 64` points, so the feature is opt-in; without
+//! it the same operations use plain scalar multiplications.
+
+use crate::pedersen::PedersenSuite;
+use crate::*;
+use ark_ec::{AdditiveGroup, AffineRepr};
+use ark_ff::BigInteger;
+
+/// Default window size (in bits) for [`FixedBaseTable`].
+///
+/// With 6-bit windows a ~256-bit scalar multiplication costs ~43 mixed
+/// additions and the table holds `43 * 64` affine points.
+pub const FIXED_BASE_WINDOW: usize = 6;
+
+/// Windowed multiples of a fixed base point.
+///
+/// For a base `P` and window size `w`, row `i` holds `j * 2^(i*w) * P` for
+/// `j` in `[0, 2^w)`. A scalar multiplication is then one table lookup and
+/// one mixed addition per window, with no doublings.
+///
+/// [`Self::mul`] reads the entry indexed by the scalar window and is meant for
+/// public scalars only (e.g. verifier responses). [`Self::mul_secret`] scans
+/// the whole row and selects the entry encoding with masks.
+#[derive(Clone)]
+pub struct FixedBaseTable<S: Suite> {
+    window: usize,
+    // Rows of `2^window` entries.
+    table: Vec<Vec<AffinePoint<S>>>,
+}
+
+impl<S: Suite> FixedBaseTable<S> {
+    /// Build a table for `base` using [`FIXED_BASE_WINDOW`].
+    pub fn new(base: AffinePoint<S>) -> Self {
+        Self::with_window(base, FIXED_BASE_WINDOW)
+    }
+
+    /// Build a table for `base` using a custom window size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not in `[1, 16]`.
+    pub fn with_window(base: AffinePoint<S>, window: usize) -> Self {
+        assert!((1..=16).contains(&window), "window size must be in [1, 16]");
+        let bits = ScalarField::<S>::MODULUS_BIT_SIZE as usize;
+        let rows = bits.div_ceil(window);
+        let mut row_base = base.into_group();
+        let table = (0..rows)
+            .map(|_| {
+                let mut acc = AffinePoint::<S>::zero().into_group();
+                let row: Vec<_> = (0..1usize << window)
+                    .map(|_| {
+                        let curr = acc;
+                        acc += row_base;
+                        curr
+                    })
+                    .collect();
+                for _ in 0..window {
+                    row_base.double_in_place();
+                }
+                CurveGroup::normalize_batch(&row)
+            })
+            .collect();
+        Self { window, table }
+    }
+
+    /// Window size used by this table.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Base point.
+    pub fn base(&self) -> AffinePoint<S> {
+        self.table[0][1]
+    }
+
+    /// Multiply the base point by `scalar`.
+    ///
+    /// The table entries read depend on `scalar`: use [`Self::mul_secret`]
+    /// for secret scalars.
+    pub fn mul(&self, scalar: &ScalarField<S>) -> <AffinePoint<S> as AffineRepr>::Group {
+        self.windows(scalar)
+            .fold(AffinePoint::<S>::zero().into_group(), |acc, (row, idx)| {
+                acc + row[idx]
+            })
+    }
+
+    /// Multiply the base point by the secret `scalar`.
+    ///
+    /// Same as [`Self::mul`], but each row is scanned in full and the entry
+    /// encoding selected with masks, so that the memory access pattern
+    /// doesn't depend on `scalar`. The point additions are the same as for a
+    /// plain scalar multiplication.
+    pub fn mul_secret(&self, scalar: &ScalarField<S>) -> <AffinePoint<S> as AffineRepr>::Group {
+        let entry_len = self.base().uncompressed_size();
+        let mut buf = Vec::with_capacity(entry_len);
+        let mut entry = ark_std::vec![0u8; entry_len];
+        self.windows(scalar)
+            .map(|(row, idx)| {
+                entry.fill(0);
+                for (j, curr) in row.iter().enumerate() {
+                    let mask = ct_eq_mask(j, idx);
+                    buf.clear();
+                    curr.serialize_uncompressed(&mut buf)
+                        .expect("Serialization into a vector can't fail");
+                    for (byte, curr) in entry.iter_mut().zip(&buf) {
+                        *byte |= curr & mask;
+                    }
+                }
+                AffinePoint::<S>::deserialize_uncompressed_unchecked(&entry[..])
+                    .expect("Table entries are valid encodings")
+            })
+            .fold(AffinePoint::<S>::zero().into_group(), |acc, point| {
+                acc + point
+            })
+    }
+
+    /// Rows paired with the `scalar` window indexing them.
+    fn windows<'a>(
+        &'a self,
+        scalar: &ScalarField<S>,
+    ) -> impl Iterator<Item = (&'a [AffinePoint<S>], usize)> + 'a {
+        let bits = scalar.into_bigint().to_bits_le();
+        let idxs: Vec<_> = bits
+            .chunks(self.window)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .rev()
+                    .fold(0usize, |idx, &bit| (idx << 1) | bit as usize)
+            })
+            .collect();
+        self.table.iter().map(Vec::as_slice).zip(idxs)
+    }
+}
+
+/// All ones if `a == b`, zero otherwise, without branching on the values.
+#[inline(always)]
+fn ct_eq_mask(a: usize, b: usize) -> u8 {
+    let diff = core::hint::black_box((a ^ b) as u64);
+    (((diff | diff.wrapping_neg()) >> 63) as u8).wrapping_sub(1)
+}
+
+/// Multiply the base point by the secret scalar (see [`FixedBaseTable::mul_secret`]).
+impl<S: Suite> core::ops::Mul<ScalarField<S>> for &FixedBaseTable<S> {
+    type Output = <AffinePoint<S> as AffineRepr>::Group;
+
+    fn mul(self, scalar: ScalarField<S>) -> Self::Output {
+        self.mul_secret(&scalar)
+    }
+}
+
+/// Per-suite fixed-base precomputation context.
+///
+/// Holds [`FixedBaseTable`]s for [`Suite::generator`] and, for Pedersen
+/// suites, [`PedersenSuite::BLINDING_BASE`]. Building the tables is
+/// relatively expensive, so the context is meant to be created once per
+/// process and shared by provers and verifiers.
+#[derive(Clone)]
+pub struct SuitePrecomp<S: Suite> {
+    generator: FixedBaseTable<S>,
+    blinding_base: Option<FixedBaseTable<S>>,
+}
+
+impl<S: Suite> SuitePrecomp<S> {
+    /// Build a context with the generator table only.
+    pub fn new() -> Self {
+        Self {
+            generator: FixedBaseTable::new(S::generator()),
+            blinding_base: None,
+        }
+    }
+
+    /// Generator table.
+    pub fn generator(&self) -> &FixedBaseTable<S> {
+        &self.generator
+    }
+
+    /// Blinding base table, if built.
+    pub fn blinding_base(&self) -> Option<&FixedBaseTable<S>> {
+        self.blinding_base.as_ref()
+    }
+}
+
+impl<S: Suite> Default for SuitePrecomp<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: PedersenSuite> SuitePrecomp<S> {
+    /// Build a context with both the generator and the blinding base tables.
+    pub fn new_pedersen() -> Self {
+        Self {
+            generator: FixedBaseTable::new(S::generator()),
+            blinding_base: Some(FixedBaseTable::new(S::BLINDING_BASE)),
+        }
+    }
+}
+
+/// Verification context bound to a specific public key.
+///
+/// Caches a [`FixedBaseTable`] for the public key `Y` and borrows the
+/// generator `G` table of a [`SuitePrecomp`]. Tiny and Thin verification fold
+/// the Schnorr pair `(G, Y)` into the merged I/O pair; with the tables the
+/// expanded equation `s*G - c*Y + sum_i z_i*(s*I_i - c*O_i)` only needs a
+/// short MSM over the I/O pairs. Verifiers checking many proofs from a small
+/// set of keys build one context per key, sharing the same [`SuitePrecomp`],
+/// and amortize the tables across proofs.
+///
+/// Implements [`tiny::Verifier`](crate::tiny::Verifier) and
+/// [`thin::Verifier`](crate::thin::Verifier).
+#[derive(Clone)]
+pub struct VerifierContext<'a, S: Suite> {
+    public: Public<S>,
+    public_table: FixedBaseTable<S>,
+    generator_table: &'a FixedBaseTable<S>,
+}
+
+impl<'a, S: Suite> VerifierContext<'a, S> {
+    /// Build the verification context for `public`.
+    ///
+    /// Only the public key table is built, the generator table is taken from
+    /// `precomp`.
+    pub fn new(public: Public<S>, precomp: &'a SuitePrecomp<S>) -> Self {
+        Self {
+            public,
+            public_table: FixedBaseTable::new(public.0),
+            generator_table: precomp.generator(),
+        }
+    }
+
+    /// Public key bound to this context.
+    pub fn public(&self) -> &Public<S> {
+        &self.public
+    }
+
+    /// Compute `s*I_m - c*O_m` for the merged pair of `[(G, Y)] || ios`.
+    ///
+    /// `zs` are the delinearization scalars, with `zs[0]` for the Schnorr pair.
+    pub(crate) fn schnorr_lhs(
+        &self,
+        ios: &[VrfIo<S>],
+        zs: &[ScalarField<S>],
+        s: ScalarField<S>,
+        c: ScalarField<S>,
+    ) -> <AffinePoint<S> as AffineRepr>::Group {
+        let schnorr = self.generator_table.mul(&(s * zs[0])) - self.public_table.mul(&(c * zs[0]));
+        let (points, scalars): (Vec<_>, Vec<_>) = ios
+            .iter()
+            .zip(&zs[1..])
+            .flat_map(|(io, z)| [(io.input.0, s * z), (io.output.0, -(c * z))])
+            .unzip();
+        let ios_part = match points.len() {
+            0 => return schnorr,
+            1..=2 => super::straus::short_msm(&points, &scalars, 2),
+            3..=4 => super::straus::short_msm(&points, &scalars, 1),
+            _ => super::msm::batch_msm(&points, &scalars),
+        };
+        schnorr + ios_part
+    }
+}
+
+/// Process-wide [`FixedBaseTable`], built on first use.
+///
+/// Backs the [`Suite::generator_table`] and
+/// [`PedersenSuite::blinding_base_table`] hooks, declared as a `static`
+/// within the hook of a concrete suite:
+///
+/// ```rust,ignore
+/// fn generator_table<'a>() -> Option<&'a utils::FixedBaseTable<Self>> {
+///     static TABLE: utils::StaticTable<MySuite> = utils::StaticTable::new();
+///     TABLE.get_or_init(Self::generator)
+/// }
+/// ```
+///
+/// The table is never freed. Without the `static-tables` feature it is never
+/// built and [`Self::get_or_init`] returns `None`.
+pub struct StaticTable<S: Suite> {
+    #[cfg(feature = "static-tables")]
+    table: std::sync::OnceLock<FixedBaseTable<S>>,
+    _suite: core::marker::PhantomData<fn() -> S>,
+}
+
+impl<S: Suite> StaticTable<S> {
+    /// Empty table.
+    pub const fn new() -> Self {
+        Self {
+            #[cfg(feature = "static-tables")]
+            table: std::sync::OnceLock::new(),
+            _suite: core::marker::PhantomData,
+        }
+    }
+
+    /// Table of `base`, built on first use.
+    ///
+    /// Returns `None` if the `static-tables` feature is disabled.
+    pub fn get_or_init(
+        &'static self,
+        base: impl FnOnce() -> AffinePoint<S>,
+    ) -> Option<&'static FixedBaseTable<S>> {
+        #[cfg(feature = "static-tables")]
+        return Some(self.table.get_or_init(|| FixedBaseTable::new(base())));
+        #[cfg(not(feature = "static-tables"))]
+        {
+            let _ = base;
+            None
+        }
+    }
+}
+
+impl<S: Suite> Default for StaticTable<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Multiply [`Suite::generator`] by the secret `scalar`, through the static
+/// table if available.
+#[cfg(feature = "prove")]
+pub(crate) fn generator_mul<S: Suite>(
+    scalar: ScalarField<S>,
+) -> <AffinePoint<S> as AffineRepr>::Group {
+    match S::generator_table() {
+        Some(table) => table.mul_secret(&scalar),
+        None => S::generator() * scalar,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::random_val;
+    use suites::testing::TestSuite;
+
+    #[test]
+    fn fixed_base_mul_matches_plain_mul() {
+        let rng = &mut ark_std::test_rng();
+        let base = random_val::<AffinePoint<TestSuite>>(Some(rng));
+        for window in [1, 4, FIXED_BASE_WINDOW, 8] {
+            let table = FixedBaseTable::<TestSuite>::with_window(base, window);
+            assert_eq!(table.base(), base);
+            for _ in 0..4 {
+                let s = random_val::<ScalarField<TestSuite>>(Some(rng));
+                assert_eq!(table.mul(&s), base * s);
+                assert_eq!(table.mul_secret(&s), base * s);
+                assert_eq!(&table * s, base * s);
+            }
+            assert!(table.mul(&ScalarField::<TestSuite>::zero()).is_zero());
+            assert_eq!(table.mul(&-ScalarField::<TestSuite>::from(1u8)), -base);
+        }
+    }
+
+    #[cfg(feature = "static-tables")]
+    #[test]
+    fn static_tables() {
+        let rng = &mut ark_std::test_rng();
+        let s = random_val::<ScalarField<TestSuite>>(Some(rng));
+
+        let generator = TestSuite::generator_table().unwrap();
+        assert_eq!(generator.mul(&s), TestSuite::generator() * s);
+        assert!(core::ptr::eq(
+            generator,
+            TestSuite::generator_table().unwrap()
+        ));
+        assert_eq!(generator_mul::<TestSuite>(s), TestSuite::generator() * s);
+
+        let blinding = TestSuite::blinding_base_table().unwrap();
+        assert_eq!(blinding.mul(&s), TestSuite::BLINDING_BASE * s);
+        assert!(!core::ptr::eq(generator, blinding));
+    }
+}
@@ -18,6 +18,47 @@ use super::common::DomSep;
 #[cfg(not(feature = "std"))]
 use ark_std::vec::Vec;
 
+/// Size of the stack buffer holding a TAI candidate encoding.
+const TAI_BUF_SIZE: usize = 128;
+
+/// Try-And-Increment configuration.
+///
+/// The default configuration ([`TaiConfig::DEFAULT`]) uses a single byte
+/// counter and up to 256 attempts, matching [`hash_to_curve_tai`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaiConfig {
+    /// Maximum number of candidate derivations before giving up.
+    pub max_iterations: u32,
+    /// Width in bytes (1 to 4) of the little-endian counter absorbed per attempt.
+    pub counter_width: usize,
+}
+
+impl TaiConfig {
+    /// Single byte counter, up to 256 attempts.
+    pub const DEFAULT: Self = Self {
+        max_iterations: 256,
+        counter_width: 1,
+    };
+}
+
+impl Default for TaiConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Try-And-Increment failure reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaiError {
+    /// No valid point found within the configured number of iterations.
+    IterationsExceeded,
+    /// The base field element encoding doesn't fit the candidate buffer.
+    BufferTooSmall,
+    /// The counter width is not in `1..=4` or cannot represent every
+    /// iteration index below `max_iterations`.
+    InvalidConfig,
+}
+
 /// Try-And-Increment hash-to-curve, inspired by RFC-9381 section 5.4.1.1.
 ///
 /// 1. Hashes `suite_id || 0x01 || data || ctr || 0x00` using the suite transcript.
@@ -29,27 +70,168 @@ use ark_std::vec::Vec;
 ///
 /// Returns `None` if no valid point is found after 256 attempts.
 pub fn hash_to_curve_tai<S: Suite>(data: &[u8]) -> Option<AffinePoint<S>> {
-    let base_len = BaseField::<S>::default().serialized_size(ark_serialize::Compress::Yes);
-    let mut hash_buf = [0u8; 128];
-    let hash = &mut hash_buf[..base_len];
+    hash_to_curve_tai_with_config::<S>(data, &TaiConfig::DEFAULT)
+        .ok()
+        .map(|(pt, _)| pt)
+}
 
-    let mut prefix = S::Transcript::new(S::SUITE_ID);
-    prefix.absorb_raw(&[DomSep::HashToCurveTai as u8]);
-    prefix.absorb_raw(data);
+/// Try-And-Increment hash-to-curve with explicit configuration.
+///
+/// Same procedure as [`hash_to_curve_tai`], with the counter width and the
+/// maximum number of attempts taken from `config`. The counter is absorbed
+/// as `counter_width` little-endian bytes, so the default configuration is
+/// byte-for-byte compatible with [`hash_to_curve_tai`].
+///
+/// On success returns the point together with the counter value that
+/// produced it, which is useful for test-vector debugging.
+pub fn hash_to_curve_tai_with_config<S: Suite>(
+    data: &[u8],
+    config: &TaiConfig,
+) -> Result<(AffinePoint<S>, u32), TaiError> {
+    tai_search::<S>(&tai_prefix::<S>(DomSep::HashToCurveTai, data), config)
+}
+
+/// Try-And-Increment search over the candidates of `prefix`.
+fn tai_search<S: Suite>(
+    prefix: &S::Transcript,
+    config: &TaiConfig,
+) -> Result<(AffinePoint<S>, u32), TaiError> {
+    let base_len = tai_candidate_len::<S>(config)?;
+    let mut hash_buf = [0u8; TAI_BUF_SIZE];
+    let hash = &mut hash_buf[..base_len];
 
-    for ctr in 0..=255_u8 {
-        let mut t = prefix.clone();
-        t.absorb_raw(&[ctr]);
-        t.squeeze_raw(hash);
+    for ctr in 0..config.max_iterations {
+        tai_candidate::<S>(prefix, ctr, config.counter_width, hash);
         let Some(pt) = AffinePoint::<S>::from_random_bytes(hash) else {
             continue;
         };
         let pt = pt.clear_cofactor();
         if !pt.is_zero() {
-            return Some(pt);
+            return Ok((pt, ctr));
+        }
+    }
+    Err(TaiError::IterationsExceeded)
+}
+
+/// Fixed-iteration Try-And-Increment hash-to-curve.
+///
+/// Returns the same point as [`hash_to_curve_tai`] but always runs all 256
+/// candidate derivations, so the number of hash evaluations, decoding
+/// attempts and cofactor clearings does not depend on `data`. Suites hashing
+/// attacker-controlled (or secret) inputs select it with [`Suite::TAI_FIXED`].
+///
+/// Returns `None` if no valid point is found after 256 attempts.
+pub fn hash_to_curve_tai_fixed<S: Suite>(data: &[u8]) -> Option<AffinePoint<S>> {
+    hash_to_curve_tai_fixed_with_config::<S>(data, &TaiConfig::DEFAULT)
+        .ok()
+        .map(|(pt, _)| pt)
+}
+
+/// Fixed-iteration Try-And-Increment hash-to-curve with explicit configuration.
+///
+/// Runs exactly `config.max_iterations` candidate derivations. Every
+/// candidate is encoded and conditionally assigned to the result with
+/// [`subtle`] masks, so that the first valid one is selected without branching
+/// on which candidate it is. Candidates which fail to decode are replaced by
+/// the generator, which is never selected, so that every iteration performs
+/// the same sequence of operations.
+///
+/// This is "constant-time-ish": the work performed and the selection are
+/// input independent, but the underlying field and curve arithmetic are not
+/// guaranteed to be constant time. Prefer Elligator2 based suites when timing
+/// is a concern and the curve supports it.
+pub fn hash_to_curve_tai_fixed_with_config<S: Suite>(
+    data: &[u8],
+    config: &TaiConfig,
+) -> Result<(AffinePoint<S>, u32), TaiError> {
+    tai_search_fixed::<S>(&tai_prefix::<S>(DomSep::HashToCurveTai, data), config)
+}
+
+/// Fixed-iteration Try-And-Increment search over the candidates of `prefix`.
+fn tai_search_fixed<S: Suite>(
+    prefix: &S::Transcript,
+    config: &TaiConfig,
+) -> Result<(AffinePoint<S>, u32), TaiError> {
+    use subtle::{Choice, ConditionallySelectable};
+
+    let base_len = tai_candidate_len::<S>(config)?;
+    let mut hash_buf = [0u8; TAI_BUF_SIZE];
+    let hash = &mut hash_buf[..base_len];
+
+    let point_len = S::generator().uncompressed_size();
+    let mut candidate = Vec::with_capacity(point_len);
+    let mut selected = ark_std::vec![0u8; point_len];
+    let mut selected_ctr = 0u32;
+    let mut found = Choice::from(0);
+    for ctr in 0..config.max_iterations {
+        tai_candidate::<S>(prefix, ctr, config.counter_width, hash);
+        let pt = AffinePoint::<S>::from_random_bytes(hash);
+        let decoded = Choice::from(pt.is_some() as u8);
+        let pt = pt.unwrap_or(S::generator()).clear_cofactor();
+        let take = decoded & !Choice::from(pt.is_zero() as u8) & !found;
+
+        candidate.clear();
+        pt.serialize_uncompressed(&mut candidate)
+            .expect("Serialization into a vector can't fail");
+        for (byte, cand) in selected.iter_mut().zip(&candidate) {
+            byte.conditional_assign(cand, take);
         }
+        selected_ctr.conditional_assign(&ctr, take);
+        found |= take;
+    }
+    if !bool::from(found) {
+        return Err(TaiError::IterationsExceeded);
     }
-    None
+    let pt = AffinePoint::<S>::deserialize_uncompressed_unchecked(&selected[..])
+        .expect("Selected candidate is a valid encoding");
+    Ok((pt, selected_ctr))
+}
+
+/// Validate a TAI configuration and return the candidate encoding length.
+fn tai_candidate_len<S: Suite>(config: &TaiConfig) -> Result<usize, TaiError> {
+    let width = config.counter_width;
+    if !(1..=4).contains(&width)
+        || (width < 4 && u64::from(config.max_iterations) > 1 << (8 * width))
+    {
+        return Err(TaiError::InvalidConfig);
+    }
+    let base_len = BaseField::<S>::default().serialized_size(ark_serialize::Compress::Yes);
+    if base_len > TAI_BUF_SIZE {
+        return Err(TaiError::BufferTooSmall);
+    }
+    Ok(base_len)
+}
+
+/// Keyed PRF input point (see [`Secret::prf`](crate::Secret::prf)).
+///
+/// Try-And-Increment under the PRF tag instead of the hash-to-curve one, so
+/// that no [`Input::new`](crate::Input::new) data maps to the same point,
+/// whatever the suite's [`Suite::data_to_point`]. Fixed-iteration if
+/// [`Suite::TAI_FIXED`].
+#[cfg(feature = "prove")]
+pub(crate) fn prf_to_point<S: Suite>(msg: &[u8]) -> Option<AffinePoint<S>> {
+    let prefix = tai_prefix::<S>(DomSep::Prf, msg);
+    let res = if S::TAI_FIXED {
+        tai_search_fixed::<S>(&prefix, &TaiConfig::DEFAULT)
+    } else {
+        tai_search::<S>(&prefix, &TaiConfig::DEFAULT)
+    };
+    res.ok().map(|(pt, _)| pt)
+}
+
+/// TAI transcript prefix shared by all the candidates.
+fn tai_prefix<S: Suite>(tag: DomSep, data: &[u8]) -> S::Transcript {
+    let mut prefix = S::Transcript::new(S::SUITE_ID);
+    prefix.absorb_raw(&[tag as u8]);
+    prefix.absorb_raw(data);
+    prefix
+}
+
+/// Derive the candidate encoding for the given counter value.
+fn tai_candidate<S: Suite>(prefix: &S::Transcript, ctr: u32, width: usize, buf: &mut [u8]) {
+    let mut t = prefix.clone();
+    t.absorb_raw(&ctr.to_le_bytes()[..width]);
+    t.squeeze_raw(buf);
 }
 
 /// Elligator2 hash-to-curve generic over the field hasher.
@@ -181,4 +363,51 @@ mod tests {
         assert!(pt.is_on_curve());
         assert!(pt.is_in_correct_subgroup_assuming_on_curve())
     }
+
+    #[test]
+    fn hash_to_curve_tai_with_config_works() {
+        let data = b"hello world";
+        let (pt, ctr) =
+            hash_to_curve_tai_with_config::<TestSuite>(data, &TaiConfig::DEFAULT).unwrap();
+        assert_eq!(pt, hash_to_curve_tai::<TestSuite>(data).unwrap());
+
+        // Allowing only the attempts before the successful one must fail.
+        let config = TaiConfig {
+            max_iterations: ctr,
+            ..TaiConfig::DEFAULT
+        };
+        assert_eq!(
+            hash_to_curve_tai_with_config::<TestSuite>(data, &config),
+            Err(TaiError::IterationsExceeded)
+        );
+
+        // Wider counter yields a different point.
+        let config = TaiConfig {
+            counter_width: 2,
+            ..TaiConfig::DEFAULT
+        };
+        let (pt2, _) = hash_to_curve_tai_with_config::<TestSuite>(data, &config).unwrap();
+        assert_ne!(pt, pt2);
+
+        // Counter too narrow for the iterations bound.
+        let config = TaiConfig {
+            max_iterations: 257,
+            counter_width: 1,
+        };
+        assert_eq!(
+            hash_to_curve_tai_with_config::<TestSuite>(data, &config),
+            Err(TaiError::InvalidConfig)
+        );
+    }
+
+    #[test]
+    fn hash_to_curve_tai_fixed_matches() {
+        for i in 0..16u8 {
+            let data = [i; 3];
+            assert_eq!(
+                hash_to_curve_tai_fixed_with_config::<TestSuite>(&data, &TaiConfig::DEFAULT),
+                hash_to_curve_tai_with_config::<TestSuite>(&data, &TaiConfig::DEFAULT),
+            );
+        }
+    }
 }
@@ -0,0 +1,172 @@
+//! # Input cache
+//!
+//! Least recently used cache of [`Input::new`] results, for workloads
+//! evaluating the same inputs over and over (e.g. verifying thousands of
+//! proofs for the same epoch seed), where hash-to-curve is a visible fraction
+//! of the verification time.
+//!
+//! The cache can be shared between threads. Hash-to-curve runs outside of
+//! the cache lock, so concurrent misses for different data don't serialize.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::utils::input_cache::InputCache;
+//!
+//! let cache = InputCache::<BandersnatchSha512Ell2>::new(1024);
+//! let input = cache.input(b"epoch seed").unwrap();
+//! ```
+
+use crate::*;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+/// Cache hit and miss counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups served from the cache.
+    pub hits: u64,
+    /// Lookups requiring hash-to-curve.
+    pub misses: u64,
+}
+
+struct Lru<S: Suite> {
+    entries: HashMap<Vec<u8>, (Input<S>, u64)>,
+    // Entries by last use.
+    order: BTreeMap<u64, Vec<u8>>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+impl<S: Suite> Lru<S> {
+    fn get(&mut self, data: &[u8]) -> Option<Input<S>> {
+        let tick = self.tick;
+        let (input, last_use) = self.entries.get_mut(data)?;
+        let key = self.order.remove(last_use).expect("cache order entry");
+        self.order.insert(tick, key);
+        *last_use = tick;
+        self.tick += 1;
+        Some(*input)
+    }
+
+    fn insert(&mut self, data: &[u8], input: Input<S>, capacity: usize) {
+        if self.get(data).is_some() {
+            return;
+        }
+        if self.entries.len() >= capacity {
+            if let Some((_, key)) = self.order.pop_first() {
+                self.entries.remove(&key);
+            }
+        }
+        self.entries.insert(data.to_vec(), (input, self.tick));
+        self.order.insert(self.tick, data.to_vec());
+        self.tick += 1;
+    }
+}
+
+/// Hash-to-curve LRU cache.
+pub struct InputCache<S: Suite> {
+    capacity: usize,
+    lru: Mutex<Lru<S>>,
+}
+
+impl<S: Suite> InputCache<S> {
+    /// Construct a cache holding up to `capacity` inputs.
+    ///
+    /// A zero capacity disables caching.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            lru: Mutex::new(Lru {
+                entries: HashMap::with_capacity(capacity),
+                order: BTreeMap::new(),
+                tick: 0,
+                stats: CacheStats::default(),
+            }),
+        }
+    }
+
+    // The cache operations can't panic half way, so a poisoned lock still
+    // guards a consistent state.
+    fn lock(&self) -> MutexGuard<'_, Lru<S>> {
+        self.lru.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Same as [`Input::new`], served from the cache when possible.
+    ///
+    /// Hash-to-curve failures are not cached.
+    pub fn input(&self, data: &[u8]) -> Option<Input<S>> {
+        {
+            let mut lru = self.lock();
+            if let Some(input) = lru.get(data) {
+                lru.stats.hits += 1;
+                return Some(input);
+            }
+            lru.stats.misses += 1;
+        }
+        let input = Input::new(data)?;
+        if self.capacity != 0 {
+            self.lock().insert(data, input, self.capacity);
+        }
+        Some(input)
+    }
+
+    /// Maximum number of cached inputs.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached inputs.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if the cache holds no inputs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hit and miss counters since construction or the last [`Self::clear`].
+    pub fn stats(&self) -> CacheStats {
+        self.lock().stats
+    }
+
+    /// Remove all the cached inputs and reset the counters.
+    pub fn clear(&self) {
+        let mut lru = self.lock();
+        lru.entries.clear();
+        lru.order.clear();
+        lru.stats = CacheStats::default();
+    }
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+
+    #[test]
+    fn input_cache() {
+        let cache = InputCache::<S>::new(2);
+        let input = cache.input(b"foo").unwrap();
+        assert!(input == Input::new(b"foo").unwrap());
+        assert!(cache.input(b"foo").unwrap() == input);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+
+        // `foo` is the most recently used, `bar` is evicted.
+        cache.input(b"bar").unwrap();
+        cache.input(b"foo").unwrap();
+        cache.input(b"baz").unwrap();
+        assert_eq!(cache.len(), 2);
+        cache.input(b"foo").unwrap();
+        cache.input(b"bar").unwrap();
+        assert_eq!(cache.stats(), CacheStats { hits: 3, misses: 4 });
+
+        cache.clear();
+        assert!(cache.is_empty());
+
+        let cache = InputCache::<S>::new(0);
+        cache.input(b"foo").unwrap();
+        assert!(cache.is_empty());
+    }
+}
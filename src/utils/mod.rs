@@ -4,7 +4,20 @@
 //! throughout the VRF implementations.
 
 pub mod common;
+pub mod cost;
+pub mod dst;
+pub mod epoch;
+pub mod evm;
+pub mod fixed_base;
 pub mod hash_to_curve;
+#[cfg(feature = "input-cache")]
+pub mod input_cache;
+pub mod msm;
+pub mod sec1;
+#[cfg(feature = "prove")]
+pub mod secret_split;
+pub mod shuffle;
+pub mod sortition;
 pub mod straus;
 pub mod te_sw_map;
 pub mod transcript;
@@ -15,6 +28,9 @@ pub mod transcript;
 /// inspired by RFC-9381 and RFC-8032.
 pub use common::*;
 
+/// Fixed-base precomputation tables.
+pub use fixed_base::*;
+
 /// Hash-to-curve implementations (TAI, Elligator2 with XMD/XOF).
 pub use hash_to_curve::*;
 
@@ -26,37 +42,3 @@ pub use te_sw_map::*;
 
 /// Fiat-Shamir transcript abstraction.
 pub use transcript::*;
-
-/// Point scalar multiplication with optional secret splitting.
-///
-/// When the `secret-split` feature is enabled, this macro splits the secret scalar
-/// into the sum of two randomly generated scalars that retain the same sum. This
-/// technique provides side-channel resistance at the cost of doubling the number
-/// of scalar multiplications.
-///
-/// Without the feature enabled, it performs a standard scalar multiplication.
-mod secret_split {
-    #[cfg(feature = "secret-split")]
-    #[doc(hidden)]
-    #[macro_export]
-    macro_rules! smul {
-        ($p:expr, $s:expr) => {{
-            #[inline(always)]
-            fn get_rand<T: ark_std::UniformRand>(_: &T) -> T {
-                T::rand(&mut ark_std::rand::rngs::OsRng)
-            }
-            let x1 = get_rand(&$s);
-            let x2 = $s - x1;
-            $p * x1 + $p * x2
-        }};
-    }
-
-    #[cfg(not(feature = "secret-split"))]
-    #[doc(hidden)]
-    #[macro_export]
-    macro_rules! smul {
-        ($p:expr, $s:expr) => {
-            $p * $s
-        };
-    }
-}
@@ -0,0 +1,222 @@
+//! Multi-scalar multiplication for large point sets.
+//!
+//! Used by the batch verifiers, where a single MSM over all the deferred
+//! proofs dominates the verification cost.
+//!
+//! Also provides the multiplication of many points by the same scalar, used
+//! to compute the VRF outputs of many inputs (see [`crate::Secret::output_batch`]).
+
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{AdditiveGroup, PrimeField, Zero};
+use ark_std::vec::Vec;
+
+/// Tuning parameters for [`batch_msm_with_config`].
+///
+/// The arkworks defaults are tuned for generic point counts; batch verifiers
+/// produce MSMs of `3n`-`5n` points, for which a different window or
+/// threading threshold may perform better on a given target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsmConfig {
+    /// Pippenger window size in bits.
+    ///
+    /// `None` uses the arkworks heuristic. Must be in `[1, 16]`.
+    pub window: Option<usize>,
+    /// Minimum number of points assigned to a parallel chunk.
+    ///
+    /// Below this size the per-chunk bucket setup outweighs the gain of
+    /// spreading the work over more threads. Only used with the `parallel`
+    /// feature.
+    pub min_chunk_size: usize,
+}
+
+impl MsmConfig {
+    /// Default configuration.
+    pub const DEFAULT: Self = Self {
+        window: None,
+        min_chunk_size: 256,
+    };
+}
+
+impl Default for MsmConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Multi-scalar multiplication `sum(scalars[i] * bases[i])`.
+///
+/// Same as [`batch_msm_with_config`] with [`MsmConfig::DEFAULT`].
+pub fn batch_msm<C: AffineRepr>(bases: &[C], scalars: &[C::ScalarField]) -> C::Group {
+    batch_msm_with_config(bases, scalars, &MsmConfig::DEFAULT)
+}
+
+/// Multi-scalar multiplication `sum(scalars[i] * bases[i])`.
+///
+/// Under the `parallel` feature the input is split into one chunk per
+/// rayon thread (at least `config.min_chunk_size` points each), the chunks
+/// are evaluated concurrently and the partial results summed. Otherwise
+/// this is a single Pippenger MSM.
+///
+/// Lengths are assumed to match; extra elements in the longer slice are ignored.
+///
+/// # Panics
+///
+/// Panics if `config.window` is not in `[1, 16]`.
+pub fn batch_msm_with_config<C: AffineRepr>(
+    bases: &[C],
+    scalars: &[C::ScalarField],
+    config: &MsmConfig,
+) -> C::Group {
+    let len = bases.len().min(scalars.len());
+    let (bases, scalars) = (&bases[..len], &scalars[..len]);
+    if let Some(window) = config.window {
+        assert!((1..=16).contains(&window), "window size must be in [1, 16]");
+    }
+    let msm = |b: &[C], s: &[C::ScalarField]| match config.window {
+        Some(window) => pippenger(b, s, window),
+        None => C::Group::msm_unchecked(b, s),
+    };
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        let chunk_size = len
+            .div_ceil(rayon::current_num_threads())
+            .max(config.min_chunk_size)
+            .max(1);
+        if chunk_size < len {
+            return bases
+                .par_chunks(chunk_size)
+                .zip(scalars.par_chunks(chunk_size))
+                .map(|(b, s)| msm(b, s))
+                .sum();
+        }
+    }
+    msm(bases, scalars)
+}
+
+/// Multiply each of the `bases` by the same `scalar`.
+///
+/// Fixed window method with window size `w`: the scalar digits are computed
+/// once, then the tables of the `2^w` multiples of all the bases are
+/// normalized with a single batch inversion, and so are the results.
+///
+/// The number of doublings and additions doesn't depend on the scalar value.
+///
+/// # Panics
+///
+/// Panics if `w` is not in `[1, 8]`.
+pub fn fixed_scalar_mul<C: AffineRepr>(bases: &[C], scalar: &C::ScalarField, w: usize) -> Vec<C> {
+    assert!((1..=8).contains(&w), "window size must be in [1, 8]");
+    let repr = scalar.into_bigint();
+    let num_bits = C::ScalarField::MODULUS_BIT_SIZE as usize;
+    let mask = (1u32 << w) - 1;
+    let digits: Vec<usize> = (0..num_bits)
+        .step_by(w)
+        .rev()
+        .map(|start| super::straus::extract_digit(&repr, start, w, mask) as usize)
+        .collect();
+
+    // tables[i * 2^w + d] = d * bases[i]
+    let table_len = 1 << w;
+    let mut tables = Vec::with_capacity(bases.len() * table_len);
+    for base in bases {
+        let mut acc = C::Group::zero();
+        tables.push(acc);
+        for _ in 1..table_len {
+            acc += base;
+            tables.push(acc);
+        }
+    }
+    let tables = C::Group::normalize_batch(&tables);
+
+    let results: Vec<_> = tables
+        .chunks_exact(table_len)
+        .map(|table| {
+            let mut acc = C::Group::zero();
+            for &digit in &digits {
+                for _ in 0..w {
+                    acc.double_in_place();
+                }
+                acc += table[digit];
+            }
+            acc
+        })
+        .collect();
+    C::Group::normalize_batch(&results)
+}
+
+/// Bucket method MSM with a fixed window size.
+fn pippenger<C: AffineRepr>(bases: &[C], scalars: &[C::ScalarField], window: usize) -> C::Group {
+    let reprs: Vec<_> = scalars.iter().map(|s| s.into_bigint()).collect();
+    let num_bits = C::ScalarField::MODULUS_BIT_SIZE as usize;
+    let mask = (1u32 << window) - 1;
+    let zero = C::Group::zero();
+    let mut buckets = ark_std::vec![zero; (1 << window) - 1];
+
+    // Windows are processed from the most significant one, doubling the
+    // accumulator `window` times in between.
+    let mut acc = zero;
+    for start in (0..num_bits).step_by(window).rev() {
+        for _ in 0..window {
+            acc.double_in_place();
+        }
+        buckets.iter_mut().for_each(|b| *b = zero);
+        for (base, repr) in bases.iter().zip(&reprs) {
+            let digit = super::straus::extract_digit(repr, start, window, mask) as usize;
+            if digit != 0 {
+                buckets[digit - 1] += base;
+            }
+        }
+        // sum_i (i+1) * buckets[i] via running sums.
+        let mut running = zero;
+        for b in buckets.iter().rev() {
+            running += b;
+            acc += running;
+        }
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::suites::testing::TestSuite;
+    use crate::testing::random_val;
+    use crate::{AffinePoint, ScalarField};
+
+    #[test]
+    fn batch_msm_matches_msm() {
+        let rng = &mut ark_std::test_rng();
+        for n in [0, 1, 7, 600] {
+            let bases: Vec<AffinePoint<TestSuite>> =
+                (0..n).map(|_| random_val(Some(rng))).collect();
+            let scalars: Vec<ScalarField<TestSuite>> =
+                (0..n).map(|_| random_val(Some(rng))).collect();
+            let expected =
+                <AffinePoint<TestSuite> as AffineRepr>::Group::msm_unchecked(&bases, &scalars);
+            assert_eq!(batch_msm(&bases, &scalars), expected);
+            for window in [1, 5, 8, 13] {
+                let config = MsmConfig {
+                    window: Some(window),
+                    min_chunk_size: 100,
+                };
+                assert_eq!(batch_msm_with_config(&bases, &scalars, &config), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_scalar_mul_matches_mul() {
+        let rng = &mut ark_std::test_rng();
+        let mut bases: Vec<AffinePoint<TestSuite>> =
+            (0..5).map(|_| random_val(Some(rng))).collect();
+        bases.push(AffinePoint::<TestSuite>::zero());
+        let scalar: ScalarField<TestSuite> = random_val(Some(rng));
+        for scalar in [scalar, -scalar, ScalarField::<TestSuite>::zero()] {
+            let expected: Vec<_> = bases.iter().map(|b| (*b * scalar).into_affine()).collect();
+            for w in [1, 4, 5, 8] {
+                assert_eq!(fixed_scalar_mul(&bases, &scalar, w), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! # SEC1 encoding
+//!
+//! Points and proofs of short Weierstrass suites (e.g. secp256r1) in the
+//! SEC1 encoding used by RFC-9381 and most of the NIST and secp256k1 tooling,
+//! instead of the arkworks compressed encoding.
+//!
+//! - A point is encoded as in SEC1 section 2.3.3 with point compression:
+//!   a `0x02` (even `y`) or `0x03` (odd `y`) prefix followed by the big-endian
+//!   `x` coordinate. The point at infinity is the single `0x00` byte.
+//! - A scalar is big-endian, padded to the length of the group order.
+//!
+//! Decoding also accepts the uncompressed form (`0x04` prefix followed by
+//! `x` and `y`), and performs the same checks as the arkworks decoders:
+//! canonical coordinates and scalars, curve and prime-order subgroup
+//! membership.
+//!
+//! The proof layouts are the concatenation of the proof components, in
+//! declaration order:
+//! - Tiny VRF: `c || s`, with `c` truncated to
+//!   [`CHALLENGE_LEN`](super::common::CHALLENGE_LEN) bytes (as `cLen` in RFC-9381).
+//! - Thin VRF: `r || s`.
+//! - Pedersen VRF: `pk_com || r || ok || s || sb`.
+//!
+//! The proof layouts have a fixed length, so proofs with a point at infinity
+//! (always invalid) can't be decoded. Tiny VRF proofs must be canonical (see
+//! [`tiny::Proof::check_canonical`]).
+
+use crate::*;
+use ark_ec::short_weierstrass::{Affine as SWAffine, SWCurveConfig};
+use utils::common::CHALLENGE_LEN;
+
+/// Length in bytes of an encoded element of the prime field `F`.
+pub const fn field_len<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize).div_ceil(8)
+}
+
+/// Length in bytes of a compressed point of the curve `C`.
+pub const fn point_len<C: SWCurveConfig>() -> usize
+where
+    C::BaseField: PrimeField,
+{
+    1 + field_len::<C::BaseField>()
+}
+
+fn field_encode<F: PrimeField>(value: &F, len: usize, buf: &mut Vec<u8>) {
+    use ark_ff::BigInteger;
+    let bytes = value.into_bigint().to_bytes_be();
+    let digits = &bytes[bytes.len() - len..];
+    debug_assert!(bytes[..bytes.len() - len].iter().all(|&b| b == 0));
+    buf.extend_from_slice(digits);
+}
+
+fn field_decode<F: PrimeField>(bytes: &[u8]) -> Result<F, Error> {
+    let value = F::from_be_bytes_mod_order(bytes);
+    let mut encoded = Vec::with_capacity(bytes.len());
+    field_encode(&value, bytes.len(), &mut encoded);
+    if encoded != bytes {
+        return Err(Error::NonCanonicalScalar);
+    }
+    Ok(value)
+}
+
+/// Encode a scalar (or any prime field element) as big-endian bytes.
+pub fn encode_scalar<F: PrimeField>(value: &F) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(field_len::<F>());
+    field_encode(value, field_len::<F>(), &mut buf);
+    buf
+}
+
+/// Decode a big-endian scalar.
+///
+/// Returns `Error::InvalidData` if `bytes` has not the scalar length and
+/// `Error::NonCanonicalScalar` if the value is not below the field modulus.
+pub fn decode_scalar<F: PrimeField>(bytes: &[u8]) -> Result<F, Error> {
+    if bytes.len() != field_len::<F>() {
+        return Err(Error::InvalidData);
+    }
+    field_decode(bytes)
+}
+
+/// Encode a point with point compression.
+pub fn encode_point<C: SWCurveConfig>(point: &SWAffine<C>) -> Vec<u8>
+where
+    C::BaseField: PrimeField,
+{
+    use ark_ff::BigInteger;
+    let Some((x, y)) = point.xy() else {
+        return [0x00].to_vec();
+    };
+    let mut buf = Vec::with_capacity(point_len::<C>());
+    buf.push(if y.into_bigint().is_odd() { 0x03 } else { 0x02 });
+    field_encode(&x, field_len::<C::BaseField>(), &mut buf);
+    buf
+}
+
+/// Decode a compressed or uncompressed point.
+///
+/// Returns `Error::InvalidData` for an unknown prefix or a wrong length,
+/// `Error::NonCanonicalEncoding` for a coordinate not below the field
+/// modulus, `Error::PointNotOnCurve` and `Error::PointNotInSubgroup` for
+/// points failing the membership checks. The point at infinity is decoded:
+/// callers are expected to reject it where invalid, as with the arkworks
+/// decoders.
+pub fn decode_point<C: SWCurveConfig>(bytes: &[u8]) -> Result<SWAffine<C>, Error>
+where
+    C::BaseField: PrimeField,
+{
+    use ark_ff::BigInteger;
+    let len = field_len::<C::BaseField>();
+    let coord =
+        |bytes: &[u8]| field_decode::<C::BaseField>(bytes).map_err(|_| Error::NonCanonicalEncoding);
+    let point = match bytes {
+        [0x00] => return Ok(SWAffine::identity()),
+        [prefix @ (0x02 | 0x03), x @ ..] if x.len() == len => {
+            let x = coord(x)?;
+            let point = SWAffine::<C>::get_point_from_x_unchecked(x, false)
+                .ok_or(Error::PointNotOnCurve)?;
+            let odd = point.y.into_bigint().is_odd();
+            match odd == (*prefix == 0x03) {
+                true => point,
+                false => -point,
+            }
+        }
+        [0x04, xy @ ..] if xy.len() == 2 * len => {
+            let point = SWAffine::<C>::new_unchecked(coord(&xy[..len])?, coord(&xy[len..])?);
+            if !point.is_on_curve() {
+                return Err(Error::PointNotOnCurve);
+            }
+            point
+        }
+        _ => return Err(Error::InvalidData),
+    };
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Error::PointNotInSubgroup);
+    }
+    Ok(point)
+}
+
+/// Split `bytes` into consecutive chunks of the given lengths.
+///
+/// Returns `Error::InvalidData` if the lengths don't add up to the input length.
+fn split<const N: usize>(mut bytes: &[u8], lens: [usize; N]) -> Result<[&[u8]; N], Error> {
+    if lens.iter().sum::<usize>() != bytes.len() {
+        return Err(Error::InvalidData);
+    }
+    Ok(lens.map(|len| {
+        let (chunk, rest) = bytes.split_at(len);
+        bytes = rest;
+        chunk
+    }))
+}
+
+/// Encode a Tiny VRF proof.
+pub fn encode_tiny<S, C>(proof: &tiny::Proof<S>) -> Vec<u8>
+where
+    S: tiny::TinySuite<Affine = SWAffine<C>>,
+    C: SWCurveConfig<BaseField: PrimeField>,
+{
+    let mut buf = Vec::with_capacity(CHALLENGE_LEN + field_len::<ScalarField<S>>());
+    field_encode(&proof.c, CHALLENGE_LEN, &mut buf);
+    buf.extend(encode_scalar(&proof.s));
+    buf
+}
+
+/// Decode a Tiny VRF proof.
+///
+/// Returns `Error::InvalidData` for a wrong length and
+/// `Error::NonCanonicalScalar` for a non-canonical response.
+pub fn decode_tiny<S, C>(bytes: &[u8]) -> Result<tiny::Proof<S>, Error>
+where
+    S: tiny::TinySuite<Affine = SWAffine<C>>,
+    C: SWCurveConfig<BaseField: PrimeField>,
+{
+    let [c, s] = split(bytes, [CHALLENGE_LEN, field_len::<ScalarField<S>>()])?;
+    Ok(tiny::Proof {
+        c: ScalarField::<S>::from_be_bytes_mod_order(c),
+        s: decode_scalar(s)?,
+    })
+}
+
+/// Encode a Thin VRF proof.
+pub fn encode_thin<S, C>(proof: &thin::Proof<S>) -> Vec<u8>
+where
+    S: thin::ThinVrfSuite<Affine = SWAffine<C>>,
+    C: SWCurveConfig<BaseField: PrimeField>,
+{
+    let mut buf = encode_point(&proof.r);
+    buf.extend(encode_scalar(&proof.s));
+    buf
+}
+
+/// Decode a Thin VRF proof.
+///
+/// Returns the errors of [`decode_point`] and [`decode_scalar`], and
+/// `Error::InvalidData` for a wrong length.
+pub fn decode_thin<S, C>(bytes: &[u8]) -> Result<thin::Proof<S>, Error>
+where
+    S: thin::ThinVrfSuite<Affine = SWAffine<C>>,
+    C: SWCurveConfig<BaseField: PrimeField>,
+{
+    let [r, s] = split(bytes, [point_len::<C>(), field_len::<ScalarField<S>>()])?;
+    Ok(thin::Proof {
+        r: decode_point(r)?,
+        s: decode_scalar(s)?,
+    })
+}
+
+/// Encode a Pedersen VRF proof.
+pub fn encode_pedersen<S, C>(proof: &pedersen::Proof<S>) -> Vec<u8>
+where
+    S: pedersen::PedersenSuite<Affine = SWAffine<C>>,
+    C: SWCurveConfig<BaseField: PrimeField>,
+{
+    let mut buf = Vec::new();
+    for point in [&proof.pk_com, &proof.r, &proof.ok] {
+        buf.extend(encode_point(point));
+    }
+    buf.extend(encode_scalar(&proof.s));
+    buf.extend(encode_scalar(&proof.sb));
+    buf
+}
+
+/// Decode a Pedersen VRF proof.
+///
+/// Returns the errors of [`decode_point`] and [`decode_scalar`], and
+/// `Error::InvalidData` for a wrong length.
+pub fn decode_pedersen<S, C>(bytes: &[u8]) -> Result<pedersen::Proof<S>, Error>
+where
+    S: pedersen::PedersenSuite<Affine = SWAffine<C>>,
+    C: SWCurveConfig<BaseField: PrimeField>,
+{
+    let (p, f) = (point_len::<C>(), field_len::<ScalarField<S>>());
+    let [pk_com, r, ok, s, sb] = split(bytes, [p, p, p, f, f])?;
+    Ok(pedersen::Proof {
+        pk_com: decode_point(pk_com)?,
+        r: decode_point(r)?,
+        ok: decode_point(ok)?,
+        s: decode_scalar(s)?,
+        sb: decode_scalar(sb)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_SEED;
+
+    #[allow(unused)]
+    fn check_points<S, C>()
+    where
+        S: Suite<Affine = SWAffine<C>>,
+        C: SWCurveConfig<BaseField: PrimeField>,
+    {
+        let point = Secret::<S>::from_seed(TEST_SEED).public().0;
+        let buf = encode_point(&point);
+        assert_eq!(buf.len(), point_len::<C>());
+        assert_eq!(decode_point::<C>(&buf), Ok(point));
+
+        // Same `x`, opposite `y` parity.
+        let neg = encode_point(&-point);
+        assert_eq!(neg[0] ^ buf[0], 1);
+        assert_eq!(neg[1..], buf[1..]);
+        assert_eq!(decode_point::<C>(&neg), Ok(-point));
+
+        let (x, y) = point.xy().unwrap();
+        let uncompressed = [[0x04].to_vec(), encode_scalar(&x), encode_scalar(&y)].concat();
+        assert_eq!(decode_point::<C>(&uncompressed), Ok(point));
+        assert_eq!(decode_point::<C>(&[0x00]), Ok(SWAffine::identity()));
+        assert_eq!(encode_point(&SWAffine::<C>::identity()), [0x00]);
+
+        let mut bad = buf.clone();
+        bad[0] = 0x05;
+        assert_eq!(decode_point::<C>(&bad), Err(Error::InvalidData));
+        assert_eq!(decode_point::<C>(&buf[1..]), Err(Error::InvalidData));
+        bad[0] = 0x02;
+        bad[1..].fill(0xff);
+        assert_eq!(decode_point::<C>(&bad), Err(Error::NonCanonicalEncoding));
+        let mut bad = uncompressed.clone();
+        *bad.last_mut().unwrap() ^= 1;
+        assert_eq!(decode_point::<C>(&bad), Err(Error::PointNotOnCurve));
+
+        let scalar = ScalarField::<S>::from(1234u32);
+        let buf = encode_scalar(&scalar);
+        assert_eq!(buf.len(), field_len::<ScalarField<S>>());
+        assert_eq!(decode_scalar(&buf), Ok(scalar));
+        assert_eq!(
+            decode_scalar::<ScalarField<S>>(&[0xff; 32]),
+            Err(Error::NonCanonicalScalar)
+        );
+    }
+
+    #[allow(unused)]
+    fn check_proofs<S, C>()
+    where
+        S: pedersen::PedersenSuite<Affine = SWAffine<C>>,
+        C: SWCurveConfig<BaseField: PrimeField>,
+    {
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+        let io = secret.vrf_io(Input::new(b"foo").unwrap());
+        let ad = b"bar";
+
+        let proof = tiny::Prover::prove(&secret, io, ad);
+        let buf = encode_tiny(&proof);
+        assert_eq!(buf.len(), tiny::Proof::<S>::ENCODED_LEN);
+        let proof = decode_tiny::<S, C>(&buf).unwrap();
+        assert!(tiny::Verifier::verify(&public, io, ad, &proof).is_ok());
+        assert_eq!(
+            decode_tiny::<S, C>(&buf[1..]).err(),
+            Some(Error::InvalidData)
+        );
+
+        let proof = thin::Prover::prove(&secret, io, ad);
+        let proof = decode_thin::<S, C>(&encode_thin(&proof)).unwrap();
+        assert!(thin::Verifier::verify(&public, io, ad, &proof).is_ok());
+        let mut batch = thin::BatchVerifier::new();
+        batch.push(&public, io, ad, &proof);
+        batch.push(&public, io, b"baz", &proof);
+        assert!(batch.verify().is_err());
+        let mut batch = thin::BatchVerifier::new();
+        batch.push(&public, io, ad, &proof);
+        assert!(batch.verify().is_ok());
+
+        let (proof, _) = pedersen::Prover::prove(&secret, io, ad);
+        let proof = decode_pedersen::<S, C>(&encode_pedersen(&proof)).unwrap();
+        assert!(<Public<S> as pedersen::Verifier<S>>::verify(io, ad, &proof).is_ok());
+        let mut batch = pedersen::BatchVerifier::new();
+        batch.push(io, ad, &proof);
+        assert!(batch.verify().is_ok());
+        batch.push(io, b"baz", &proof);
+        assert!(batch.verify().is_err());
+    }
+
+    #[cfg(feature = "bandersnatch")]
+    #[test]
+    fn bandersnatch_sw() {
+        use suites::bandersnatch_sw::BandersnatchSha512Tai as S;
+        check_points::<S, _>();
+        check_proofs::<S, _>();
+    }
+
+    #[cfg(feature = "secp256r1")]
+    #[test]
+    fn secp256r1() {
+        use suites::secp256r1::Secp256r1Sha256Tai as S;
+        check_points::<S, _>();
+        check_proofs::<S, _>();
+    }
+}
@@ -0,0 +1,155 @@
+//! # Secret splitting
+//!
+//! Point scalar multiplication with optional secret splitting.
+//!
+//! When the `secret-split` feature is enabled, secret scalars are split into
+//! the sum of two random scalars (the masks) which are multiplied separately.
+//! This technique provides side-channel resistance at the cost of doubling the
+//! number of scalar multiplications. Without the feature enabled, a standard
+//! scalar multiplication is performed.
+//!
+//! ## Masks randomness
+//!
+//! With `secret-split` the masks are drawn from the OS randomness (the
+//! feature enables `getrandom`).
+//!
+//! Targets without OS randomness (e.g. `wasm32-unknown-unknown`) enable
+//! `secret-split-custom-rng` instead, and register their source once in the
+//! final binary with [`register_secret_split_rng`](crate::register_secret_split_rng):
+//!
+//! ```rust,ignore
+//! fn host_random_bytes(buf: &mut [u8]) {
+//!     // Fill `buf` with cryptographically secure random bytes.
+//! }
+//!
+//! ark_vrf::register_secret_split_rng!(host_random_bytes);
+//! ```
+//!
+//! The source is resolved when the binary is linked, so a missing
+//! registration fails the build (undefined `__ark_vrf_secret_split_rng`
+//! symbol) instead of panicking at the first split. When both features are
+//! enabled the registered source is used.
+
+#[cfg(feature = "secret-split-custom-rng")]
+#[allow(unsafe_code)]
+unsafe extern "Rust" {
+    fn __ark_vrf_secret_split_rng(buf: &mut [u8]);
+}
+
+/// Register the `secret-split-custom-rng` masks randomness source.
+///
+/// `$source` is a `fn(&mut [u8])` filling the given buffer with
+/// cryptographically secure random bytes. Must be invoked exactly once, in
+/// the crate producing the final binary.
+#[macro_export]
+macro_rules! register_secret_split_rng {
+    ($source:path) => {
+        #[doc(hidden)]
+        #[allow(unsafe_code)]
+        #[unsafe(no_mangle)]
+        pub fn __ark_vrf_secret_split_rng(buf: &mut [u8]) {
+            let source: fn(&mut [u8]) = $source;
+            source(buf)
+        }
+    };
+}
+
+/// RNG wrapper over the registered source.
+#[cfg(feature = "secret-split-custom-rng")]
+struct SourceRng;
+
+#[cfg(feature = "secret-split-custom-rng")]
+impl ark_std::rand::RngCore for SourceRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut b = [0u8; 4];
+        self.fill_bytes(&mut b);
+        u32::from_le_bytes(b)
+    }
+    fn next_u64(&mut self) -> u64 {
+        let mut b = [0u8; 8];
+        self.fill_bytes(&mut b);
+        u64::from_le_bytes(b)
+    }
+    #[allow(unsafe_code)]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // SAFETY: the symbol is defined by `register_secret_split_rng`, with
+        // this signature.
+        unsafe { __ark_vrf_secret_split_rng(dest) }
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), ark_std::rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Random mask drawn from the masks randomness source.
+#[cfg(any(feature = "secret-split", feature = "secret-split-custom-rng"))]
+#[doc(hidden)]
+pub fn mask<T: ark_std::UniformRand>() -> T {
+    #[cfg(feature = "secret-split-custom-rng")]
+    {
+        T::rand(&mut SourceRng)
+    }
+    #[cfg(not(feature = "secret-split-custom-rng"))]
+    {
+        T::rand(&mut ark_std::rand::rngs::OsRng)
+    }
+}
+
+#[cfg(any(feature = "secret-split", feature = "secret-split-custom-rng"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! smul {
+    ($p:expr, $s:expr) => {{
+        #[inline(always)]
+        fn get_rand<T: ark_std::UniformRand>(_: &T) -> T {
+            $crate::utils::secret_split::mask()
+        }
+        let x1 = get_rand(&$s);
+        let x2 = $s - x1;
+        $p * x1 + $p * x2
+    }};
+}
+
+#[cfg(not(any(feature = "secret-split", feature = "secret-split-custom-rng")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! smul {
+    ($p:expr, $s:expr) => {
+        $p * $s
+    };
+}
+
+/// Test masks source, a different stream on each call.
+#[cfg(all(test, feature = "secret-split-custom-rng"))]
+fn test_source(buf: &mut [u8]) {
+    use ark_std::rand::{RngCore, SeedableRng};
+    use core::sync::atomic::{AtomicU64, Ordering};
+    static CALLS: AtomicU64 = AtomicU64::new(0);
+    let seed = CALLS.fetch_add(1, Ordering::Relaxed);
+    ark_std::rand::rngs::StdRng::seed_from_u64(seed).fill_bytes(buf);
+}
+
+#[cfg(all(test, feature = "secret-split-custom-rng"))]
+mod test_rng {
+    crate::register_secret_split_rng!(super::test_source);
+}
+
+#[cfg(all(
+    test,
+    any(feature = "secret-split", feature = "secret-split-custom-rng"),
+    feature = "bandersnatch"
+))]
+mod tests {
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+    use crate::*;
+
+    #[test]
+    fn split_outputs() {
+        let secret = Secret::<S>::from_seed([0; 32]);
+        let input = Input::<S>::new(b"foo").unwrap();
+        let expected = (input.0 * secret.scalar).into_affine();
+        assert_eq!(secret.output(input).0, expected);
+        assert_eq!(secret.output_batch(&[input])[0].0, expected);
+    }
+}
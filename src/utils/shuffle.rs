@@ -0,0 +1,115 @@
+//! # Committee shuffling
+//!
+//! Deterministic Fisher-Yates shuffling seeded from one or more VRF outputs
+//! (e.g. the outputs revealed by the previous epoch block authors), to derive
+//! committee orderings anyone can recompute.
+//!
+//! The shuffle seed absorbs the outputs (in order) and the number of shuffled
+//! items under a dedicated domain separation tag, so shuffles of lists of
+//! different lengths are unrelated. Swap positions are drawn without modulo
+//! bias.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::utils::shuffle;
+//!
+//! let mut validators = validators.clone();
+//! shuffle::shuffle(&outputs, &mut validators);
+//!
+//! // Verifier side
+//! assert!(shuffle::verify_permutation(&outputs, &claimed_order));
+//! ```
+
+use crate::*;
+use utils::common::DomSep;
+
+/// Shuffle `items` in place, seeded from `outputs`.
+///
+/// Equivalent to reordering `items` by [`permutation`]: the item at position
+/// `i` after the shuffle was at position `permutation(outputs, len)[i]`.
+pub fn shuffle<S: Suite, T>(outputs: &[Output<S>], items: &mut [T]) {
+    let mut t = S::Transcript::new(S::SUITE_ID);
+    t.absorb_raw(&[DomSep::Shuffle as u8]);
+    t.absorb_raw(&(outputs.len() as u64).to_le_bytes());
+    for output in outputs {
+        t.absorb_serialize(&output.0);
+    }
+    t.absorb_raw(&(items.len() as u64).to_le_bytes());
+    for i in (1..items.len()).rev() {
+        let j = uniform_index(&mut t, i as u64 + 1);
+        items.swap(i, j);
+    }
+}
+
+/// Uniform draw in `0..bound` from the squeeze stream of `t`.
+fn uniform_index(t: &mut impl Transcript, bound: u64) -> usize {
+    // Draws above the largest multiple of `bound` are rejected.
+    let rem = (u64::MAX % bound + 1) % bound;
+    loop {
+        let mut buf = [0; 8];
+        t.squeeze_raw(&mut buf);
+        let value = u64::from_le_bytes(buf);
+        if value <= u64::MAX - rem {
+            return (value % bound) as usize;
+        }
+    }
+}
+
+/// Permutation of `0..n` seeded from `outputs`.
+pub fn permutation<S: Suite>(outputs: &[Output<S>], n: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..n).collect();
+    shuffle(outputs, &mut indices);
+    indices
+}
+
+/// Returns `true` if `indices` is the permutation seeded from `outputs`.
+///
+/// Verifier side counterpart of [`permutation`].
+pub fn verify_permutation<S: Suite>(outputs: &[Output<S>], indices: &[usize]) -> bool {
+    permutation(outputs, indices.len()) == indices
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+
+    fn outputs(n: u32) -> Vec<Output<S>> {
+        let secret = Secret::<S>::from_seed([0; 32]);
+        (0..n)
+            .map(|i| secret.output(Input::new(&i.to_le_bytes()).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn shuffle_is_seeded_permutation() {
+        let outputs = outputs(3);
+        let perm = permutation(&outputs, 100);
+        let mut sorted = perm.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+        assert_ne!(perm, sorted);
+        assert!(verify_permutation(&outputs, &perm));
+        assert!(!verify_permutation(&outputs[..2], &perm));
+        assert!(!verify_permutation(&outputs, &perm[..99]));
+
+        let mut items: Vec<_> = (0..100).map(|i| i * 10).collect();
+        shuffle(&outputs, &mut items);
+        assert!(perm.iter().zip(&items).all(|(i, item)| i * 10 == *item));
+
+        assert!(permutation(&outputs, 0).is_empty());
+        assert_eq!(permutation(&outputs, 1), [0]);
+        assert_ne!(permutation(&outputs, 99)[..], perm[..99]);
+    }
+
+    #[test]
+    fn shuffle_is_uniform() {
+        // Counts of the first position over the 6 orderings of 3 items.
+        let mut counts = [0; 3];
+        for output in outputs(600) {
+            counts[permutation(&[output], 3)[0]] += 1;
+        }
+        assert!(counts.iter().all(|c| (150..250).contains(c)));
+    }
+}
@@ -0,0 +1,191 @@
+//! # Stake-weighted sortition
+//!
+//! Deterministic leader and committee election from verified VRF outputs,
+//! with election probabilities proportional to arbitrary `u128` weights
+//! (e.g. stakes).
+//!
+//! All the functions only depend on the output and on public data, so the
+//! verifier recomputes the election result from the output of a verified
+//! proof, without trusting the result claimed by the prover.
+//!
+//! - [`is_eligible`]: each party evaluates its own VRF and is elected if its
+//!   [`ticket`] falls below a threshold proportional to its weight.
+//! - [`winner`]: deterministic tie-breaking between several eligible parties,
+//!   the lowest ticket wins.
+//! - [`select`]: a single VRF output draws one index out of a weighted list
+//!   (e.g. from a shared randomness beacon output).
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::utils::sortition;
+//!
+//! // Prover
+//! let output = secret.output(input);
+//! if sortition::is_eligible(&output, stake, total_stake) {
+//!     let proof = secret.prove(input, output, ad);
+//! }
+//!
+//! // Verifier, after verifying the proof
+//! assert!(sortition::is_eligible(&output, stake, total_stake));
+//! ```
+
+use crate::*;
+use utils::common::DomSep;
+
+/// Sortition draws stream of `output`.
+///
+/// The first draw is the output [`ticket`].
+fn draws<S: Suite>(output: &Output<S>) -> impl FnMut() -> u128 {
+    let mut t = S::Transcript::new(S::SUITE_ID);
+    t.absorb_raw(&[DomSep::Sortition as u8]);
+    t.absorb_serialize(&output.0);
+    move || {
+        let mut buf = [0; 16];
+        t.squeeze_raw(&mut buf);
+        u128::from_le_bytes(buf)
+    }
+}
+
+/// High half of the 256-bit product `a * b`.
+fn mul_hi(a: u128, b: u128) -> u128 {
+    const LO: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & LO);
+    let (b1, b0) = (b >> 64, b & LO);
+    let (p01, p10) = (a0 * b1, a1 * b0);
+    let mid = ((a0 * b0) >> 64) + (p01 & LO) + (p10 & LO);
+    a1 * b1 + (p01 >> 64) + (p10 >> 64) + (mid >> 64)
+}
+
+/// Sortition ticket of `output`.
+///
+/// Uniformly distributed `u128`, derived from the output point under a
+/// dedicated domain separation tag.
+pub fn ticket<S: Suite>(output: &Output<S>) -> u128 {
+    draws(output)()
+}
+
+/// Returns `true` if `output` elects a party with `weight` out of
+/// `total_weight`.
+///
+/// Happens with probability `weight / total_weight` (up to a `2^-128`
+/// rounding), i.e. iff `ticket * total_weight < weight * 2^128`. A weight not
+/// less than the total weight is always elected, a zero weight never is.
+pub fn is_eligible<S: Suite>(output: &Output<S>, weight: u128, total_weight: u128) -> bool {
+    mul_hi(ticket(output), total_weight) < weight
+}
+
+/// Position of the winning output among the `outputs` of several eligible
+/// parties.
+///
+/// The output with the lowest [`ticket`] wins. Equal tickets (i.e. equal
+/// outputs) are won by the first of them. Returns `None` if `outputs` is
+/// empty.
+pub fn winner<'a, S: Suite + 'a>(
+    outputs: impl IntoIterator<Item = &'a Output<S>>,
+) -> Option<usize> {
+    outputs
+        .into_iter()
+        .enumerate()
+        .min_by_key(|(i, output)| (ticket(*output), *i))
+        .map(|(i, _)| i)
+}
+
+/// Index drawn by `output` out of `weights`.
+///
+/// Index `i` is drawn with probability `weights[i] / sum(weights)`, without
+/// bias (the draw is rejection sampled from the output draws stream). Returns
+/// `None` if the weights sum is zero or overflows `u128`.
+pub fn select<S: Suite>(output: &Output<S>, weights: &[u128]) -> Option<usize> {
+    let total = weights
+        .iter()
+        .try_fold(0_u128, |acc, w| acc.checked_add(*w))
+        .filter(|total| *total != 0)?;
+    // Draws above the largest multiple of `total` are rejected.
+    let rem = (u128::MAX % total + 1) % total;
+    let mut draw = draws(output);
+    let value = loop {
+        let value = draw();
+        if value <= u128::MAX - rem {
+            break value % total;
+        }
+    };
+    let mut acc = 0;
+    weights.iter().position(|w| {
+        acc += w;
+        value < acc
+    })
+}
+
+/// Returns `true` if `index` is the index drawn by `output` out of `weights`.
+///
+/// Verifier side counterpart of [`select`].
+pub fn verify_select<S: Suite>(output: &Output<S>, weights: &[u128], index: usize) -> bool {
+    select(output, weights) == Some(index)
+}
+
+#[cfg(all(test, feature = "bandersnatch"))]
+mod tests {
+    use super::*;
+    use crate::suites::bandersnatch::BandersnatchSha512Ell2 as S;
+
+    fn outputs(n: u32) -> Vec<Output<S>> {
+        let secret = Secret::<S>::from_seed([0; 32]);
+        (0..n)
+            .map(|i| secret.output(Input::new(&i.to_le_bytes()).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn mul_hi_matches_wide_product() {
+        let m = u128::MAX;
+        assert_eq!(mul_hi(m, m), m - 1);
+        assert_eq!(mul_hi(m, 2), 1);
+        assert_eq!(mul_hi(1 << 64, 1 << 64), 1);
+        assert_eq!(mul_hi(1 << 127, 3), 1);
+        assert_eq!(mul_hi(12345, 67890), 0);
+    }
+
+    #[test]
+    fn eligibility() {
+        let outputs = outputs(1000);
+        for output in &outputs {
+            assert!(!is_eligible(output, 0, 100));
+            assert!(is_eligible(output, 100, 100));
+        }
+        let elected = outputs
+            .iter()
+            .filter(|output| is_eligible(*output, 1, 4))
+            .count();
+        assert!((200..300).contains(&elected));
+
+        let eligible: Vec<_> = outputs.iter().take(10).collect();
+        let i = winner(eligible.iter().copied()).unwrap();
+        assert!(eligible.iter().all(|o| ticket(eligible[i]) <= ticket(*o)));
+        assert_eq!(winner([eligible[3], eligible[3]]), Some(0));
+        assert_eq!(winner::<S>([]), None);
+    }
+
+    #[test]
+    fn weighted_select() {
+        let weights = [0, 1, 3, 0, u128::MAX / 2];
+        let output = outputs(1).remove(0);
+        assert_eq!(select(&output, &[]), None);
+        assert_eq!(select(&output, &[0, 0]), None);
+        assert_eq!(select(&output, &[u128::MAX, 1]), None);
+        assert_eq!(select(&output, &[0, 7, 0]), Some(1));
+        assert_eq!(select(&output, &[u128::MAX]), Some(0));
+
+        let index = select(&output, &weights).unwrap();
+        assert!(verify_select(&output, &weights, index));
+        assert!(!verify_select(&output, &weights, index + 1));
+
+        let mut counts = [0; 4];
+        for output in outputs(1000) {
+            counts[select(&output, &[1, 0, 1, 2]).unwrap()] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!((400..600).contains(&counts[3]));
+        assert!((150..350).contains(&counts[0]));
+    }
+}
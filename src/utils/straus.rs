@@ -43,7 +43,7 @@ fn table<C: AffineRepr>(points: &[C], w: usize) -> Vec<C> {
 }
 
 /// Extracts a `w`-bit digit from position `bit_pos` (LSB-indexed) of the BigInt.
-fn extract_digit<B: BigInteger>(repr: &B, bit_pos: usize, w: usize, mask: u32) -> u32 {
+pub(crate) fn extract_digit<B: BigInteger>(repr: &B, bit_pos: usize, w: usize, mask: u32) -> u32 {
     let limbs = repr.as_ref();
     let limb_idx = bit_pos / 64;
     let bit_idx = bit_pos % 64;
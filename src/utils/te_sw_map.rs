@@ -8,7 +8,7 @@ use ark_ec::{
     short_weierstrass::{Affine as SWAffine, SWCurveConfig},
     twisted_edwards::{Affine as TEAffine, MontCurveConfig, TECurveConfig},
 };
-use ark_ff::{Field, One};
+use ark_ff::{Field, One, Zero, batch_inversion};
 use ark_std::borrow::Cow;
 use ark_std::vec::Vec;
 
@@ -17,6 +17,10 @@ use ark_std::vec::Vec;
 ///
 /// This trait must be implemented for curves that need to be converted between
 /// Twisted Edwards, Short Weierstrass, and Montgomery forms.
+///
+/// The constants are fully determined by the curve's [`MontCurveConfig`]
+/// coefficients. Use [`mont_a_over_three`] and [`mont_b_inv`] to derive them
+/// and [`check_map_config`] to assert that an implementation is consistent.
 pub trait MapConfig: TECurveConfig + SWCurveConfig + MontCurveConfig {
     /// Precomputed value of Montgomery curve parameter A divided by 3.
     const MONT_A_OVER_THREE: <Self as CurveConfig>::BaseField;
@@ -25,6 +29,25 @@ pub trait MapConfig: TECurveConfig + SWCurveConfig + MontCurveConfig {
     const MONT_B_INV: <Self as CurveConfig>::BaseField;
 }
 
+/// Derive [`MapConfig::MONT_A_OVER_THREE`] from the Montgomery coefficient A.
+pub fn mont_a_over_three<C: MontCurveConfig>() -> <C as CurveConfig>::BaseField {
+    let three = <C as CurveConfig>::BaseField::from(3u8);
+    <C as MontCurveConfig>::COEFF_A / three
+}
+
+/// Derive [`MapConfig::MONT_B_INV`] from the Montgomery coefficient B.
+///
+/// Returns `None` if B is zero (not a valid Montgomery curve).
+pub fn mont_b_inv<C: MontCurveConfig>() -> Option<<C as CurveConfig>::BaseField> {
+    <C as MontCurveConfig>::COEFF_B.inverse()
+}
+
+/// Check that the [`MapConfig`] constants match the values derived from the
+/// curve's Montgomery coefficients.
+pub fn check_map_config<C: MapConfig>() -> bool {
+    C::MONT_A_OVER_THREE == mont_a_over_three::<C>() && Some(C::MONT_B_INV) == mont_b_inv::<C>()
+}
+
 /// Map a point in Short Weierstrass form into its corresponding point in Twisted Edwards form.
 ///
 /// This function performs the conversion by first mapping from Short Weierstrass to Montgomery form,
@@ -67,6 +90,100 @@ pub fn te_to_sw<C: MapConfig>(point: &TEAffine<C>) -> Option<SWAffine<C>> {
     Some(SWAffine::new_unchecked(x, y))
 }
 
+/// Batch version of [`sw_to_te`].
+///
+/// All the field inversions are performed with a single batch inversion.
+/// Under the `parallel` feature the input is split in chunks, each batch
+/// inverted independently.
+///
+/// Returns `None` if any point fails conversion.
+pub fn sw_to_te_batch<C: MapConfig>(points: &[SWAffine<C>]) -> Option<Vec<TEAffine<C>>> {
+    batch_map(points, sw_to_te_chunk)
+}
+
+/// Batch version of [`te_to_sw`].
+///
+/// All the field inversions are performed with a single batch inversion.
+/// Under the `parallel` feature the input is split in chunks, each batch
+/// inverted independently.
+///
+/// Returns `None` if any point fails conversion.
+pub fn te_to_sw_batch<C: MapConfig>(points: &[TEAffine<C>]) -> Option<Vec<SWAffine<C>>> {
+    batch_map(points, te_to_sw_chunk)
+}
+
+fn batch_map<T: Sync, U: Send>(
+    points: &[T],
+    f: impl Fn(&[T]) -> Option<Vec<U>> + Sync,
+) -> Option<Vec<U>> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        let chunk_size = points.len().div_ceil(rayon::current_num_threads()).max(1);
+        let chunks = points
+            .par_chunks(chunk_size)
+            .map(&f)
+            .collect::<Option<Vec<_>>>()?;
+        Some(chunks.into_iter().flatten().collect())
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        f(points)
+    }
+}
+
+fn sw_to_te_chunk<C: MapConfig>(points: &[SWAffine<C>]) -> Option<Vec<TEAffine<C>>> {
+    let one = <<C as CurveConfig>::BaseField as One>::one();
+    // (Bx - A/3, By)
+    let mont: Vec<_> = points
+        .iter()
+        .map(|p| {
+            let mx = <C as MontCurveConfig>::COEFF_B * p.x - C::MONT_A_OVER_THREE;
+            let my = <C as MontCurveConfig>::COEFF_B * p.y;
+            (mx, my)
+        })
+        .collect();
+    let mut denoms: Vec<_> = mont.iter().flat_map(|(mx, my)| [*my, *mx + one]).collect();
+    if denoms.iter().any(|d| d.is_zero()) {
+        return None;
+    }
+    batch_inversion(&mut denoms);
+    // (x,y) -> (x/y,(x−1)/(x+1))
+    let points = mont
+        .iter()
+        .zip(denoms.chunks_exact(2))
+        .map(|((mx, _), inv)| TEAffine::new_unchecked(*mx * inv[0], (*mx - one) * inv[1]))
+        .collect();
+    Some(points)
+}
+
+fn te_to_sw_chunk<C: MapConfig>(points: &[TEAffine<C>]) -> Option<Vec<SWAffine<C>>> {
+    let one = <<C as CurveConfig>::BaseField as One>::one();
+    let mut denoms: Vec<_> = points
+        .iter()
+        .flat_map(|p| [one - p.y, p.x - p.x * p.y])
+        .collect();
+    if denoms.iter().any(|d| d.is_zero()) {
+        return None;
+    }
+    batch_inversion(&mut denoms);
+    let points = points
+        .iter()
+        .zip(denoms.chunks_exact(2))
+        .map(|(p, inv)| {
+            // TE to Montgomery: (1+y)/(1-y), (1+y)/(x(1-y))
+            let v_w_num = one + p.y;
+            let v = v_w_num * inv[0];
+            let w = v_w_num * inv[1];
+            // Montgomery to SW: ((x+A/3)/B,y/B)
+            let x = C::MONT_B_INV * (v + C::MONT_A_OVER_THREE);
+            let y = C::MONT_B_INV * w;
+            SWAffine::new_unchecked(x, y)
+        })
+        .collect();
+    Some(points)
+}
+
 /// Trait for types that can be converted from/to Short Weierstrass form.
 ///
 /// This trait provides methods to convert between a type and its Short Weierstrass representation,
@@ -88,6 +205,22 @@ pub trait SWMapping<C: SWCurveConfig>: Sized {
     /// slice if no conversion is needed, or an owned vector if conversion
     /// is required.
     fn to_sw_slice(slice: &[Self]) -> Option<Cow<'_, [SWAffine<C>]>>;
+
+    /// Convert a slice of Short Weierstrass points to a slice of this type.
+    ///
+    /// Returns `None` if any element fails conversion. Returns a borrowed
+    /// slice if no conversion is needed, or an owned vector if conversion
+    /// is required.
+    fn from_sw_slice(slice: &[SWAffine<C>]) -> Option<Cow<'_, [Self]>>
+    where
+        Self: Clone,
+    {
+        slice
+            .iter()
+            .map(|p| Self::from_sw(*p))
+            .collect::<Option<Vec<_>>>()
+            .map(Cow::Owned)
+    }
 }
 
 impl<C: SWCurveConfig> SWMapping<C> for SWAffine<C> {
@@ -105,6 +238,11 @@ impl<C: SWCurveConfig> SWMapping<C> for SWAffine<C> {
     fn to_sw_slice(slice: &[Self]) -> Option<Cow<'_, [SWAffine<C>]>> {
         Some(Cow::Borrowed(slice))
     }
+
+    #[inline(always)]
+    fn from_sw_slice(slice: &[SWAffine<C>]) -> Option<Cow<'_, [Self]>> {
+        Some(Cow::Borrowed(slice))
+    }
 }
 
 impl<C: MapConfig> SWMapping<C> for TEAffine<C> {
@@ -120,23 +258,12 @@ impl<C: MapConfig> SWMapping<C> for TEAffine<C> {
 
     #[inline(always)]
     fn to_sw_slice(slice: &[Self]) -> Option<Cow<'_, [SWAffine<C>]>> {
-        let pks;
-        #[cfg(feature = "parallel")]
-        {
-            use rayon::prelude::*;
-            pks = slice
-                .par_iter()
-                .map(|p| te_to_sw(p))
-                .collect::<Option<Vec<_>>>()?;
-        }
-        #[cfg(not(feature = "parallel"))]
-        {
-            pks = slice
-                .iter()
-                .map(|p| te_to_sw(p))
-                .collect::<Option<Vec<_>>>()?;
-        }
-        Some(Cow::Owned(pks))
+        te_to_sw_batch(slice).map(Cow::Owned)
+    }
+
+    #[inline(always)]
+    fn from_sw_slice(slice: &[SWAffine<C>]) -> Option<Cow<'_, [Self]>> {
+        sw_to_te_batch(slice).map(Cow::Owned)
     }
 }
 
@@ -161,6 +288,22 @@ pub trait TEMapping<C: TECurveConfig>: Sized {
     /// slice if no conversion is needed, or an owned vector if conversion
     /// is required.
     fn to_te_slice(slice: &[Self]) -> Option<Cow<'_, [TEAffine<C>]>>;
+
+    /// Convert a slice of Twisted Edwards points to a slice of this type.
+    ///
+    /// Returns `None` if any element fails conversion. Returns a borrowed
+    /// slice if no conversion is needed, or an owned vector if conversion
+    /// is required.
+    fn from_te_slice(slice: &[TEAffine<C>]) -> Option<Cow<'_, [Self]>>
+    where
+        Self: Clone,
+    {
+        slice
+            .iter()
+            .map(|p| Self::from_te(*p))
+            .collect::<Option<Vec<_>>>()
+            .map(Cow::Owned)
+    }
 }
 
 impl<C: TECurveConfig> TEMapping<C> for TEAffine<C> {
@@ -178,6 +321,11 @@ impl<C: TECurveConfig> TEMapping<C> for TEAffine<C> {
     fn to_te_slice(slice: &[Self]) -> Option<Cow<'_, [TEAffine<C>]>> {
         Some(Cow::Borrowed(slice))
     }
+
+    #[inline(always)]
+    fn from_te_slice(slice: &[TEAffine<C>]) -> Option<Cow<'_, [Self]>> {
+        Some(Cow::Borrowed(slice))
+    }
 }
 
 impl<C: MapConfig> TEMapping<C> for SWAffine<C> {
@@ -193,22 +341,11 @@ impl<C: MapConfig> TEMapping<C> for SWAffine<C> {
 
     #[inline(always)]
     fn to_te_slice(slice: &[Self]) -> Option<Cow<'_, [TEAffine<C>]>> {
-        let pks;
-        #[cfg(feature = "parallel")]
-        {
-            use rayon::prelude::*;
-            pks = slice
-                .par_iter()
-                .map(|p| sw_to_te(p))
-                .collect::<Option<Vec<_>>>()?;
-        }
-        #[cfg(not(feature = "parallel"))]
-        {
-            pks = slice
-                .iter()
-                .map(|p| sw_to_te(p))
-                .collect::<Option<Vec<_>>>()?;
-        }
-        Some(Cow::Owned(pks))
+        sw_to_te_batch(slice).map(Cow::Owned)
+    }
+
+    #[inline(always)]
+    fn from_te_slice(slice: &[TEAffine<C>]) -> Option<Cow<'_, [Self]>> {
+        te_to_sw_batch(slice).map(Cow::Owned)
     }
 }
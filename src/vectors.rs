@@ -0,0 +1,554 @@
+//! # Test vectors
+//!
+//! Generation and processing of the JSON test vectors published in
+//! `data/vectors`, so that implementations in other languages can produce
+//! and consume exactly the same vectors.
+//!
+//! Each vector is a flat JSON object with hex encoded fields (little-endian,
+//! compressed encodings). The fields shared by all schemes are produced by
+//! [`TestVector`]; the per-scheme vectors extend them with the proof fields:
+//!
+//! - [`tiny::vectors::TestVector`](crate::tiny::vectors::TestVector)
+//! - [`thin::vectors::TestVector`](crate::thin::vectors::TestVector)
+//! - [`pedersen::vectors::TestVector`](crate::pedersen::vectors::TestVector)
+//! - `ring::vectors::TestVector` (with `ring`)
+//!
+//! Vectors are named after [`SuiteExt::SUITE_NAME`] and the scheme, e.g.
+//! `bandersnatch_sha-512_ell2_tiny`.
+//!
+//! The key pairs of the RFC-9381 appendix examples are checked by
+//! [`key_derivation`].
+//!
+//! ## Negative vectors
+//!
+//! A vector with the `"expected": "invalid"` field must be rejected by
+//! [`TestVectorTrait::verify_map`], which checks a vector using only its
+//! public fields. [`negative`] derives such vectors from a valid one, so that
+//! verifiers are exercised against:
+//!
+//! - a corrupted proof challenge;
+//! - a non-canonical proof response scalar;
+//! - a proof bound to another suite identifier (see [`WrongSuiteId`]);
+//! - an output point `gamma` not on the curve.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use ark_vrf::{suites::bandersnatch::BandersnatchSha512Ell2, tiny, vectors};
+//! use ark_vrf::vectors::TestVectorTrait;
+//!
+//! type Vector = tiny::vectors::TestVector<BandersnatchSha512Ell2>;
+//!
+//! let json = vectors::to_json(&vectors::generate::<Vector>("my-vectors"));
+//! for vector in vectors::from_json::<Vector>(&json)? {
+//!     vector.run();
+//! }
+//! assert_eq!(vectors::process::<Vector>(&json)?, 7);
+//! ```
+
+use crate::*;
+
+pub mod key_derivation;
+
+/// Zcash SRS file.
+///
+/// Derived from <https://zfnd.org/conclusion-of-the-powers-of-tau-ceremony>.
+/// Domain size: 2^11.
+pub const BLS12_381_PCS_SRS_FILE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/data/srs/bls12-381-srs-2-11-uncompressed-zcash.bin"
+);
+
+/// Pure testing SRS file
+///
+/// Derived from seed `[0_u8; 32]`.
+/// Domain size 2^9.
+pub const BN254_PCS_SRS_FILE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/data/srs/bn254-testing-2-9-uncompressed.bin"
+);
+
+// Points and scalars encoding utilities (little-endian, compressed).
+
+/// Point encode.
+pub fn point_encode<S: Suite>(pt: &AffinePoint<S>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    pt.serialize_compressed(&mut buf).unwrap();
+    buf
+}
+
+/// Point decode.
+pub fn point_decode<S: Suite>(buf: &[u8]) -> Result<AffinePoint<S>, Error> {
+    AffinePoint::<S>::deserialize_compressed(buf).map_err(Into::into)
+}
+
+/// Scalar encode.
+pub fn scalar_encode<S: Suite>(sc: &ScalarField<S>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    sc.serialize_compressed(&mut buf).unwrap();
+    buf
+}
+
+/// Scalar decode.
+pub fn scalar_decode<S: Suite>(buf: &[u8]) -> ScalarField<S> {
+    ScalarField::<S>::from_le_bytes_mod_order(buf)
+}
+
+/// Vector fields, in order, as hex strings.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TestVectorMap(pub indexmap::IndexMap<String, String>);
+
+impl TestVectorMap {
+    /// Returns true if the vector is expected to fail verification.
+    pub fn is_invalid(&self) -> bool {
+        self.0.get("expected").is_some_and(|v| v == "invalid")
+    }
+
+    /// Mark the vector as expected to fail verification.
+    pub fn set_invalid(&mut self) {
+        self.0.insert("expected".to_string(), "invalid".to_string());
+    }
+
+    /// Decode a hex field.
+    ///
+    /// Returns `Error::InvalidData` if the field is missing or not hex.
+    pub fn try_get_bytes(&self, field: &str) -> Result<Vec<u8>, Error> {
+        let value = self.0.get(field).ok_or(Error::InvalidData)?;
+        hex::decode(value).map_err(|_| Error::InvalidData)
+    }
+
+    /// Decode a field holding a compressed encoding, with full validity checks.
+    ///
+    /// Returns `Error::InvalidData` if the field is missing or its value is
+    /// not the canonical encoding of a valid `T`.
+    pub fn try_get<T: CanonicalSerialize + CanonicalDeserialize>(
+        &self,
+        field: &str,
+    ) -> Result<T, Error> {
+        utils::decode_canonical(&self.try_get_bytes(field)?)
+    }
+
+    /// Decode a hex field.
+    pub fn get_bytes(&self, field: &str) -> Vec<u8> {
+        hex::decode(self.0.get(field).unwrap()).unwrap()
+    }
+
+    /// Set a field to the hex encoding of `buf`.
+    pub fn set_bytes(&mut self, field: &str, buf: &[u8]) {
+        self.0.insert(field.to_string(), hex::encode(buf));
+    }
+
+    /// Decode a field holding a compressed encoding.
+    pub fn get<T: CanonicalDeserialize>(&self, field: &str) -> T {
+        let buf = self.get_bytes(field);
+        T::deserialize_compressed(&buf[..]).unwrap()
+    }
+
+    /// Set a field to the compressed encoding of `value`.
+    pub fn set(&mut self, field: &str, value: &impl CanonicalSerialize) {
+        let mut buf = Vec::new();
+        value.serialize_compressed(&mut buf).unwrap();
+        self.set_bytes(field, &buf);
+    }
+}
+
+/// Test vector of a VRF scheme.
+///
+/// Conversions panic on malformed fields and [`Self::run`] panics on the
+/// first mismatch, naming the offending field.
+pub trait TestVectorTrait {
+    /// Vectors suite.
+    type Suite: Suite;
+
+    /// Vectors name, e.g. `bandersnatch_sha-512_ell2_tiny`.
+    fn name() -> String;
+
+    /// Build a vector for the secret derived from `seed`.
+    fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self;
+
+    /// Build a vector from its fields.
+    fn from_map(map: &TestVectorMap) -> Self;
+
+    /// Vector fields.
+    fn to_map(&self) -> TestVectorMap;
+
+    /// Recompute every field and check it against the vector.
+    fn run(&self);
+
+    /// Check a vector using only its public fields.
+    ///
+    /// Unlike [`Self::from_map`], fields are decoded with the full validity
+    /// checks and errors are returned instead of panicking, so negative
+    /// vectors can be processed.
+    fn verify_map(map: &TestVectorMap) -> Result<(), Error>;
+
+    /// Same vector with the proof bound to [`WrongSuiteId`].
+    ///
+    /// Defaults to `None`, for vectors without a proof.
+    fn wrong_suite_id(&self) -> Option<TestVectorMap> {
+        None
+    }
+}
+
+/// Suite with a name for its vectors.
+pub trait SuiteExt: Suite {
+    /// Suite name, used as vectors name prefix.
+    const SUITE_NAME: &str;
+}
+
+/// Suite `S` bound to another suite identifier.
+///
+/// Everything but [`Suite::SUITE_ID`] is delegated to `S`, so keys, inputs
+/// and outputs are the ones of `S` while proofs must not verify for `S`.
+#[derive(Debug, Clone, Copy)]
+pub struct WrongSuiteId<S>(core::marker::PhantomData<S>);
+
+impl<S: Suite> Suite for WrongSuiteId<S> {
+    const SUITE_ID: suites::SuiteId = {
+        let mut id = S::SUITE_ID;
+        id.version ^= 0xff;
+        id
+    };
+    const THIN_VRF_DOMAIN: thin::Domain = S::THIN_VRF_DOMAIN;
+    const TAI_FIXED: bool = S::TAI_FIXED;
+
+    type Affine = S::Affine;
+    type Transcript = S::Transcript;
+
+    fn generator() -> AffinePoint<Self> {
+        S::generator()
+    }
+
+    fn nonce(sk: &ScalarField<Self>, transcript: Option<Self::Transcript>) -> ScalarField<Self> {
+        S::nonce(sk, transcript)
+    }
+
+    fn challenge(
+        pts: &[&AffinePoint<Self>],
+        transcript: Option<Self::Transcript>,
+    ) -> ScalarField<Self> {
+        S::challenge(pts, transcript)
+    }
+
+    fn absorb_ad(transcript: &mut Self::Transcript, ad: &[u8]) {
+        S::absorb_ad(transcript, ad)
+    }
+
+    fn data_to_point(data: &[u8]) -> Option<AffinePoint<Self>> {
+        S::data_to_point(data)
+    }
+
+    fn point_to_hash<const N: usize>(pt: &AffinePoint<Self>) -> [u8; N] {
+        S::point_to_hash(pt)
+    }
+}
+
+impl<S: pedersen::PedersenSuite> pedersen::PedersenSuite for WrongSuiteId<S> {
+    const BLINDING_BASE: AffinePoint<Self> = S::BLINDING_BASE;
+
+    fn blinding(secret: &ScalarField<Self>, transcript: Self::Transcript) -> ScalarField<Self> {
+        S::blinding(secret, transcript)
+    }
+
+    fn blinding_with_context(
+        secret: &ScalarField<Self>,
+        transcript: Self::Transcript,
+        context: &[u8],
+    ) -> ScalarField<Self> {
+        S::blinding_with_context(secret, transcript, context)
+    }
+}
+
+/// Fields shared by the vectors of all schemes.
+#[derive(Clone)]
+pub struct TestVector<S: Suite> {
+    /// Useful info for the vector.
+    pub comment: String,
+    /// Secret key scalar.
+    pub sk: ScalarField<S>,
+    /// Public key point.
+    pub pk: AffinePoint<S>,
+    /// VRF input raw data.
+    pub alpha: Vec<u8>,
+    /// Signature additional raw data.
+    pub ad: Vec<u8>,
+    /// VRF input point.
+    pub h: AffinePoint<S>,
+    /// VRF output point.
+    pub gamma: AffinePoint<S>,
+    /// VRF output raw data
+    pub beta: Vec<u8>,
+}
+
+impl<S: Suite> core::fmt::Debug for TestVector<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sk = hex::encode(scalar_encode::<S>(&self.sk));
+        let pk = hex::encode(point_encode::<S>(&self.pk));
+        let alpha = hex::encode(&self.alpha);
+        let ad = hex::encode(&self.ad);
+        let h = hex::encode(point_encode::<S>(&self.h));
+        let gamma = hex::encode(point_encode::<S>(&self.gamma));
+        let beta = hex::encode(&self.beta);
+        f.debug_struct("TestVector")
+            .field("comment", &self.comment)
+            .field("sk", &sk)
+            .field("pk", &pk)
+            .field("alpha", &alpha)
+            .field("ad", &ad)
+            .field("h", &h)
+            .field("gamma", &gamma)
+            .field("beta", &beta)
+            .finish()
+    }
+}
+
+impl<S: Suite> TestVector<S> {
+    /// Check the public input and output fields of a vector.
+    ///
+    /// Returns the decoded I/O pair. Fails with `Error::InvalidData` for a
+    /// malformed field and `Error::VerificationFailure` if `h` or `beta` don't
+    /// match `alpha` and `gamma`.
+    pub fn verify_io(map: &TestVectorMap) -> Result<VrfIo<S>, Error> {
+        let input =
+            Input::<S>::new(&map.try_get_bytes("alpha")?).ok_or(Error::HashToCurveFailure)?;
+        let output: Output<S> = map.try_get("gamma")?;
+        if map.try_get::<AffinePoint<S>>("h")? != input.0
+            || map.try_get_bytes("beta")? != output.hash::<32>()
+        {
+            return Err(Error::VerificationFailure);
+        }
+        Ok(VrfIo { input, output })
+    }
+}
+
+impl<S: SuiteExt + std::fmt::Debug> TestVectorTrait for TestVector<S> {
+    type Suite = S;
+
+    fn name() -> String {
+        S::SUITE_NAME.to_string() + "_base"
+    }
+
+    fn new(comment: &str, seed: &[u8; 32], alpha: &[u8], ad: &[u8]) -> Self {
+        let sk = Secret::<S>::from_seed(*seed);
+        let pk = sk.public().0;
+
+        let h = <S as Suite>::data_to_point(alpha).unwrap();
+        let input = Input::from_affine_unchecked(h);
+
+        let alpha = alpha.to_vec();
+        let output = sk.output(input);
+        let gamma = output.0;
+        let beta = output.hash::<32>().to_vec();
+
+        TestVector {
+            comment: comment.to_string(),
+            sk: sk.scalar,
+            pk,
+            alpha,
+            ad: ad.to_vec(),
+            h,
+            gamma,
+            beta,
+        }
+    }
+
+    fn from_map(map: &TestVectorMap) -> Self {
+        let item_bytes = |field| hex::decode(map.0.get(field).unwrap()).unwrap();
+        let comment = map.0.get("comment").unwrap().to_string();
+        let sk = scalar_decode::<S>(&item_bytes("sk"));
+        let pk = point_decode::<S>(&item_bytes("pk")).unwrap();
+        let alpha = item_bytes("alpha");
+        let ad = item_bytes("ad");
+        let h = point_decode::<S>(&item_bytes("h")).unwrap();
+        let gamma = point_decode::<S>(&item_bytes("gamma")).unwrap();
+        let beta = item_bytes("beta");
+        Self {
+            comment,
+            sk,
+            pk,
+            alpha,
+            ad,
+            h,
+            gamma,
+            beta,
+        }
+    }
+
+    fn to_map(&self) -> TestVectorMap {
+        let items = [
+            ("comment", self.comment.clone()),
+            ("sk", hex::encode(scalar_encode::<S>(&self.sk))),
+            ("pk", hex::encode(point_encode::<S>(&self.pk))),
+            ("alpha", hex::encode(&self.alpha)),
+            ("ad", hex::encode(&self.ad)),
+            ("h", hex::encode(point_encode::<S>(&self.h))),
+            ("gamma", hex::encode(point_encode::<S>(&self.gamma))),
+            ("beta", hex::encode(&self.beta)),
+        ];
+        let map: indexmap::IndexMap<String, String> =
+            items.into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+        TestVectorMap(map)
+    }
+
+    fn run(&self) {
+        println!("Run test vector: {}", self.comment);
+
+        let sk = Secret::<S>::from_scalar(self.sk);
+
+        let pk = sk.public();
+        assert_eq!(self.pk, pk.0, "public key ('pk') mismatch");
+
+        let h = S::data_to_point(&self.alpha).unwrap();
+        assert_eq!(self.h, h, "hash-to-curve ('h') mismatch");
+        let input = Input::<S>::from_affine_unchecked(h);
+
+        let output = sk.output(input);
+        assert_eq!(self.gamma, output.0, "VRF pre-output ('gamma') mismatch");
+
+        let beta = output.hash::<32>().to_vec();
+        assert_eq!(self.beta, beta, "VRF output ('beta') mismatch");
+    }
+
+    fn verify_map(map: &TestVectorMap) -> Result<(), Error> {
+        Self::verify_io(map).map(|_| ())
+    }
+}
+
+/// Compressed encoding which is not a point of `S`.
+fn off_curve_encoding<S: Suite>() -> Vec<u8> {
+    let len = S::generator().compressed_size();
+    (0..=u8::MAX)
+        .map(|i| {
+            let mut buf = vec![0; len];
+            buf[0] = i;
+            buf
+        })
+        .find(|buf| AffinePoint::<S>::deserialize_compressed_unchecked(&buf[..]).is_err())
+        .expect("some small x coordinate is not on the curve")
+}
+
+/// Negative vectors derived from a valid vector.
+///
+/// Each map is marked as invalid and its comment names the applied mutation.
+/// Mutations of fields missing from the vector are skipped (e.g. there is no
+/// challenge to corrupt in a Thin VRF proof).
+pub fn negative<V: TestVectorTrait>(vector: &V) -> Vec<TestVectorMap> {
+    let map = vector.to_map();
+    let mutate = |name: &'static str, field: &str, f: &dyn Fn(&mut Vec<u8>)| {
+        let mut buf = map.try_get_bytes(field).ok()?;
+        f(&mut buf);
+        let mut map = map.clone();
+        map.set_bytes(field, &buf);
+        Some((name, map))
+    };
+    let off_curve = off_curve_encoding::<V::Suite>();
+    let mutations = [
+        mutate("corrupted challenge", "proof_c", &|buf| buf[0] ^= 1),
+        mutate("non-canonical scalar", "proof_s", &|buf| buf.fill(0xff)),
+        mutate("wrong scheme id", "scheme_id", &|buf| buf[4] ^= 1),
+        mutate("off-curve gamma", "gamma", &|buf| {
+            buf.clone_from(&off_curve)
+        }),
+        vector.wrong_suite_id().map(|map| ("wrong suite id", map)),
+    ];
+    mutations
+        .into_iter()
+        .flatten()
+        .map(|(name, mut map)| {
+            let comment = format!("{} - {}", map.0["comment"], name);
+            map.0.insert("comment".to_string(), comment);
+            map.set_invalid();
+            map
+        })
+        .collect()
+}
+
+/// Generate the published set of vectors.
+///
+/// Each vector is checked with [`TestVectorTrait::run`] and commented with
+/// `identifier` and its position.
+pub fn generate<V: TestVectorTrait>(identifier: &str) -> Vec<V> {
+    // ("secret_seed", "vrf raw input", "additional data"))
+    let var_data: Vec<(u8, &[u8], &[u8])> = vec![
+        (1, b"", b""),
+        (2, b"0a", b""),
+        (3, b"", b"0b8c"),
+        (4, b"73616D706C65", b""),
+        (5, b"42616E646572736E6174636820766563746F72", b""),
+        (5, b"42616E646572736E6174636820766563746F72", b"1F42"),
+        (6, b"42616E646572736E6174636820766563746F72", b"1F42"),
+    ];
+
+    var_data
+        .iter()
+        .enumerate()
+        .map(|(i, var_data)| {
+            let alpha = hex::decode(var_data.1).unwrap();
+            let ad = hex::decode(var_data.2).unwrap();
+            let comment = format!("{} - vector-{}", identifier, i + 1);
+            let mut seed = [0u8; 32];
+            seed[0] = var_data.0;
+            let vector = V::new(&comment, &seed, &alpha, &ad);
+            println!("Gen test vector: {}", comment);
+            vector.run();
+            vector
+        })
+        .collect()
+}
+
+/// Encode vectors as a pretty printed JSON array.
+pub fn to_json<V: TestVectorTrait>(vectors: &[V]) -> String {
+    let vector_maps: Vec<_> = vectors.iter().map(V::to_map).collect();
+    serde_json::to_string_pretty(&vector_maps).unwrap()
+}
+
+/// Decode the valid vectors from a JSON array.
+///
+/// Vectors marked as invalid are skipped. Returns `Error::InvalidData` if
+/// `json` is not an array of objects with string fields.
+pub fn from_json<V: TestVectorTrait>(json: &str) -> Result<Vec<V>, Error> {
+    let vector_maps: Vec<TestVectorMap> =
+        serde_json::from_str(json).map_err(|_| Error::InvalidData)?;
+    Ok(vector_maps
+        .iter()
+        .filter(|map| !map.is_invalid())
+        .map(V::from_map)
+        .collect())
+}
+
+/// Check all the vectors of a JSON array.
+///
+/// Valid vectors are checked with [`TestVectorTrait::run`] and
+/// [`TestVectorTrait::verify_map`], and their [`negative`] vectors must be
+/// rejected, as must the vectors marked as invalid. Panics on the first
+/// failed check and returns the number of valid vectors.
+///
+/// Returns `Error::InvalidData` if `json` is not an array of objects with
+/// string fields.
+pub fn process<V: TestVectorTrait>(json: &str) -> Result<usize, Error> {
+    let vector_maps: Vec<TestVectorMap> =
+        serde_json::from_str(json).map_err(|_| Error::InvalidData)?;
+    let mut count = 0;
+    for map in &vector_maps {
+        let comment = &map.0["comment"];
+        if map.is_invalid() {
+            assert!(
+                V::verify_map(map).is_err(),
+                "invalid vector accepted: {comment}"
+            );
+            continue;
+        }
+        let vector = V::from_map(map);
+        vector.run();
+        assert_eq!(V::verify_map(map), Ok(()), "vector rejected: {comment}");
+        for map in negative(&vector) {
+            let comment = &map.0["comment"];
+            assert!(
+                V::verify_map(&map).is_err(),
+                "invalid vector accepted: {comment}"
+            );
+        }
+        count += 1;
+    }
+    Ok(count)
+}
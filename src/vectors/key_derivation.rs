@@ -0,0 +1,165 @@
+//! # Key derivation vectors
+//!
+//! Checks of the secret derivation and public key encoding of the Ed25519
+//! and P-256 suites against the key pairs of the RFC-9381 appendix B examples,
+//! in `data/vectors/rfc-9381`.
+//!
+//! The examples are parsed from the appendix ASCII format, where each example
+//! is a sequence of `name = value` lines:
+//!
+//! ```text
+//! SK = 9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60
+//! PK = d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a
+//! alpha = (the empty string)
+//! ```
+//!
+//! Values are hex, optionally followed by a parenthesized note, and may
+//! continue on the following lines. Every `SK` line starts a new example.
+//! Lines with a multi-word name (e.g. `try_and_increment succeeded on ctr = 0`)
+//! and lines starting with `#` are ignored.
+//!
+//! Only the key pairs are checked: the proofs and outputs of the built-in
+//! suites are not RFC-9381 compatible (see the README), so the examples `pi`
+//! and `beta` can't be reproduced.
+
+use crate::*;
+use indexmap::IndexMap;
+
+/// RFC-9381 appendix example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rfc9381Vector {
+    /// Decoded fields, in order of appearance.
+    pub fields: IndexMap<String, Vec<u8>>,
+}
+
+impl Rfc9381Vector {
+    /// Field value.
+    ///
+    /// Returns `Error::InvalidData` if the field is missing.
+    pub fn get(&self, name: &str) -> Result<&[u8], Error> {
+        self.fields
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or(Error::InvalidData)
+    }
+}
+
+fn hex_value(value: &str) -> Result<Vec<u8>, Error> {
+    match value.split_whitespace().next() {
+        None => Ok(Vec::new()),
+        Some(token) if token.starts_with('(') => Ok(Vec::new()),
+        Some(token) => hex::decode(token).map_err(|_| Error::InvalidData),
+    }
+}
+
+/// Parse RFC-9381 appendix examples.
+///
+/// Returns `Error::InvalidData` for a malformed value or a field preceding
+/// the first `SK`.
+pub fn parse(text: &str) -> Result<Vec<Rfc9381Vector>, Error> {
+    let mut vectors: Vec<Rfc9381Vector> = Vec::new();
+    let mut last: Option<String> = None;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            last = None;
+            continue;
+        }
+        let Some((name, value)) = line.split_once('=') else {
+            // Continuation of the previous hex value.
+            let Some(name) = &last else { continue };
+            let field = vectors
+                .last_mut()
+                .and_then(|v| v.fields.get_mut(name))
+                .ok_or(Error::InvalidData)?;
+            field.extend(hex_value(line)?);
+            continue;
+        };
+        let name = name.trim();
+        if name.contains(char::is_whitespace) {
+            last = None;
+            continue;
+        }
+        if name == "SK" {
+            vectors.push(Rfc9381Vector {
+                fields: IndexMap::new(),
+            });
+        }
+        let vector = vectors.last_mut().ok_or(Error::InvalidData)?;
+        vector.fields.insert(name.to_string(), hex_value(value)?);
+        last = Some(name.to_string());
+    }
+    Ok(vectors)
+}
+
+/// Check the key pair of an ECVRF-EDWARDS25519-SHA512 example.
+///
+/// The secret is derived from `SK` as in RFC-8032 section 5.1.5 (see
+/// [`secret_from_rfc_8032_seed`](crate::suites::ed25519::secret_from_rfc_8032_seed))
+/// and the public key is compared with `PK` in the RFC-8032 encoding.
+#[cfg(feature = "ed25519")]
+pub fn check_ed25519_key_pair(vector: &Rfc9381Vector) -> Result<(), Error> {
+    use crate::suites::ed25519::secret_from_rfc_8032_seed;
+
+    let sk = vector.get("SK")?;
+    let seed = sk.try_into().map_err(|_| Error::InvalidData)?;
+    let public = secret_from_rfc_8032_seed(seed).public();
+    (rfc_8032_encode(&public.0)? == vector.get("PK")?)
+        .then_some(())
+        .ok_or(Error::VerificationFailure)
+}
+
+#[cfg(feature = "ed25519")]
+fn rfc_8032_encode(point: &ark_ed25519::EdwardsAffine) -> Result<Vec<u8>, Error> {
+    use ark_ff::BigInteger;
+    let (x, y) = point.xy().ok_or(Error::IdentityPoint)?;
+    let mut buf = y.into_bigint().to_bytes_le();
+    if x.into_bigint().is_odd() {
+        buf[31] |= 0x80;
+    }
+    Ok(buf)
+}
+
+/// Check the key pair of an ECVRF-P256-SHA256 example.
+///
+/// The secret scalar is `SK` read as a big-endian integer and the public key
+/// is compared with `PK` in the SEC1 compressed encoding.
+#[cfg(feature = "secp256r1")]
+pub fn check_p256_key_pair(vector: &Rfc9381Vector) -> Result<(), Error> {
+    use crate::suites::secp256r1::Secp256r1Sha256Tai;
+
+    let sk = vector.get("SK")?;
+    if sk.len() != 32 {
+        return Err(Error::InvalidData);
+    }
+    let scalar = ScalarField::<Secp256r1Sha256Tai>::from_be_bytes_mod_order(sk);
+    let public = Secret::<Secp256r1Sha256Tai>::from_scalar(scalar).public();
+    (utils::sec1::encode_point(&public.0) == vector.get("PK")?)
+        .then_some(())
+        .ok_or(Error::VerificationFailure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_appendix_format() {
+        let text = "
+            SK = 00
+            alpha = 72 (1 byte)
+            pi = 0102
+               0304
+            try_and_increment succeeded on ctr = 0
+
+            SK = ff
+            alpha = (the empty string)
+        ";
+        let vectors = parse(text).unwrap();
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(vectors[0].get("alpha"), Ok(&[0x72][..]));
+        assert_eq!(vectors[0].get("pi"), Ok(&[1, 2, 3, 4][..]));
+        assert_eq!(vectors[1].get("alpha"), Ok(&[][..]));
+        assert_eq!(vectors[1].get("pi"), Err(Error::InvalidData));
+        assert_eq!(parse("PK = 00"), Err(Error::InvalidData));
+    }
+}
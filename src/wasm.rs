@@ -0,0 +1,368 @@
+//! # WebAssembly bindings
+//!
+//! [`wasm_bindgen`] wrappers for the built-in suites, exchanging `Uint8Array`s
+//! in the suites' compressed encoding.
+//!
+//! Each enabled suite is exposed as a JS class with static methods
+//! (e.g. `Bandersnatch.tinyProve(secret, data, ad)`). Inputs are decoded with
+//! [`decode_canonical`](crate::utils::decode_canonical) and failures are
+//! thrown as JS errors. Verification methods return `false` for a proof which
+//! doesn't verify and throw for malformed inputs.
+//!
+//! Ring suites additionally get `<Suite>RingProver` and `<Suite>RingVerifier`
+//! classes, constructed from the SRS bytes (the uncompressed
+//! [`PcsParams`](crate::ring::PcsParams) encoding, as distributed by the
+//! ceremony) so that no filesystem access is required.
+//!
+//! Secret splitting requires `secret-split-custom-rng` and a registered masks
+//! randomness source (see [`utils::secret_split`](crate::utils::secret_split)),
+//! as OS randomness (`getrandom`) is not available on `wasm32-unknown-unknown`.
+
+use crate::utils::decode_canonical as decode;
+use crate::*;
+use wasm_bindgen::prelude::*;
+
+/// Length of the hash returned by the `outputHash` methods.
+pub const OUTPUT_HASH_LEN: usize = 32;
+
+fn encode<T: CanonicalSerialize>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut buf = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut buf)?;
+    Ok(buf)
+}
+
+fn input<S: Suite>(data: &[u8]) -> Result<Input<S>, Error> {
+    Input::new(data).ok_or(Error::HashToCurveFailure)
+}
+
+fn vrf_io<S: Suite>(data: &[u8], output: &[u8]) -> Result<VrfIo<S>, Error> {
+    Ok(VrfIo {
+        input: input(data)?,
+        output: decode(output)?,
+    })
+}
+
+fn verdict(res: Result<(), Error>) -> Result<bool, Error> {
+    match res {
+        Ok(()) => Ok(true),
+        Err(Error::VerificationFailure) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+fn secret_from_seed<S: Suite>(seed: &[u8]) -> Result<Vec<u8>, Error> {
+    let seed = seed.try_into().map_err(|_| Error::InvalidData)?;
+    encode(&Secret::<S>::from_seed(seed))
+}
+
+fn public_from_secret<S: Suite>(secret: &[u8]) -> Result<Vec<u8>, Error> {
+    encode(&decode::<Secret<S>>(secret)?.public())
+}
+
+fn output<S: Suite>(secret: &[u8], data: &[u8]) -> Result<Vec<u8>, Error> {
+    let secret: Secret<S> = decode(secret)?;
+    encode(&secret.output(input(data)?))
+}
+
+fn output_hash<S: Suite>(output: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(decode::<Output<S>>(output)?
+        .hash::<OUTPUT_HASH_LEN>()
+        .to_vec())
+}
+
+fn tiny_prove<S: Suite>(secret: &[u8], data: &[u8], ad: &[u8]) -> Result<Vec<u8>, Error> {
+    use tiny::Prover;
+    let secret: Secret<S> = decode(secret)?;
+    let io = secret.vrf_io(input(data)?);
+    encode(&secret.prove(io, ad))
+}
+
+fn tiny_verify<S: Suite>(
+    public: &[u8],
+    data: &[u8],
+    output: &[u8],
+    ad: &[u8],
+    proof: &[u8],
+) -> Result<bool, Error> {
+    use tiny::Verifier;
+    let public: Public<S> = decode(public)?;
+    let proof: tiny::Proof<S> = decode(proof)?;
+    verdict(public.verify(vrf_io(data, output)?, ad, &proof))
+}
+
+fn pedersen_prove<S: pedersen::PedersenSuite>(
+    secret: &[u8],
+    data: &[u8],
+    ad: &[u8],
+) -> Result<Vec<u8>, Error> {
+    use pedersen::Prover;
+    let secret: Secret<S> = decode(secret)?;
+    let io = secret.vrf_io(input(data)?);
+    encode(&secret.prove(io, ad).0)
+}
+
+fn pedersen_verify<S: pedersen::PedersenSuite>(
+    data: &[u8],
+    output: &[u8],
+    ad: &[u8],
+    proof: &[u8],
+) -> Result<bool, Error> {
+    use pedersen::Verifier;
+    let proof: pedersen::Proof<S> = decode(proof)?;
+    verdict(Public::<S>::verify(vrf_io(data, output)?, ad, &proof))
+}
+
+#[cfg(feature = "ring")]
+fn ring_setup<S: ring::RingSuite>(
+    srs: &[u8],
+    ring_size: usize,
+) -> Result<ring::RingSetup<S>, Error> {
+    let pcs_params = ring::PcsParams::<S>::deserialize_uncompressed_unchecked(srs)?;
+    ring::RingSetup::from_pcs_params(ring_size, pcs_params)
+}
+
+#[cfg(feature = "ring")]
+fn ring_keys<S: Suite>(ring: &[u8]) -> Result<Vec<AffinePoint<S>>, Error> {
+    let len = S::generator().compressed_size();
+    if ring.len() % len != 0 {
+        return Err(Error::InvalidData);
+    }
+    ring.chunks(len)
+        .map(|pk| decode::<Public<S>>(pk).map(|pk| pk.0))
+        .collect()
+}
+
+macro_rules! suite_bindings {
+    ($name:ident, $suite:ty) => {
+        #[doc = concat!("Bindings for [`", stringify!($suite), "`].")]
+        #[wasm_bindgen]
+        pub struct $name(());
+
+        #[wasm_bindgen]
+        impl $name {
+            /// Derive a secret key from a 32-byte seed.
+            #[wasm_bindgen(js_name = secretFromSeed)]
+            pub fn secret_from_seed(seed: &[u8]) -> Result<Vec<u8>, JsError> {
+                Ok(secret_from_seed::<$suite>(seed)?)
+            }
+
+            /// Public key of a secret key.
+            #[wasm_bindgen(js_name = publicFromSecret)]
+            pub fn public_from_secret(secret: &[u8]) -> Result<Vec<u8>, JsError> {
+                Ok(public_from_secret::<$suite>(secret)?)
+            }
+
+            /// VRF output point for the input derived from `data`.
+            pub fn output(secret: &[u8], data: &[u8]) -> Result<Vec<u8>, JsError> {
+                Ok(output::<$suite>(secret, data)?)
+            }
+
+            /// Hash of a VRF output point.
+            #[wasm_bindgen(js_name = outputHash)]
+            pub fn output_hash(output: &[u8]) -> Result<Vec<u8>, JsError> {
+                Ok(output_hash::<$suite>(output)?)
+            }
+
+            /// Tiny VRF proof for the input derived from `data`.
+            #[wasm_bindgen(js_name = tinyProve)]
+            pub fn tiny_prove(secret: &[u8], data: &[u8], ad: &[u8]) -> Result<Vec<u8>, JsError> {
+                Ok(tiny_prove::<$suite>(secret, data, ad)?)
+            }
+
+            /// Verify a Tiny VRF proof.
+            #[wasm_bindgen(js_name = tinyVerify)]
+            pub fn tiny_verify(
+                public: &[u8],
+                data: &[u8],
+                output: &[u8],
+                ad: &[u8],
+                proof: &[u8],
+            ) -> Result<bool, JsError> {
+                Ok(tiny_verify::<$suite>(public, data, output, ad, proof)?)
+            }
+
+            /// Pedersen VRF proof for the input derived from `data`.
+            #[wasm_bindgen(js_name = pedersenProve)]
+            pub fn pedersen_prove(
+                secret: &[u8],
+                data: &[u8],
+                ad: &[u8],
+            ) -> Result<Vec<u8>, JsError> {
+                Ok(pedersen_prove::<$suite>(secret, data, ad)?)
+            }
+
+            /// Verify a Pedersen VRF proof.
+            #[wasm_bindgen(js_name = pedersenVerify)]
+            pub fn pedersen_verify(
+                data: &[u8],
+                output: &[u8],
+                ad: &[u8],
+                proof: &[u8],
+            ) -> Result<bool, JsError> {
+                Ok(pedersen_verify::<$suite>(data, output, ad, proof)?)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ring")]
+macro_rules! ring_bindings {
+    ($prover:ident, $verifier:ident, $suite:ty) => {
+        #[doc = concat!("Ring prover for [`", stringify!($suite), "`].")]
+        #[wasm_bindgen]
+        pub struct $prover(ring::RingProver<$suite>);
+
+        #[wasm_bindgen]
+        impl $prover {
+            /// Build a prover for the key at `key_index` in `ring`.
+            ///
+            /// `srs` is the uncompressed PCS parameters encoding and `ring`
+            /// the concatenation of the ring public keys.
+            #[wasm_bindgen(constructor)]
+            pub fn new(
+                srs: &[u8],
+                ring_size: usize,
+                ring: &[u8],
+                key_index: usize,
+            ) -> Result<$prover, JsError> {
+                let setup = ring_setup::<$suite>(srs, ring_size)?;
+                let pks = ring_keys::<$suite>(ring)?;
+                if key_index >= pks.len() {
+                    return Err(Error::InvalidData.into());
+                }
+                let prover_key = setup.prover_key(&pks)?;
+                let prover = setup.ring_ctx.into_ring_prover(prover_key, key_index);
+                Ok(Self(prover))
+            }
+
+            /// Ring VRF proof for the input derived from `data`.
+            pub fn prove(&self, secret: &[u8], data: &[u8], ad: &[u8]) -> Result<Vec<u8>, JsError> {
+                use ring::Prover;
+                let secret: Secret<$suite> = decode(secret)?;
+                let io = secret.vrf_io(input(data)?);
+                Ok(encode(&secret.prove(io, ad, &self.0)?)?)
+            }
+        }
+
+        #[doc = concat!("Ring verifier for [`", stringify!($suite), "`].")]
+        #[wasm_bindgen]
+        pub struct $verifier(ring::RingVerifier<$suite>);
+
+        #[wasm_bindgen]
+        impl $verifier {
+            /// Build a verifier for `ring`.
+            ///
+            /// `srs` is the uncompressed PCS parameters encoding and `ring`
+            /// the concatenation of the ring public keys.
+            #[wasm_bindgen(constructor)]
+            pub fn new(srs: &[u8], ring_size: usize, ring: &[u8]) -> Result<$verifier, JsError> {
+                let setup = ring_setup::<$suite>(srs, ring_size)?;
+                let verifier_key = setup.verifier_key(&ring_keys::<$suite>(ring)?)?;
+                Ok(Self(setup.ring_ctx.into_ring_verifier(verifier_key)))
+            }
+
+            /// Verify a Ring VRF proof.
+            pub fn verify(
+                &self,
+                data: &[u8],
+                output: &[u8],
+                ad: &[u8],
+                proof: &[u8],
+            ) -> Result<bool, JsError> {
+                use ring::Verifier;
+                let proof: ring::Proof<$suite> = decode(proof)?;
+                let io = vrf_io(data, output)?;
+                Ok(verdict(Public::<$suite>::verify(io, ad, &proof, &self.0))?)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "bandersnatch")]
+suite_bindings!(Bandersnatch, suites::bandersnatch::BandersnatchSha512Ell2);
+#[cfg(feature = "bandersnatch")]
+suite_bindings!(
+    BandersnatchSw,
+    suites::bandersnatch_sw::BandersnatchSha512Tai
+);
+#[cfg(all(feature = "bandersnatch", feature = "shake128"))]
+suite_bindings!(
+    BandersnatchShake128,
+    suites::bandersnatch_shake128::BandersnatchShake128Ell2
+);
+#[cfg(feature = "ed25519")]
+suite_bindings!(Ed25519, suites::ed25519::Ed25519Sha512Tai);
+#[cfg(feature = "secp256r1")]
+suite_bindings!(Secp256r1, suites::secp256r1::Secp256r1Sha256Tai);
+#[cfg(feature = "jubjub")]
+suite_bindings!(JubJub, suites::jubjub::JubJubSha512Ell2);
+#[cfg(feature = "baby-jubjub")]
+suite_bindings!(BabyJubJub, suites::baby_jubjub::BabyJubJubSha512Ell2);
+
+#[cfg(all(feature = "ring", feature = "bandersnatch"))]
+ring_bindings!(
+    BandersnatchRingProver,
+    BandersnatchRingVerifier,
+    suites::bandersnatch::BandersnatchSha512Ell2
+);
+#[cfg(all(feature = "ring", feature = "bandersnatch"))]
+ring_bindings!(
+    BandersnatchSwRingProver,
+    BandersnatchSwRingVerifier,
+    suites::bandersnatch_sw::BandersnatchSha512Tai
+);
+#[cfg(all(feature = "ring", feature = "jubjub"))]
+ring_bindings!(
+    JubJubRingProver,
+    JubJubRingVerifier,
+    suites::jubjub::JubJubSha512Ell2
+);
+#[cfg(all(feature = "ring", feature = "baby-jubjub"))]
+ring_bindings!(
+    BabyJubJubRingProver,
+    BabyJubJubRingVerifier,
+    suites::baby_jubjub::BabyJubJubSha512Ell2
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use suites::testing::TestSuite;
+
+    #[test]
+    fn prove_verify() {
+        let secret = secret_from_seed::<TestSuite>(&[0; 32]).unwrap();
+        let public = public_from_secret::<TestSuite>(&secret).unwrap();
+        let output = output::<TestSuite>(&secret, b"foo").unwrap();
+        assert_eq!(
+            output_hash::<TestSuite>(&output).unwrap().len(),
+            OUTPUT_HASH_LEN
+        );
+
+        let proof = tiny_prove::<TestSuite>(&secret, b"foo", b"bar").unwrap();
+        assert_eq!(
+            tiny_verify::<TestSuite>(&public, b"foo", &output, b"bar", &proof),
+            Ok(true)
+        );
+        assert_eq!(
+            tiny_verify::<TestSuite>(&public, b"foo", &output, b"baz", &proof),
+            Ok(false)
+        );
+
+        let proof = pedersen_prove::<TestSuite>(&secret, b"foo", b"bar").unwrap();
+        assert_eq!(
+            pedersen_verify::<TestSuite>(b"foo", &output, b"bar", &proof),
+            Ok(true)
+        );
+        assert_eq!(
+            pedersen_verify::<TestSuite>(b"foo", &output, b"baz", &proof),
+            Ok(false)
+        );
+
+        assert_eq!(
+            secret_from_seed::<TestSuite>(&[0; 31]),
+            Err(Error::InvalidData)
+        );
+        assert!(tiny_verify::<TestSuite>(&public, b"foo", &output, b"bar", &proof).is_err());
+    }
+}